@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use rolldown::BundlerOptions;
+use rolldown::{BundlerOptions, RawMinifyOptions};
 use rolldown_testing::workspace;
 
 pub fn join_by_workspace_root(path: &str) -> PathBuf {
@@ -43,7 +43,7 @@ pub fn derive_benchmark_items(
       name: format!("{}-minify", name),
       options: Box::new(move || {
         let mut options = create_bundler_options();
-        options.minify = Some(true);
+        options.minify = Some(RawMinifyOptions::Boolean(true));
         options
       }),
     });
@@ -55,7 +55,7 @@ pub fn derive_benchmark_items(
       options: Box::new(move || {
         let mut options = create_bundler_options();
         options.sourcemap = Some(rolldown::SourceMapType::File);
-        options.minify = Some(true);
+        options.minify = Some(RawMinifyOptions::Boolean(true));
         options
       }),
     });
@@ -15,7 +15,7 @@ impl Generator for AssetGenerator {
       .modules
       .iter()
       .filter_map(|&id| ctx.link_output.module_table.modules[id].as_normal())
-      .filter(|m| m.asset_view.is_some())
+      .filter(|m| m.asset_view.as_ref().is_some_and(|view| view.data_url.is_none()))
       .collect::<Vec<_>>();
 
     let mut instantiated_chunks = vec![];
@@ -31,6 +31,7 @@ impl Generator for AssetGenerator {
         origin_chunk: ctx.chunk_idx,
         content: StrOrBytes::Bytes(asset_view.source.to_vec()),
         map: None,
+        index_map: None,
         kind: InstantiationKind::None,
         augment_chunk_hash: None,
         file_dir: file_dir.to_path_buf(),
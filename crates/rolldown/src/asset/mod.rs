@@ -1,7 +1,24 @@
+use std::path::Path;
+
 use rolldown_common::AssetView;
+use rolldown_utils::{
+  base64::to_standard_base64, concat_string, dataurl::encode_as_svg_dataurl, mime::guess_mime,
+};
 
 pub mod asset_generator;
 
 pub fn create_asset_view(source: Box<[u8]>) -> AssetView {
-  AssetView { source }
+  AssetView { source, data_url: None }
+}
+
+/// SVGs are inlined as percent-escaped data URLs, others are base64 encoded.
+pub fn asset_to_data_url(id: &str, source: &[u8]) -> anyhow::Result<String> {
+  let path = Path::new(id);
+  if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("svg")) {
+    if let Some(data_url) = encode_as_svg_dataurl(source) {
+      return Ok(data_url);
+    }
+  }
+  let mime = guess_mime(path, source)?;
+  Ok(concat_string!("data:", mime.to_string(), ";base64,", to_standard_base64(source)))
 }
@@ -164,6 +164,10 @@ impl<'me, 'ast: 'me> Visit<'ast> for AstScanner<'me, 'ast> {
           ImportRecordMeta::empty()
         },
       );
+      // `import('./data.json', { with: { type: 'json' } })`
+      if let Some(type_value) = expr.arguments.first().and_then(dynamic_import_attribute_type) {
+        self.assert_module_type_by_import_attribute(import_rec_idx, type_value);
+      }
       self.init_dynamic_import_binding_usage_info(import_rec_idx);
       self.result.imports.insert(expr.span, import_rec_idx);
     }
@@ -298,6 +302,9 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         // use this flag to avoid insert it as `Symbol` at the same time.
         let mut is_inserted_before = false;
         if self.result.named_imports.contains_key(&root_symbol_id) {
+          if self.is_eagerly_evaluated() {
+            self.result.eagerly_evaluated_imports.insert(root_symbol_id);
+          }
           if let Some((span, props)) = self.try_extract_parent_static_member_expr_chain(usize::MAX)
           {
             if !span.is_unspanned() {
@@ -400,3 +407,27 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     }
   }
 }
+
+/// Gets `'json'` of `{ with: { type: 'json' } }` in `import('./data.json', { with: { type: 'json' } })`.
+fn dynamic_import_attribute_type<'a, 'ast>(
+  options: &'a ast::Expression<'ast>,
+) -> Option<&'a ast::StringLiteral<'ast>> {
+  let find_property = |obj: &'a ast::ObjectExpression<'ast>, name: &str| {
+    obj.properties.iter().find_map(|prop| match prop {
+      ast::ObjectPropertyKind::ObjectProperty(prop)
+        if !prop.computed && prop.key.static_name().is_some_and(|key| key == name) =>
+      {
+        Some(&prop.value)
+      }
+      _ => None,
+    })
+  };
+  let ast::Expression::ObjectExpression(options) = options else { return None };
+  let Some(ast::Expression::ObjectExpression(with)) = find_property(options, "with") else {
+    return None;
+  };
+  match find_property(with, "type") {
+    Some(ast::Expression::StringLiteral(type_value)) => Some(type_value),
+    _ => None,
+  }
+}
@@ -14,7 +14,7 @@ use oxc::{
   ast::{
     ast::{
       ExportAllDeclaration, ExportDefaultDeclaration, ExportNamedDeclaration, IdentifierReference,
-      ImportDeclaration, ModuleDeclaration, Program,
+      ImportDeclaration, ModuleDeclaration, Program, WithClause,
     },
     Comment, Visit,
   },
@@ -25,8 +25,8 @@ use oxc_index::IndexVec;
 use rolldown_common::dynamic_import_usage::{DynamicImportExportsUsage, DynamicImportUsageInfo};
 use rolldown_common::{
   AstScopes, EcmaModuleAstUsage, ExportsKind, ImportKind, ImportRecordIdx, ImportRecordMeta,
-  LocalExport, MemberExprRef, ModuleDefFormat, ModuleId, ModuleIdx, NamedImport, RawImportRecord,
-  Specifier, StmtInfo, StmtInfos, SymbolRef, SymbolRefDbForModule, SymbolRefFlags,
+  LocalExport, MemberExprRef, ModuleDefFormat, ModuleId, ModuleIdx, ModuleType, NamedImport,
+  RawImportRecord, Specifier, StmtInfo, StmtInfos, SymbolRef, SymbolRefDbForModule, SymbolRefFlags,
   ThisExprReplaceKind,
 };
 use rolldown_ecmascript_utils::{BindingIdentifierExt, BindingPatternExt};
@@ -74,6 +74,8 @@ pub struct ScanResult {
   /// `new URL('...', import.meta.url)`
   pub new_url_references: FxHashMap<Span, ImportRecordIdx>,
   pub this_expr_replace_map: FxHashMap<Span, ThisExprReplaceKind>,
+  /// Imported bindings read while the module is evaluated, i.e. outside of any function.
+  pub eagerly_evaluated_imports: FxHashSet<SymbolRef>,
 }
 
 pub struct AstScanner<'me, 'ast> {
@@ -156,6 +158,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
       dynamic_import_rec_exports_usage: FxHashMap::default(),
       new_url_references: FxHashMap::default(),
       this_expr_replace_map: FxHashMap::default(),
+      eagerly_evaluated_imports: FxHashSet::default(),
     };
 
     Self {
@@ -311,6 +314,38 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     id
   }
 
+  /// Reads `type` of `with { type: '...' }` and asserts the module type of the importee with it.
+  fn scan_with_clause(&mut self, rec_id: ImportRecordIdx, with_clause: Option<&WithClause>) {
+    let Some(with_clause) = with_clause else { return };
+    let Some(type_attr) = with_clause.with_entries.iter().find(|attr| match &attr.key {
+      ast::ImportAttributeKey::Identifier(id) => id.name == "type",
+      ast::ImportAttributeKey::StringLiteral(str) => str.value == "type",
+    }) else {
+      return;
+    };
+    self.assert_module_type_by_import_attribute(rec_id, &type_attr.value);
+  }
+
+  fn assert_module_type_by_import_attribute(
+    &mut self,
+    rec_id: ImportRecordIdx,
+    type_value: &ast::StringLiteral,
+  ) {
+    if let Some(module_type) = ModuleType::from_import_attribute_type(&type_value.value) {
+      self.result.import_records[rec_id].asserted_module_type = Some(module_type);
+    } else {
+      self.result.errors.push(BuildDiagnostic::invalid_import_attribute(
+        self.id.to_string().into(),
+        self.source.clone(),
+        type_value.span,
+        format!(
+          "Import attribute `type: {:?}` is not supported. Expected one of `json`, `text`, `bytes` or `css`.",
+          type_value.value.as_str()
+        ),
+      ));
+    }
+  }
+
   fn add_named_import(
     &mut self,
     local: SymbolId,
@@ -475,6 +510,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         ImportRecordMeta::empty()
       },
     );
+    self.scan_with_clause(id, decl.with_clause.as_deref());
     if let Some(exported) = &decl.exported {
       // export * as ns from '...'
       self.add_star_re_export(exported.name().as_str(), id, decl.span);
@@ -498,6 +534,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
           ImportRecordMeta::empty()
         },
       );
+      self.scan_with_clause(record_id, decl.with_clause.as_deref());
       decl.specifiers.iter().for_each(|spec| {
         self.add_re_export(
           spec.exported.name().as_str(),
@@ -590,6 +627,7 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
         ImportRecordMeta::empty()
       },
     );
+    self.scan_with_clause(rec_id, decl.with_clause.as_deref());
     self.result.imports.insert(decl.span, rec_id);
     // // `import '...'` or `import {} from '...'`
     if decl.specifiers.as_ref().map_or(true, |s| s.is_empty()) {
@@ -732,6 +770,14 @@ impl<'me, 'ast: 'me> AstScanner<'me, 'ast> {
     symbol_id.is_none()
   }
 
+  /// If the current visit position is evaluated with the module, instead of inside a function that
+  /// may be called later
+  pub fn is_eagerly_evaluated(&self) -> bool {
+    !self.scope_stack.iter().any(|scope| {
+      scope.is_some_and(|scope| self.scopes.get_flags(scope).contains(ScopeFlags::Function))
+    })
+  }
+
   /// If it is not a top level `this` reference visit position
   pub fn is_this_nested(&self) -> bool {
     self.is_nested_this_inside_class
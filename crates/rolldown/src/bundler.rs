@@ -1,8 +1,9 @@
 use super::stages::{link_stage::LinkStage, scan_stage::ScanStageOutput};
 use crate::{
-  bundler_builder::BundlerBuilder,
+  bundler_builder::{BundlerBuilder, BundlerParts},
   stages::{generate_stage::GenerateStage, scan_stage::ScanStage},
   types::bundle_output::BundleOutput,
+  utils::apply_log_rules::apply_log_rules_to_errors,
   BundlerOptions, SharedOptions, SharedResolver,
 };
 use anyhow::Result;
@@ -11,7 +12,8 @@ use rolldown_common::{Cache, NormalizedBundlerOptions, SharedFileEmitter};
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_fs::{FileSystem, OsFileSystem};
 use rolldown_plugin::{
-  HookBuildEndArgs, HookRenderErrorArgs, SharedPluginDriver, __inner::SharedPluginable,
+  HookBuildEndArgs, HookRenderErrorArgs, PluginDriver, SharedPluginDriver,
+  __inner::SharedPluginable,
 };
 use std::sync::Arc;
 use tracing_chrome::FlushGuard;
@@ -24,9 +26,16 @@ pub struct Bundler {
   pub(crate) file_emitter: SharedFileEmitter,
   pub(crate) plugin_driver: SharedPluginDriver,
   pub(crate) warnings: Vec<BuildDiagnostic>,
+  /// Errors happened while normalizing the options. They are reported by the first build.
+  pub(crate) errors: Vec<BuildDiagnostic>,
   pub(crate) _log_guard: Option<FlushGuard>,
   #[allow(unused)]
   pub(crate) cache: Arc<Cache>,
+  /// Whether the bundler is driven by a watcher. Results of `load` and `transform` of unchanged
+  /// modules are reused across rebuilds in watch mode.
+  pub(crate) watch_mode: bool,
+  /// The raw options and plugins, kept until `options` hooks run at the start of the first build.
+  pub(crate) pending_options_hooks: Option<(BundlerOptions, Vec<SharedPluginable>)>,
 }
 
 impl Bundler {
@@ -51,10 +60,12 @@ impl Bundler {
   pub async fn generate(&mut self) -> BuildResult<BundleOutput> {
     let scan_stage_output = self.scan().await?;
 
-    self.bundle_up(scan_stage_output, /* is_write */ false).await.map(|mut output| {
+    let result = self.bundle_up(scan_stage_output, /* is_write */ false).await;
+    let result = result.map(|mut output| {
       output.warnings.append(&mut self.warnings);
       output
-    })
+    });
+    apply_log_rules_to_errors(&self.options, result)
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -70,12 +81,24 @@ impl Bundler {
   }
 
   pub async fn scan(&mut self) -> BuildResult<ScanStageOutput> {
+    let result = self.scan_inner().await;
+    apply_log_rules_to_errors(&self.options, result)
+  }
+
+  async fn scan_inner(&mut self) -> BuildResult<ScanStageOutput> {
+    self.run_options_hooks().await?;
+
+    if !self.errors.is_empty() {
+      return Err(std::mem::take(&mut self.errors).into());
+    }
+
     let scan_stage_output = match ScanStage::new(
       Arc::clone(&self.options),
       Arc::clone(&self.plugin_driver),
       self.fs,
       Arc::clone(&self.resolver),
       Arc::clone(&self.cache),
+      self.watch_mode,
     )
     .scan()
     .await
@@ -96,9 +119,46 @@ impl Bundler {
     Ok(scan_stage_output)
   }
 
+  /// Runs `options` and `output_options` hooks before the first build. If they replace the options,
+  /// the options, the resolver and the plugin driver are re-derived from the returned ones.
+  async fn run_options_hooks(&mut self) -> BuildResult<()> {
+    let Some((raw_options, plugins)) = &self.pending_options_hooks else {
+      return Ok(());
+    };
+
+    let mut raw_options = raw_options.clone();
+    let plugins = plugins.clone();
+    let is_replaced = PluginDriver::options(&plugins, &mut raw_options).await?;
+
+    // The hooks are only dropped once they succeed, so a failed build runs them again in the next.
+    self.pending_options_hooks = None;
+    if !is_replaced {
+      return Ok(());
+    }
+
+    let BundlerParts { options, resolver, file_emitter, plugin_driver, warnings, errors } =
+      BundlerParts::new(raw_options, plugins);
+    self.options = options;
+    self.resolver = resolver;
+    self.file_emitter = file_emitter;
+    self.plugin_driver = plugin_driver;
+    self.warnings = warnings;
+    self.errors = errors;
+
+    Ok(())
+  }
+
   pub async fn bundle_write(
     &mut self,
     scan_stage_output: ScanStageOutput,
+  ) -> BuildResult<BundleOutput> {
+    let result = self.bundle_write_inner(scan_stage_output).await;
+    apply_log_rules_to_errors(&self.options, result)
+  }
+
+  async fn bundle_write_inner(
+    &mut self,
+    scan_stage_output: ScanStageOutput,
   ) -> BuildResult<BundleOutput> {
     let mut output = self.bundle_up(scan_stage_output, /* is_write */ true).await?;
 
@@ -121,6 +181,12 @@ impl Bundler {
         .map_err(|err| anyhow::anyhow!("Failed to write file in {:?}", dest).context(err))?;
     }
 
+    for name_cache in &output.name_cache_files {
+      self.fs.write(&name_cache.path, name_cache.content.as_bytes()).map_err(|err| {
+        anyhow::anyhow!("Failed to write the name cache {:?}", name_cache.path).context(err)
+      })?;
+    }
+
     self.plugin_driver.write_bundle(&mut output.assets, &self.options).await?;
 
     output.warnings.append(&mut self.warnings);
@@ -146,7 +212,7 @@ impl Bundler {
     let mut link_stage_output = LinkStage::new(scan_stage_output, &self.options).link();
 
     let bundle_output =
-      GenerateStage::new(&mut link_stage_output, &self.options, &self.plugin_driver)
+      GenerateStage::new(&mut link_stage_output, &self.options, &self.plugin_driver, self.fs)
         .generate()
         .await; // Notice we don't use `?` to break the control flow here.
 
@@ -1,16 +1,18 @@
 use std::sync::Arc;
 
-use rolldown_common::{Cache, FileEmitter};
+use rolldown_common::{Cache, FileEmitter, SharedFileEmitter};
+use rolldown_error::BuildDiagnostic;
 use rolldown_fs::OsFileSystem;
-use rolldown_plugin::{PluginDriver, __inner::SharedPluginable};
+use rolldown_plugin::{PluginDriver, SharedPluginDriver, __inner::SharedPluginable};
 use rolldown_resolver::Resolver;
 
 use crate::{
   utils::{
     apply_inner_plugins::apply_inner_plugins,
+    apply_log_rules::apply_log_rules,
     normalize_options::{normalize_options, NormalizeOptionsReturn},
   },
-  Bundler, BundlerOptions, SharedResolver,
+  Bundler, BundlerOptions, SharedOptions, SharedResolver,
 };
 
 #[derive(Debug, Default)]
@@ -23,28 +25,28 @@ impl BundlerBuilder {
   pub fn build(mut self) -> Bundler {
     let maybe_guard = rolldown_tracing::try_init_tracing();
 
-    let NormalizeOptionsReturn { options, resolve_options, warnings } =
-      normalize_options(self.options);
-
-    let resolver: SharedResolver =
-      Resolver::new(resolve_options, options.platform, options.cwd.clone(), OsFileSystem).into();
-
-    let options = Arc::new(options);
-
-    let file_emitter = Arc::new(FileEmitter::new(Arc::clone(&options)));
+    // `options` hooks are async, so they can't run here. The raw options are kept and the hooks run
+    // at the start of the first build. See `Bundler::run_options_hooks`.
+    let raw_options = (!self.plugins.is_empty()).then(|| self.options.clone());
 
     apply_inner_plugins(&mut self.plugins);
 
+    let BundlerParts { options, resolver, file_emitter, plugin_driver, warnings, errors } =
+      BundlerParts::new(self.options, self.plugins.clone());
+
     Bundler {
       closed: false,
-      plugin_driver: PluginDriver::new_shared(self.plugins, &resolver, &file_emitter, &options),
+      plugin_driver,
       file_emitter,
       resolver,
       options,
       fs: OsFileSystem,
       warnings,
+      errors,
       _log_guard: maybe_guard,
       cache: Arc::new(Cache::default()),
+      watch_mode: false,
+      pending_options_hooks: raw_options.map(|raw_options| (raw_options, self.plugins)),
     }
   }
 
@@ -60,3 +62,33 @@ impl BundlerBuilder {
     self
   }
 }
+
+/// Everything of a `Bundler` that derives from the raw options.
+pub(crate) struct BundlerParts {
+  pub options: SharedOptions,
+  pub resolver: SharedResolver,
+  pub file_emitter: SharedFileEmitter,
+  pub plugin_driver: SharedPluginDriver,
+  pub warnings: Vec<BuildDiagnostic>,
+  pub errors: Vec<BuildDiagnostic>,
+}
+
+impl BundlerParts {
+  pub fn new(options: BundlerOptions, plugins: Vec<SharedPluginable>) -> Self {
+    let NormalizeOptionsReturn { options, resolve_options, mut warnings } =
+      normalize_options(options);
+    let mut errors = vec![];
+    apply_log_rules(&options, &mut errors, &mut warnings);
+
+    let resolver: SharedResolver =
+      Resolver::new(resolve_options, options.platform, options.cwd.clone(), OsFileSystem).into();
+
+    let options = Arc::new(options);
+
+    let file_emitter = Arc::new(FileEmitter::new(Arc::clone(&options)));
+
+    let plugin_driver = PluginDriver::new_shared(plugins, &resolver, &file_emitter, &options);
+
+    Self { options, resolver, file_emitter, plugin_driver, warnings, errors }
+  }
+}
@@ -55,6 +55,7 @@ impl Generator for CssGenerator {
         origin_chunk: ctx.chunk_idx,
         content: content.into(),
         map: None,
+        index_map: None,
         kind: InstantiationKind::None,
         augment_chunk_hash: None,
         file_dir: file_dir.to_path_buf(),
@@ -2,7 +2,10 @@ use std::sync::Arc;
 
 use crate::{
   types::generator::{GenerateContext, GenerateOutput, Generator},
-  utils::{chunk::generate_rendered_chunk, render_ecma_module::render_ecma_module},
+  utils::{
+    chunk::{generate_rendered_chunk, render_runtime_helpers::render_runtime_helpers_import},
+    render_ecma_module::render_ecma_module,
+  },
 };
 
 use anyhow::Result;
@@ -45,7 +48,13 @@ impl Generator for EcmaGenerator {
           .map(|m| (m, codegen_ret.expect("should have codegen_ret")))
       })
       .map(|(m, codegen_ret)| {
-        (m.idx, m.id.clone(), render_ecma_module(m, ctx.options, codegen_ret))
+        let sources =
+          if m.idx == ctx.link_output.runtime.id() && !ctx.options.runtime_helpers.is_inline() {
+            render_runtime_helpers_import(ctx, m)
+          } else {
+            render_ecma_module(m, ctx.options, codegen_ret)
+          };
+        (m.idx, m.id.clone(), sources)
       })
       .collect::<Vec<_>>();
 
@@ -187,7 +196,13 @@ impl Generator for EcmaGenerator {
 
     ctx.warnings.extend(warnings);
 
-    let (content, mut map) = source_joiner.join();
+    let (content, mut map, mut index_map) = if ctx.options.sourcemap_index_map {
+      let (content, index_map) = source_joiner.join_index_map();
+      (content, None, index_map)
+    } else {
+      let (content, map) = source_joiner.join();
+      (content, map, None)
+    };
 
     // Here file path is generated by chunk file name template, it maybe including path segments.
     // So here need to read it's parent directory as file_dir.
@@ -201,7 +216,10 @@ impl Generator for EcmaGenerator {
     );
     let file_dir = file_path.parent().expect("chunk file name should have a parent");
 
-    if let Some(map) = map.as_mut() {
+    let section_maps = index_map
+      .iter_mut()
+      .flat_map(|index_map| index_map.sections_mut().iter_mut().map(|section| &mut section.map));
+    for map in map.iter_mut().chain(section_maps) {
       let paths =
         map.get_sources().map(|source| source.as_path().relative(file_dir)).collect::<Vec<_>>();
       // Here not normalize the windows path, the rollup `sourcemap_path_transform` ctx.options need to original path.
@@ -214,6 +232,7 @@ impl Generator for EcmaGenerator {
         origin_chunk: ctx.chunk_idx,
         content: content.into(),
         map,
+        index_map,
         kind: InstantiationKind::from(EcmaAssetMeta { rendered_chunk }),
         augment_chunk_hash: None,
         file_dir: file_dir.to_path_buf(),
@@ -113,6 +113,7 @@ pub async fn create_ecma_view(
     dynamic_import_rec_exports_usage: dynamic_import_exports_usage,
     new_url_references: new_url_imports,
     this_expr_replace_map,
+    eagerly_evaluated_imports,
   } = scan_result;
   if !errors.is_empty() {
     return Err(errors.into());
@@ -212,6 +213,7 @@ pub async fn create_ecma_view(
     mutations: vec![],
     new_url_references: new_url_imports,
     this_expr_replace_map,
+    eagerly_evaluated_imports,
     esm_namespace_in_cjs: None,
     esm_namespace_in_cjs_node_mode: None,
   };
@@ -1,6 +1,6 @@
 use arcstr::ArcStr;
 use itertools::Itertools;
-use rolldown_common::{ExportsKind, Specifier};
+use rolldown_common::{ExportsKind, ExternalModule, ModuleType, Specifier};
 use rolldown_sourcemap::SourceJoiner;
 use rolldown_utils::{concat_string, ecmascript::is_validate_identifier_name};
 
@@ -41,11 +41,17 @@ pub fn render_esm<'code>(
         .star_export_module_ids()
         .filter_map(|importee| {
           let importee = &ctx.link_output.module_table.modules[importee];
-          importee.as_external().map(|m| &m.name)
+          importee.as_external()
         })
-        .dedup()
-        .for_each(|ext_name| {
-          source_joiner.append_source(concat_string!("export * from \"", ext_name, "\"\n"));
+        .dedup_by(|a, b| a.idx == b.idx)
+        .for_each(|ext| {
+          source_joiner.append_source(concat_string!(
+            "export * from \"",
+            ext.name,
+            "\"",
+            render_import_attributes(ext),
+            "\n"
+          ));
         });
     }
   }
@@ -112,6 +118,7 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> String {
       &default_alias,
       // TODO: filename relative to importee
       &ctx.chunk.import_path_for(importee_chunk).into(),
+      "",
     ));
   });
   // render external imports
@@ -136,7 +143,9 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> String {
             s.push_str(alias);
             s.push_str(" from \"");
             s.push_str(&importee.name);
-            s.push_str("\";\n");
+            s.push('"');
+            s.push_str(&render_import_attributes(importee));
+            s.push_str(";\n");
             None
           }
           Specifier::Literal(imported) => {
@@ -167,7 +176,12 @@ fn render_esm_chunk_imports(ctx: &GenerateContext<'_>) -> String {
       || !default_alias.is_empty()
       || (importee.side_effects.has_side_effects() && !has_importee_imported)
     {
-      s.push_str(&create_import_declaration(specifiers, &default_alias, &importee.name));
+      s.push_str(&create_import_declaration(
+        specifiers,
+        &default_alias,
+        &importee.name,
+        &render_import_attributes(importee),
+      ));
     }
   });
 
@@ -178,6 +192,7 @@ fn create_import_declaration(
   mut specifiers: Vec<String>,
   default_alias: &[ArcStr],
   path: &ArcStr,
+  import_attributes: &str,
 ) -> String {
   let mut ret = String::new();
   let first_default_alias = match &default_alias {
@@ -198,17 +213,34 @@ fn create_import_declaration(
     ret.push_str(&specifiers.join(", "));
     ret.push_str(" } from \"");
     ret.push_str(path);
-    ret.push_str("\";\n");
+    ret.push('"');
+    ret.push_str(import_attributes);
+    ret.push_str(";\n");
   } else if let Some(first_default_alias) = first_default_alias {
     ret.push_str("import ");
     ret.push_str(first_default_alias);
     ret.push_str(" from \"");
     ret.push_str(path);
-    ret.push_str("\";\n");
+    ret.push('"');
+    ret.push_str(import_attributes);
+    ret.push_str(";\n");
   } else {
     ret.push_str("import \"");
     ret.push_str(path);
-    ret.push_str("\";\n");
+    ret.push('"');
+    ret.push_str(import_attributes);
+    ret.push_str(";\n");
   }
   ret
 }
+
+/// Externals imported with `with { type: '...' }` need to keep the attribute, or runtimes would
+/// refuse to load them, e.g. json modules.
+fn render_import_attributes(importee: &ExternalModule) -> String {
+  importee
+    .asserted_module_type
+    .as_ref()
+    .and_then(ModuleType::as_import_attribute_type)
+    .map(|ty| concat_string!(" with { type: \"", ty, "\" }"))
+    .unwrap_or_default()
+}
@@ -1,12 +1,15 @@
+use oxc::allocator;
 use oxc::ast::ast::{self, ExportDefaultDeclarationKind, Expression, Statement};
 use oxc::ast::visit::walk_mut;
 use oxc::ast::VisitMut;
 use oxc::span::{CompactStr, Span, SPAN};
-use rolldown_common::{Interop, Module, SymbolRef};
-use rolldown_ecmascript_utils::{CallExpressionExt, TakeIn};
+use rolldown_common::{Interop, Module, NamedImport, Specifier, SymbolRef};
+use rolldown_ecmascript_utils::{CallExpressionExt, ExpressionExt, TakeIn};
+use rolldown_plugin::{HookRenderDynamicImportArgs, HookResolveImportMetaArgs};
 use rolldown_utils::ecmascript::legitimize_identifier_name;
 
 use super::IsolatingModuleFinalizer;
+use crate::module_finalizers::{dynamic_import_code, expr_from_plugin_code, file_url_code};
 
 impl<'ast> VisitMut<'ast> for IsolatingModuleFinalizer<'_, 'ast> {
   fn visit_program(&mut self, program: &mut ast::Program<'ast>) {
@@ -23,7 +26,7 @@ impl<'ast> VisitMut<'ast> for IsolatingModuleFinalizer<'_, 'ast> {
           self.transform_import_declaration(import_decl);
         }
         ast::Statement::ExportDefaultDeclaration(export_default_decl) => {
-          stmts.push(self.transform_export_default_declaration(export_default_decl));
+          self.transform_export_default_declaration(export_default_decl, &mut stmts);
         }
         ast::Statement::ExportNamedDeclaration(export_named_decl) => {
           if let Some(stmt) = self.transform_named_declaration(export_named_decl) {
@@ -37,9 +40,11 @@ impl<'ast> VisitMut<'ast> for IsolatingModuleFinalizer<'_, 'ast> {
       };
     }
 
+    let mut body = self.snippet.builder.vec();
+
     // Add __esModule flag for esm module
     if self.ctx.module.exports_kind.is_esm() {
-      program.body.push(self.snippet.builder.statement_expression(
+      body.push(self.snippet.builder.statement_expression(
         SPAN,
         self.snippet.call_expr_with_arg_expr("__toCommonJS", "exports"),
       ));
@@ -47,7 +52,7 @@ impl<'ast> VisitMut<'ast> for IsolatingModuleFinalizer<'_, 'ast> {
 
     // Generate export statements, using `Object.defineProperty`
     if !self.generated_exports.is_empty() {
-      program.body.push(self.snippet.builder.statement_expression(
+      body.push(self.snippet.builder.statement_expression(
         SPAN,
         self.snippet.alloc_call_expr_with_2arg_expr_expr(
           "__export",
@@ -62,50 +67,67 @@ impl<'ast> VisitMut<'ast> for IsolatingModuleFinalizer<'_, 'ast> {
     }
 
     // Add generated imports
-    program.body.extend(self.generated_imports.drain(..));
+    body.extend(self.generated_imports.drain(..));
+
+    body.extend(stmts);
 
-    program.body.extend(stmts);
+    // Each module gets its own scope and its own `exports`, `module` and `require`.
+    program.body.push(self.snippet.app_module_wrapper_stmt(
+      program.directives.take_in(self.alloc),
+      body,
+      self.ctx.options.profiler_names,
+      &self.ctx.module.stable_id,
+    ));
   }
 
   fn visit_expression(&mut self, expr: &mut Expression<'ast>) {
-    if let Expression::Identifier(ident) = expr {
-      if let Some(named_import) = ident
-        .reference_id
-        .get()
-        .and_then(|reference_id| {
-          self.scope.symbol_id_for(
-            reference_id,
-            self.ctx.symbol_db.this_method_should_be_removed_get_symbol_table(self.ctx.module.idx),
-          )
-        })
-        .map(|symbol_id| (self.ctx.module.idx, symbol_id).into())
-        .and_then(|symbol_ref: SymbolRef| self.ctx.module.named_imports.get(&symbol_ref))
+    match expr {
+      Expression::Identifier(ident) => {
+        if let Some(named_import) = self.named_import_for(ident) {
+          *expr = self.named_import_expr(named_import, ident.span);
+        }
+      }
+      Expression::ImportExpression(import_expr) => {
+        if let Some(new_expr) = self.try_render_dynamic_import_with_plugins(import_expr) {
+          // Code returned by plugins is final, don't walk it.
+          *expr = new_expr;
+          return;
+        }
+      }
+      Expression::StaticMemberExpression(member_expr) if member_expr.object.is_import_meta() => {
+        if let Some(new_expr) = self.try_rewrite_import_meta_prop_with_plugins(member_expr) {
+          // Code returned by plugins is final, don't walk it.
+          *expr = new_expr;
+        }
+        // The object is a bare `import.meta`, don't walk into it.
+        return;
+      }
+      Expression::MetaProperty(meta_prop)
+        if meta_prop.meta.name == "import" && meta_prop.property.name == "meta" =>
       {
-        let rec = &self.ctx.module.import_records[named_import.record_id];
-
-        let namespace_object_ref =
-          self.create_namespace_object_ref_for_module(&self.ctx.modules[rec.resolved_module]);
-
-        match &named_import.imported {
-          rolldown_common::Specifier::Star => {
-            ident.name = self.snippet.atom(namespace_object_ref.as_str());
-          }
-          rolldown_common::Specifier::Literal(imported) => {
-            *expr = Expression::StaticMemberExpression(
-              self.snippet.builder.alloc_static_member_expression(
-                ident.span,
-                self.snippet.id_ref_expr(namespace_object_ref.as_str(), SPAN),
-                self.snippet.builder.identifier_name(SPAN, imported.as_str()),
-                false,
-              ),
-            );
-          }
+        if let Some(new_expr) = self.resolve_import_meta_with_plugins(None) {
+          // Code returned by plugins is final, don't walk it.
+          *expr = new_expr;
+          return;
         }
-      };
+      }
+      _ => {}
     }
     walk_mut::walk_expression(self, expr);
   }
 
+  fn visit_object_property(&mut self, prop: &mut ast::ObjectProperty<'ast>) {
+    // `{ foo }` becomes `{ foo: foo_exports.foo }` if `foo` is imported.
+    if prop.shorthand {
+      if let Expression::Identifier(ident) = &prop.value {
+        if self.named_import_for(ident).is_some() {
+          prop.shorthand = false;
+        }
+      }
+    }
+    walk_mut::walk_object_property(self, prop);
+  }
+
   fn visit_call_expression(&mut self, expr: &mut ast::CallExpression<'ast>) {
     if expr.is_global_require_call(
       self.scope,
@@ -116,11 +138,27 @@ impl<'ast> VisitMut<'ast> for IsolatingModuleFinalizer<'_, 'ast> {
       }
     }
 
+    // Calling an imported function shouldn't pass the namespace object as `this`, so `foo()` becomes
+    // `(0, foo_exports.foo)()`.
+    if let Expression::Identifier(ident) = &expr.callee {
+      if let Some(named_import @ NamedImport { imported: Specifier::Literal(_), .. }) =
+        self.named_import_for(ident)
+      {
+        let member_expr = self.named_import_expr(named_import, ident.span);
+        expr.callee =
+          self.snippet.seq2_in_paren_expr(self.snippet.number_expr(0.0, "0"), member_expr);
+        for arg in &mut expr.arguments {
+          self.visit_argument(arg);
+        }
+        return;
+      }
+    }
+
     walk_mut::walk_call_expression(self, expr);
   }
 }
 
-impl<'ast> IsolatingModuleFinalizer<'_, 'ast> {
+impl<'me, 'ast> IsolatingModuleFinalizer<'me, 'ast> {
   pub fn transform_import_declaration(&mut self, import_decl: &ast::ImportDeclaration<'ast>) {
     // The specifiers rewrite with reference the namespace object, see `IsolatingModuleFinalizer#visit_expression`
 
@@ -138,42 +176,57 @@ impl<'ast> IsolatingModuleFinalizer<'_, 'ast> {
   pub fn transform_export_default_declaration(
     &mut self,
     export_default_decl: &mut ast::ExportDefaultDeclaration<'ast>,
-  ) -> Statement<'ast> {
-    // TODO deconflict default_export_ref
-    let default_export_ref = self.ctx.module.default_export_ref.name(self.ctx.symbol_db);
+    stmts: &mut allocator::Vec<'ast, Statement<'ast>>,
+  ) {
+    let keep_names = self.ctx.options.keep_names;
 
     match &mut export_default_decl.declaration {
       decl @ ast::match_expression!(ExportDefaultDeclarationKind) => {
+        let default_export_name = self.default_export_name();
         self.generated_exports.push(self.snippet.object_property_kind_object_property(
           "default",
-          self.snippet.id_ref_expr(default_export_ref, SPAN),
+          self.snippet.id_ref_expr(&default_export_name, SPAN),
           false,
         ));
-        self.snippet.var_decl_stmt(default_export_ref, decl.to_expression_mut().take_in(self.alloc))
+        let expr = decl.to_expression_mut().take_in(self.alloc);
+        let is_anonymous = expr.is_anonymous_function_definition();
+        stmts.push(self.snippet.var_decl_stmt(&default_export_name, expr));
+        if keep_names && is_anonymous {
+          stmts.push(self.snippet.keep_name_call_expr_stmt("default", &default_export_name));
+        }
       }
       ast::ExportDefaultDeclarationKind::FunctionDeclaration(func) => {
-        let from = func.id.as_ref().map_or(default_export_ref, |ident| ident.name.as_str());
+        let is_anonymous = func.id.is_none();
+        if is_anonymous {
+          let default_export_name = self.default_export_name();
+          func.id = Some(self.snippet.id(&default_export_name, SPAN));
+        }
+        let from = func.id.as_ref().expect("should have been named").name.as_str();
         self.generated_exports.push(self.snippet.object_property_kind_object_property(
           "default",
           self.snippet.id_ref_expr(from, SPAN),
           false,
         ));
-        self
-          .snippet
-          .builder
-          .statement_expression(SPAN, Expression::FunctionExpression(func.take_in(self.alloc)))
+        let keep_name_stmt = (keep_names && is_anonymous)
+          .then(|| self.snippet.keep_name_call_expr_stmt("default", from));
+        stmts.push(Statement::FunctionDeclaration(func.take_in(self.alloc)));
+        stmts.extend(keep_name_stmt);
       }
       ast::ExportDefaultDeclarationKind::ClassDeclaration(class) => {
-        let from = class.id.as_ref().map_or(default_export_ref, |ident| ident.name.as_str());
+        if class.id.is_none() {
+          let default_export_name = self.default_export_name();
+          class.id = Some(self.snippet.id(&default_export_name, SPAN));
+          if keep_names {
+            class.body.body.insert(0, self.snippet.static_block_keep_name_helper("default"));
+          }
+        }
+        let from = class.id.as_ref().expect("should have been named").name.as_str();
         self.generated_exports.push(self.snippet.object_property_kind_object_property(
           "default",
           self.snippet.id_ref_expr(from, SPAN),
           false,
         ));
-        self
-          .snippet
-          .builder
-          .statement_expression(SPAN, Expression::ClassExpression(class.take_in(self.alloc)))
+        stmts.push(Statement::ClassDeclaration(class.take_in(self.alloc)));
       }
       ast::ExportDefaultDeclarationKind::TSInterfaceDeclaration(_) => {
         unreachable!("ExportDefaultDeclaration TSInterfaceDeclaration should be removed")
@@ -265,10 +318,7 @@ impl<'ast> IsolatingModuleFinalizer<'_, 'ast> {
                 self.snippet.id_ref_expr(from, SPAN),
                 false,
               ));
-              return Some(self.snippet.builder.statement_expression(
-                SPAN,
-                Expression::FunctionExpression(func_decl.take_in(self.alloc)),
-              ));
+              return Some(Statement::FunctionDeclaration(func_decl.take_in(self.alloc)));
             }
             ast::Declaration::ClassDeclaration(class_decl) => {
               let from =
@@ -278,32 +328,35 @@ impl<'ast> IsolatingModuleFinalizer<'_, 'ast> {
                 self.snippet.id_ref_expr(from, SPAN),
                 false,
               ));
-              return Some(self.snippet.builder.statement_expression(
-                SPAN,
-                Expression::ClassExpression(class_decl.take_in(self.alloc)),
-              ));
+              return Some(Statement::ClassDeclaration(class_decl.take_in(self.alloc)));
             }
             _ => {}
           }
         }
 
-        self.generated_exports.extend(export_named_decl.specifiers.iter().map(|specifier| {
-          self.snippet.object_property_kind_object_property(
-            &specifier.exported.name(),
-            match &specifier.local {
-              ast::ModuleExportName::IdentifierName(ident) => {
-                self.snippet.id_ref_expr(ident.name.as_str(), SPAN)
-              }
-              ast::ModuleExportName::StringLiteral(_) => {
-                unreachable!("ModuleExportName StringLiteral is invalid in ExportNamedDeclaration without source")
-              }
-              ast::ModuleExportName::IdentifierReference(ident) => {
-                self.snippet.id_ref_expr(ident.name.as_str(), SPAN)
-              }
+        let mut generated_exports = Vec::with_capacity(export_named_decl.specifiers.len());
+        for specifier in &export_named_decl.specifiers {
+          let local = match &specifier.local {
+            ast::ModuleExportName::IdentifierName(ident) => {
+              self.snippet.id_ref_expr(ident.name.as_str(), SPAN)
+            }
+            ast::ModuleExportName::StringLiteral(_) => {
+              unreachable!("ModuleExportName StringLiteral is invalid in ExportNamedDeclaration without source")
+            }
+            // Re-exported imports read the namespace object of the importee, so they stay live.
+            ast::ModuleExportName::IdentifierReference(ident) => match self.named_import_for(ident)
+            {
+              Some(named_import) => self.named_import_expr(named_import, SPAN),
+              None => self.snippet.id_ref_expr(ident.name.as_str(), SPAN),
             },
-            matches!(specifier.exported, ast::ModuleExportName::StringLiteral(_)
-          ))
-        }));
+          };
+          generated_exports.push(self.snippet.object_property_kind_object_property(
+            &specifier.exported.name(),
+            local,
+            matches!(specifier.exported, ast::ModuleExportName::StringLiteral(_)),
+          ));
+        }
+        self.generated_exports.extend(generated_exports);
         None
       }
     }
@@ -361,23 +414,138 @@ impl<'ast> IsolatingModuleFinalizer<'_, 'ast> {
     ));
   }
 
-  fn create_namespace_object_ref_for_module(&self, module: &Module) -> CompactStr {
-    match module {
+  fn create_namespace_object_ref_for_module(&mut self, module: &Module) -> CompactStr {
+    if let Some(name) = self.namespace_names.get(&module.idx()) {
+      return name.clone();
+    }
+    let name = match module {
       Module::Normal(importee) => {
-        // TODO deconflict namespace_ref
-        importee.namespace_object_ref.name(self.ctx.symbol_db).into()
+        self.generate_unique_name(importee.namespace_object_ref.name(self.ctx.symbol_db))
       }
       Module::External(external_module) => {
-        // TODO need to generate one symbol and deconflict it
-        legitimize_identifier_name(&external_module.name).into()
+        self.generate_unique_name(&legitimize_identifier_name(&external_module.name))
       }
+    };
+    self.namespace_names.insert(module.idx(), name.clone());
+    name
+  }
+
+  fn default_export_name(&mut self) -> CompactStr {
+    if let Some(name) = &self.default_export_name {
+      return name.clone();
     }
+    let name =
+      self.generate_unique_name(self.ctx.module.default_export_ref.name(self.ctx.symbol_db));
+    self.default_export_name = Some(name.clone());
+    name
+  }
+
+  /// Returns the import that declares the binding `ident` refers to, if any.
+  fn named_import_for(&self, ident: &ast::IdentifierReference) -> Option<&'me NamedImport> {
+    let ctx = self.ctx;
+    let symbol_id = self.scope.symbol_id_for(
+      ident.reference_id.get()?,
+      ctx.symbol_db.this_method_should_be_removed_get_symbol_table(ctx.module.idx),
+    )?;
+    let symbol_ref: SymbolRef = (ctx.module.idx, symbol_id).into();
+    ctx.module.named_imports.get(&symbol_ref)
+  }
+
+  /// `foo_exports` for `import * as foo` and `foo_exports.foo` for `import { foo }`.
+  fn named_import_expr(&mut self, named_import: &NamedImport, span: Span) -> Expression<'ast> {
+    let rec = &self.ctx.module.import_records[named_import.record_id];
+    let namespace_object_ref =
+      self.create_namespace_object_ref_for_module(&self.ctx.modules[rec.resolved_module]);
+
+    match &named_import.imported {
+      Specifier::Star => self.snippet.id_ref_expr(namespace_object_ref.as_str(), span),
+      Specifier::Literal(imported) => {
+        Expression::StaticMemberExpression(self.snippet.builder.alloc_static_member_expression(
+          span,
+          self.snippet.id_ref_expr(namespace_object_ref.as_str(), SPAN),
+          self.snippet.builder.identifier_name(SPAN, imported.as_str()),
+          false,
+        ))
+      }
+    }
+  }
+
+  /// Lets `resolve_file_url` hooks render `import.meta.ROLLUP_FILE_URL_<referenceId>` and
+  /// `resolve_import_meta` hooks render other `import.meta.<property>`.
+  fn try_rewrite_import_meta_prop_with_plugins(
+    &mut self,
+    member_expr: &ast::StaticMemberExpression<'ast>,
+  ) -> Option<Expression<'ast>> {
+    let property = member_expr.property.name.as_str();
+    if let Some(code) = property.strip_prefix("ROLLUP_FILE_URL_").and_then(|reference_id| {
+      file_url_code(
+        self.ctx.plugin_driver,
+        self.ctx.options,
+        self.ctx.chunk,
+        &self.ctx.module.id,
+        reference_id,
+      )
+    }) {
+      return self.expr_from_plugin_code(code);
+    }
+    self.resolve_import_meta_with_plugins(Some(property))
+  }
+
+  /// `property` is `None` for a bare `import.meta`.
+  fn resolve_import_meta_with_plugins(
+    &mut self,
+    property: Option<&str>,
+  ) -> Option<Expression<'ast>> {
+    let hook_ret = self.ctx.plugin_driver.resolve_import_meta(&HookResolveImportMetaArgs {
+      property,
+      chunk_id: self.chunk_filename(),
+      format: self.ctx.options.format,
+      module_id: &self.ctx.module.id,
+    });
+    self.expr_from_plugin_code(hook_ret)
+  }
+
+  /// Lets `render_dynamic_import` hooks render `import('...')`. Modules are defined by their stable
+  /// ids in the app format, so the stable id of the importee is the specifier.
+  fn try_render_dynamic_import_with_plugins(
+    &mut self,
+    import_expr: &ast::ImportExpression<'ast>,
+  ) -> Option<Expression<'ast>> {
+    if !matches!(import_expr.source, Expression::StringLiteral(_))
+      || !import_expr.arguments.is_empty()
+    {
+      return None;
+    }
+    let rec_id = self.ctx.module.imports.get(&import_expr.span)?;
+    let importee = &self.ctx.modules[self.ctx.module.import_records[*rec_id].resolved_module];
+    let hook_ret = self.ctx.plugin_driver.render_dynamic_import(&HookRenderDynamicImportArgs {
+      chunk_id: self.chunk_filename(),
+      format: self.ctx.options.format,
+      module_id: &self.ctx.module.id,
+      target_module_id: importee.id(),
+      specifier: importee.stable_id(),
+    });
+    let code = hook_ret
+      .map(|output| output.map(|output| dynamic_import_code(&output, importee.stable_id())));
+    self.expr_from_plugin_code(code)
+  }
+
+  fn expr_from_plugin_code(
+    &mut self,
+    hook_ret: anyhow::Result<Option<String>>,
+  ) -> Option<Expression<'ast>> {
+    expr_from_plugin_code(&self.snippet, hook_ret, &self.ctx.module.id, &mut self.errors)
+  }
+
+  fn chunk_filename(&self) -> &'me str {
+    self.ctx.chunk.preliminary_filename.as_deref().expect("This chunk should have a filename")
   }
 
-  fn get_importee_module(&self, span: Span) -> &Module {
-    let rec_id = self.ctx.module.imports[&span];
-    let rec = &self.ctx.module.import_records[rec_id];
-    &self.ctx.modules[rec.resolved_module]
+  fn get_importee_module(&self, span: Span) -> &'me Module {
+    let ctx = self.ctx;
+    let rec_id = ctx.module.imports[&span];
+    let rec = &ctx.module.import_records[rec_id];
+    &ctx.modules[rec.resolved_module]
   }
 
   fn get_interop(&self, importee: &Module) -> Option<Interop> {
@@ -1,11 +1,18 @@
 use oxc::{
   allocator::Allocator,
   ast::ast::{ObjectPropertyKind, Statement},
+  semantic::NodeId,
   span::CompactStr,
 };
-use rolldown_common::{AstScopes, IndexModules, NormalModule, SymbolRefDb};
+use rolldown_common::{
+  AstScopes, Chunk, IndexModules, ModuleIdx, NormalModule, NormalizedBundlerOptions, SymbolRef,
+  SymbolRefDb,
+};
 use rolldown_ecmascript_utils::AstSnippet;
-use rustc_hash::FxHashSet;
+use rolldown_error::BuildDiagnostic;
+use rolldown_plugin::SharedPluginDriver;
+use rolldown_utils::concat_string;
+use rustc_hash::{FxHashMap, FxHashSet};
 
 mod impl_visit_mut;
 
@@ -13,6 +20,34 @@ pub struct IsolatingModuleFinalizerContext<'me> {
   pub module: &'me NormalModule,
   pub modules: &'me IndexModules,
   pub symbol_db: &'me SymbolRefDb,
+  pub options: &'me NormalizedBundlerOptions,
+  pub plugin_driver: &'me SharedPluginDriver,
+  /// The chunk the module is rendered into.
+  pub chunk: &'me Chunk,
+}
+
+impl IsolatingModuleFinalizerContext<'_> {
+  /// Names that are declared or referenced in the module. Generated names must not shadow them.
+  pub fn collect_used_names(&self) -> FxHashSet<CompactStr> {
+    let symbol_table =
+      self.symbol_db.this_method_should_be_removed_get_symbol_table(self.module.idx);
+    // Facade symbols, such as `default_export_ref`, are not declared in the code, and import
+    // declarations are removed.
+    let mut used_names = symbol_table
+      .symbol_ids()
+      .filter(|symbol_id| symbol_table.get_declaration(*symbol_id) != NodeId::DUMMY)
+      .filter(|symbol_id| {
+        !self.module.named_imports.contains_key(&SymbolRef::from((self.module.idx, *symbol_id)))
+      })
+      .map(|symbol_id| CompactStr::new(symbol_table.get_name(symbol_id)))
+      .collect::<FxHashSet<_>>();
+    used_names.extend(
+      self.module.scope.root_unresolved_references().keys().map(|name| CompactStr::new(name)),
+    );
+    // Parameters of the module wrapper.
+    used_names.extend(["exports", "module", "require"].map(CompactStr::new_const));
+    used_names
+  }
 }
 
 pub struct IsolatingModuleFinalizer<'me, 'ast> {
@@ -23,4 +58,22 @@ pub struct IsolatingModuleFinalizer<'me, 'ast> {
   pub generated_imports_set: FxHashSet<CompactStr>,
   pub generated_imports: oxc::allocator::Vec<'ast, Statement<'ast>>,
   pub generated_exports: oxc::allocator::Vec<'ast, ObjectPropertyKind<'ast>>,
+  pub used_names: FxHashSet<CompactStr>,
+  /// Deconflicted names of the namespace objects of the imported modules.
+  pub namespace_names: FxHashMap<ModuleIdx, CompactStr>,
+  /// Deconflicted name of `default_export_ref`, generated on first use.
+  pub default_export_name: Option<CompactStr>,
+  pub errors: Vec<BuildDiagnostic>,
+}
+
+impl IsolatingModuleFinalizer<'_, '_> {
+  fn generate_unique_name(&mut self, hint: &str) -> CompactStr {
+    let mut name = CompactStr::new(hint);
+    let mut next_conflict_index = 0;
+    while !self.used_names.insert(name.clone()) {
+      next_conflict_index += 1;
+      name = concat_string!(hint, "$", itoa::Buffer::new().format(next_conflict_index)).into();
+    }
+    name
+  }
 }
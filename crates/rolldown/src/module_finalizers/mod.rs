@@ -1,2 +1,98 @@
+use arcstr::ArcStr;
+use oxc::ast::ast::Expression;
+use rolldown_common::{Chunk, NormalizedBundlerOptions, OutputFormat, Platform};
+use rolldown_ecmascript_utils::AstSnippet;
+use rolldown_error::{BuildDiagnostic, Severity};
+use rolldown_plugin::{HookRenderDynamicImportOutput, HookResolveFileUrlArgs, PluginDriver};
+use rolldown_std_utils::PathExt;
+use rolldown_utils::concat_string;
+use sugar_path::SugarPath;
+
 pub mod isolating;
 pub mod scope_hoisting;
+
+/// Turns code returned by plugin hooks into an expression. Errors are collected in `errors` and
+/// reported after finalizing.
+fn expr_from_plugin_code<'ast>(
+  snippet: &AstSnippet<'ast>,
+  hook_ret: anyhow::Result<Option<String>>,
+  module_id: &str,
+  errors: &mut Vec<BuildDiagnostic>,
+) -> Option<Expression<'ast>> {
+  match hook_ret {
+    Ok(Some(code)) => match snippet.parse_expr(&code) {
+      Ok(expr) => Some(expr),
+      Err(diagnostics) => {
+        errors.extend(BuildDiagnostic::from_oxc_diagnostics(
+          diagnostics,
+          &ArcStr::from(code),
+          module_id,
+          &Severity::Error,
+        ));
+        None
+      }
+    },
+    Ok(None) => None,
+    Err(err) => {
+      errors.push(BuildDiagnostic::unhandleable_error(err));
+      None
+    }
+  }
+}
+
+/// The code of `import.meta.ROLLUP_FILE_URL_<referenceId>` in a module of `chunk`. It's the url of
+/// the emitted file, unless `resolve_file_url` hooks return code. `None` if the reference id is
+/// unknown.
+fn file_url_code(
+  plugin_driver: &PluginDriver,
+  options: &NormalizedBundlerOptions,
+  chunk: &Chunk,
+  module_id: &str,
+  reference_id: &str,
+) -> Option<anyhow::Result<Option<String>>> {
+  let file_name = plugin_driver.file_emitter.try_get_file_name(reference_id).ok()?;
+
+  let chunk_filename =
+    chunk.absolute_preliminary_filename.as_ref().expect("This chunk should have a filename");
+  let importer_dir = chunk_filename.as_path().parent().unwrap();
+  let file_path = file_name.as_path().absolutize_with(options.cwd.join(&options.out_dir));
+  let relative_path = file_path.relative(importer_dir).as_path().expect_to_slash();
+
+  let hook_ret = plugin_driver.resolve_file_url(&HookResolveFileUrlArgs {
+    chunk_id: chunk.preliminary_filename.as_deref().expect("This chunk should have a filename"),
+    file_name: &file_name,
+    relative_path: &relative_path,
+    reference_id: Some(reference_id),
+    format: options.format,
+    module_id,
+  });
+  Some(hook_ret.map(|code| code.or_else(|| default_file_url_code(&relative_path, options))))
+}
+
+/// The code of `import.meta.ROLLUP_FILE_URL_<referenceId>` if no `resolve_file_url` hook returns
+/// any. `relative_path` is the path of the file relative to the chunk.
+fn default_file_url_code(
+  relative_path: &str,
+  options: &NormalizedBundlerOptions,
+) -> Option<String> {
+  let quoted_path = serde_json::to_string(relative_path).ok()?;
+  Some(match (options.format, options.platform) {
+    (OutputFormat::Esm, _) => concat_string!("new URL(", quoted_path, ", import.meta.url).href"),
+    (OutputFormat::Cjs, Platform::Node) => {
+      let quoted_path = serde_json::to_string(&concat_string!("/", relative_path)).ok()?;
+      concat_string!("require(\"url\").pathToFileURL(__dirname + ", quoted_path, ").href")
+    }
+    // Scripts have no `import.meta.url`, resolve the file against the script instead.
+    _ => concat_string!(
+      "new URL(",
+      quoted_path,
+      ", document.currentScript && document.currentScript.src || document.baseURI).href"
+    ),
+  })
+}
+
+/// The code of `import('<specifier>')` rendered by a `render_dynamic_import` hook.
+fn dynamic_import_code(output: &HookRenderDynamicImportOutput, specifier: &str) -> String {
+  let quoted_specifier = specifier.replace('\\', "\\\\").replace('\'', "\\'");
+  concat_string!(output.left, "'", quoted_specifier, "'", output.right)
+}
@@ -3,6 +3,7 @@ use rolldown_common::{
   IndexModules, ModuleIdx, NormalModule, RuntimeModuleBrief, SymbolRef, SymbolRefDb,
 };
 
+use rolldown_plugin::SharedPluginDriver;
 use rolldown_rstr::Rstr;
 use rustc_hash::FxHashMap;
 
@@ -23,6 +24,7 @@ pub struct ScopeHoistingFinalizerContext<'me> {
   pub runtime: &'me RuntimeModuleBrief,
   pub chunk_graph: &'me ChunkGraph,
   pub options: &'me SharedOptions,
+  pub plugin_driver: &'me SharedPluginDriver,
   pub cur_stmt_index: usize,
   pub keep_name_statement_to_insert: Vec<(usize, SymbolId, Rstr, Rstr)>,
 }
@@ -285,10 +285,20 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
       ast::Expression::ImportExpression(import_expr) => {
         if let Some(new_expr) = self.try_rewrite_inline_dynamic_import_expr(import_expr) {
           *expr = new_expr;
+        } else if let Some(new_expr) = self.try_render_dynamic_import_with_plugins(import_expr) {
+          // Code returned by plugins is final, don't walk it.
+          *expr = new_expr;
+          return;
         }
       }
       ast::Expression::NewExpression(new_expr) => {
-        self.handle_new_url_with_string_literal_and_import_meta_url(new_expr);
+        if let Some(new_expr) =
+          self.try_rewrite_new_url_with_string_literal_and_import_meta_url(new_expr)
+        {
+          // Code returned by plugins is final, don't walk it.
+          *expr = new_expr;
+          return;
+        }
       }
       ast::Expression::Identifier(ident_ref) => {
         if let Some(new_expr) = self.try_rewrite_identifier_reference_expr(ident_ref, false) {
@@ -307,6 +317,34 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
           }
         }
       }
+      ast::Expression::StaticMemberExpression(member_expr)
+        if member_expr.object.is_import_meta() =>
+      {
+        if let Some(new_expr) = self.try_rewrite_import_meta_file_url(member_expr) {
+          *expr = new_expr;
+          return;
+        }
+        if let Some(new_expr) = self.try_resolve_import_meta_with_plugins(member_expr) {
+          // Code returned by plugins is final, don't walk it.
+          *expr = new_expr;
+          return;
+        }
+        if let Some(new_expr) = self.try_rewrite_import_meta_prop_expr(member_expr) {
+          *expr = new_expr;
+        } else {
+          // The object is not a bare `import.meta`, don't walk into it.
+          return;
+        }
+      }
+      ast::Expression::MetaProperty(meta_prop)
+        if meta_prop.meta.name == "import" && meta_prop.property.name == "meta" =>
+      {
+        if let Some(new_expr) = self.resolve_import_meta_with_plugins(None) {
+          // Code returned by plugins is final, don't walk it.
+          *expr = new_expr;
+          return;
+        }
+      }
       _ => {
         if let Some(new_expr) =
           expr.as_member_expression().and_then(|expr| self.try_rewrite_member_expr(expr))
@@ -332,6 +370,9 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
           unreachable!("Always rewrite to MemberExpression for nested MemberExpression")
         }
       }
+    } else if matches!(expr, ast::MemberExpression::StaticMemberExpression(member_expr) if member_expr.object.is_import_meta())
+    {
+      // The object is not a bare `import.meta`, don't walk into it.
     } else {
       if let Some(ref_id) = self.try_get_valid_namespace_alias_ref_id_from_member_expr(expr) {
         self.interested_namespace_alias_ref_id.insert(ref_id);
@@ -366,7 +407,12 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
   fn visit_import_expression(&mut self, expr: &mut ast::ImportExpression<'ast>) {
     // Make sure the import expression is in correct form. If it's not, we should leave it as it is.
     match &mut expr.source {
-      ast::Expression::StringLiteral(str) if expr.arguments.len() == 0 => {
+      // `import('./foo.js')` or `import('./foo.json', { with: { type: 'json' } })`
+      ast::Expression::StringLiteral(str)
+        if expr.arguments.len() == 0
+          || (expr.arguments.len() == 1
+            && matches!(expr.arguments[0], ast::Expression::ObjectExpression(_))) =>
+      {
         let rec_id = self.ctx.module.imports[&expr.span];
         let rec = &self.ctx.module.import_records[rec_id];
         let importee_id = rec.resolved_module;
@@ -382,6 +428,8 @@ impl<'ast> VisitMut<'ast> for ScopeHoistingFinalizer<'_, 'ast> {
             let import_path = importer_chunk.import_path_for(importee_chunk);
 
             str.value = self.snippet.atom(&import_path);
+            // The importee is bundled into a js chunk, import attributes no longer apply to it.
+            expr.arguments.clear();
           }
           Module::External(importee) => {
             if str.value != importee.name {
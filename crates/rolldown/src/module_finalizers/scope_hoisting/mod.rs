@@ -18,6 +18,10 @@ use rolldown_ecmascript_utils::{
   AllocatorExt, AstSnippet, BindingPatternExt, CallExpressionExt, ExpressionExt, StatementExt,
   TakeIn,
 };
+use rolldown_error::BuildDiagnostic;
+use rolldown_plugin::{
+  HookRenderDynamicImportArgs, HookResolveFileUrlArgs, HookResolveImportMetaArgs,
+};
 
 mod finalizer_context;
 mod impl_visit_mut;
@@ -43,6 +47,8 @@ pub struct ScopeHoistingFinalizer<'me, 'ast> {
   /// All `ReferenceId` of `IdentifierReference` we are interested, the `IdentifierReference` should be the object of `MemberExpression` and the property is not
   /// a `"default"` property access
   pub interested_namespace_alias_ref_id: FxHashSet<ReferenceId>,
+  /// Errors from plugin hooks called while finalizing, e.g. `resolve_import_meta`.
+  pub errors: Vec<BuildDiagnostic>,
 }
 
 impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
@@ -75,6 +81,22 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     self.finalized_expr_for_symbol_ref(self.ctx.runtime.resolve_symbol(name), false, None)
   }
 
+  fn current_chunk_filename(&self) -> &'me str {
+    let cur_chunk_idx =
+      self.ctx.chunk_graph.module_to_chunk[self.ctx.id].expect("This module should be in a chunk");
+    self.ctx.chunk_graph.chunk_table[cur_chunk_idx]
+      .preliminary_filename
+      .as_deref()
+      .expect("This chunk should have a filename")
+  }
+
+  fn expr_from_plugin_code(
+    &mut self,
+    hook_ret: anyhow::Result<Option<String>>,
+  ) -> Option<Expression<'ast>> {
+    super::expr_from_plugin_code(&self.snippet, hook_ret, &self.ctx.module.id, &mut self.errors)
+  }
+
   fn try_get_valid_namespace_alias_ref_id_from_member_expr(
     &self,
     member_expr: &MemberExpression<'ast>,
@@ -391,6 +413,32 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
   }
 
   // Handle `import.meta.xxx` expression
+  /// Let `resolve_import_meta` hooks render `import.meta.<property>`. The returned expression is
+  /// final and shouldn't be visited again.
+  pub fn try_resolve_import_meta_with_plugins(
+    &mut self,
+    member_expr: &ast::StaticMemberExpression<'ast>,
+  ) -> Option<Expression<'ast>> {
+    if !member_expr.object.is_import_meta() {
+      return None;
+    }
+    self.resolve_import_meta_with_plugins(Some(member_expr.property.name.as_str()))
+  }
+
+  /// `property` is `None` for a bare `import.meta`.
+  pub fn resolve_import_meta_with_plugins(
+    &mut self,
+    property: Option<&str>,
+  ) -> Option<Expression<'ast>> {
+    let hook_ret = self.ctx.plugin_driver.resolve_import_meta(&HookResolveImportMetaArgs {
+      property,
+      chunk_id: self.current_chunk_filename(),
+      format: self.ctx.options.format,
+      module_id: &self.ctx.module.id,
+    });
+    self.expr_from_plugin_code(hook_ret)
+  }
+
   pub fn try_rewrite_import_meta_prop_expr(
     &self,
     member_expr: &ast::StaticMemberExpression<'ast>,
@@ -466,10 +514,36 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     None
   }
 
-  pub fn handle_new_url_with_string_literal_and_import_meta_url(
-    &self,
+  /// Renders `import.meta.ROLLUP_FILE_URL_<referenceId>` as the url of the emitted file. If
+  /// `resolve_file_url` hooks return code, it's used instead. The returned expression is final and
+  /// shouldn't be visited again.
+  pub fn try_rewrite_import_meta_file_url(
+    &mut self,
+    member_expr: &ast::StaticMemberExpression<'ast>,
+  ) -> Option<Expression<'ast>> {
+    if !member_expr.object.is_import_meta() {
+      return None;
+    }
+    let reference_id = member_expr.property.name.strip_prefix("ROLLUP_FILE_URL_")?;
+    let cur_chunk_idx =
+      self.ctx.chunk_graph.module_to_chunk[self.ctx.id].expect("This module should be in a chunk");
+    // Unknown reference ids are left as is.
+    let code = super::file_url_code(
+      self.ctx.plugin_driver,
+      self.ctx.options,
+      &self.ctx.chunk_graph.chunk_table[cur_chunk_idx],
+      &self.ctx.module.id,
+      reference_id,
+    )?;
+    self.expr_from_plugin_code(code)
+  }
+
+  /// Rewrites the path in `new URL('./foo.png', import.meta.url)`. If `resolve_file_url` hooks
+  /// return code, the whole expression is replaced by it.
+  pub fn try_rewrite_new_url_with_string_literal_and_import_meta_url(
+    &mut self,
     expr: &mut ast::NewExpression<'ast>,
-  ) -> Option<()> {
+  ) -> Option<Expression<'ast>> {
     let is_callee_global_url = matches!(expr.callee.as_identifier(), Some(ident) if ident.name == "URL" && self.is_global_identifier_reference(ident));
 
     if !is_callee_global_url {
@@ -498,6 +572,10 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     let rec = &self.ctx.module.import_records[rec_idx];
 
     let importee = &self.ctx.modules[rec.resolved_module].as_normal()?;
+    if let Some(data_url) = importee.asset_view.as_ref().and_then(|view| view.data_url.as_ref()) {
+      first_arg_string_literal.value = self.snippet.atom(data_url);
+      return None;
+    }
     let chunk_idx = &self.ctx.chunk_graph.module_to_chunk[importee.idx]?;
     let chunk = &self.ctx.chunk_graph.chunk_table[*chunk_idx];
     let asset_filename = &chunk.asset_absolute_preliminary_filenames[&importee.idx];
@@ -512,10 +590,62 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
     let importee_filename = asset_filename;
     let import_path = importee_filename.relative(importer_dir).as_path().expect_to_slash();
 
+    let hook_ret = self.ctx.plugin_driver.resolve_file_url(&HookResolveFileUrlArgs {
+      chunk_id: self.current_chunk_filename(),
+      file_name: &chunk.asset_preliminary_filenames[&importee.idx],
+      relative_path: &import_path,
+      reference_id: None,
+      format: self.ctx.options.format,
+      module_id: &self.ctx.module.id,
+    });
+    if let Some(expr) = self.expr_from_plugin_code(hook_ret) {
+      return Some(expr);
+    }
+
     first_arg_string_literal.value = self.snippet.atom(&import_path);
     None
   }
 
+  /// Let `render_dynamic_import` hooks render `import('...')`. The returned expression is final and
+  /// shouldn't be visited again.
+  pub fn try_render_dynamic_import_with_plugins(
+    &mut self,
+    import_expr: &ImportExpression<'ast>,
+  ) -> Option<Expression<'ast>> {
+    if !matches!(import_expr.source, Expression::StringLiteral(_))
+      || !import_expr.arguments.is_empty()
+    {
+      return None;
+    }
+    let rec_id = self.ctx.module.imports.get(&import_expr.span)?;
+    let rec = &self.ctx.module.import_records[*rec_id];
+    let (target_module_id, specifier) = match &self.ctx.modules[rec.resolved_module] {
+      Module::Normal(importee) => {
+        let importer_chunk_id = self.ctx.chunk_graph.module_to_chunk[self.ctx.module.idx]
+          .expect("Normal module should belong to a chunk");
+        let importer_chunk = &self.ctx.chunk_graph.chunk_table[importer_chunk_id];
+        // The importee may not have its own chunk, e.g. it's merged into the importer's chunk.
+        // Leave it to the default rendering.
+        let importee_chunk_id =
+          *self.ctx.chunk_graph.entry_module_to_entry_chunk.get(&importee.idx)?;
+        let importee_chunk = &self.ctx.chunk_graph.chunk_table[importee_chunk_id];
+        (importee.id.as_ref(), importer_chunk.import_path_for(importee_chunk))
+      }
+      Module::External(importee) => (importee.name.as_str(), importee.name.to_string()),
+    };
+
+    let hook_ret = self.ctx.plugin_driver.render_dynamic_import(&HookRenderDynamicImportArgs {
+      chunk_id: self.current_chunk_filename(),
+      format: self.ctx.options.format,
+      module_id: &self.ctx.module.id,
+      target_module_id,
+      specifier: &specifier,
+    });
+    let code =
+      hook_ret.map(|output| output.map(|output| super::dynamic_import_code(&output, &specifier)));
+    self.expr_from_plugin_code(code)
+  }
+
   /// try rewrite `foo_exports.bar` or `foo_exports['bar']`  to `bar` directly
   /// try rewrite `import.meta`
   fn try_rewrite_member_expr(
@@ -559,6 +689,8 @@ impl<'me, 'ast> ScopeHoistingFinalizer<'me, 'ast> {
             }
           }
           // these two branch are exclusive since `import.meta` is a global member_expr
+        } else if let Some(new_expr) = self.try_resolve_import_meta_with_plugins(inner_expr) {
+          return Some(new_expr);
         } else if let Some(new_expr) = self.try_rewrite_import_meta_prop_expr(inner_expr) {
           return Some(new_expr);
         }
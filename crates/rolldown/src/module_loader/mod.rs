@@ -1,5 +1,5 @@
 pub mod module_loader;
-mod module_task;
+pub(crate) mod module_task;
 mod runtime_module_task;
 pub mod task_context;
 pub use module_loader::ModuleLoader;
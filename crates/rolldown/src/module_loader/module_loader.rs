@@ -4,6 +4,7 @@ use super::task_context::TaskContextMeta;
 use crate::module_loader::task_context::TaskContext;
 use crate::type_alias::IndexEcmaAst;
 use crate::utils::load_entry_module::load_entry_module;
+use crate::utils::load_source::get_module_loader_from_file_extension;
 use arcstr::ArcStr;
 use oxc::semantic::{ScopeId, SymbolTable};
 use oxc::transformer::ReplaceGlobalDefinesConfig;
@@ -13,8 +14,9 @@ use rolldown_common::side_effects::{DeterminedSideEffects, HookSideEffects};
 use rolldown_common::{
   Cache, EcmaRelated, EntryPoint, EntryPointKind, ExternalModule, ImportKind, ImportRecordIdx,
   ImporterRecord, Module, ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg, ModuleSideEffects,
-  ModuleTable, ModuleType, NormalModuleTaskResult, ResolvedId, RuntimeModuleBrief,
-  RuntimeModuleTaskResult, SymbolRefDb, SymbolRefDbForModule, TreeshakeOptions, RUNTIME_MODULE_ID,
+  ModuleTable, ModuleType, NormalModule, NormalModuleTaskResult, RawImportRecord, ResolvedId,
+  RuntimeModuleBrief, RuntimeModuleTaskResult, SymbolRefDb, SymbolRefDbForModule, TreeshakeOptions,
+  RUNTIME_MODULE_ID,
 };
 use rolldown_error::{BuildDiagnostic, BuildResult};
 use rolldown_fs::OsFileSystem;
@@ -56,6 +58,9 @@ pub struct ModuleLoader {
   pub tx: tokio::sync::mpsc::Sender<ModuleLoaderMsg>,
   rx: tokio::sync::mpsc::Receiver<ModuleLoaderMsg>,
   visited: FxHashMap<ArcStr, ModuleIdx>,
+  /// Module types asserted by the import that first loaded a module, used to detect importers that
+  /// assert conflicting types. `None` means the module was loaded without import attributes.
+  asserted_module_types: FxHashMap<ModuleIdx, Option<ModuleType>>,
   runtime_id: ModuleIdx,
   remaining: u32,
   intermediate_normal_modules: IntermediateNormalModules,
@@ -81,6 +86,7 @@ impl ModuleLoader {
     resolver: SharedResolver,
     plugin_driver: SharedPluginDriver,
     cache: Arc<Cache>,
+    watch_mode: bool,
   ) -> BuildResult<Self> {
     // 1024 should be enough for most cases
     // over 1024 pending tasks are insane
@@ -97,6 +103,7 @@ impl ModuleLoader {
             .collect::<Vec<BuildDiagnostic>>()
         })?
       },
+      is_module_cache_enabled: watch_mode || options.experimental.is_incremental_build_enabled(),
     };
 
     let shared_context = Arc::new(TaskContext {
@@ -127,6 +134,7 @@ impl ModuleLoader {
       intermediate_normal_modules,
       symbol_ref_db: SymbolRefDb::default(),
       visited: FxHashMap::from_iter([(RUNTIME_MODULE_ID.into(), runtime_id)]),
+      asserted_module_types: FxHashMap::default(),
     })
   }
 
@@ -141,6 +149,7 @@ impl ModuleLoader {
       std::collections::hash_map::Entry::Occupied(visited) => *visited.get(),
       std::collections::hash_map::Entry::Vacant(not_visited) => {
         let idx = self.intermediate_normal_modules.alloc_ecma_module_idx();
+        self.asserted_module_types.insert(idx, assert_module_type.clone());
 
         if resolved_id.is_external {
           let external_module_side_effects =
@@ -179,6 +188,7 @@ impl ModuleLoader {
               imported_ids: FxIndexSet::default(),
               dynamically_imported_ids: FxIndexSet::default(),
               exports: vec![],
+              meta: None,
             }),
           );
 
@@ -191,8 +201,13 @@ impl ModuleLoader {
             legitimize_identifier_name(resolved_id.id.as_str()).as_ref(),
           );
 
-          let ext =
-            ExternalModule::new(idx, resolved_id.id, external_module_side_effects, symbol_ref);
+          let ext = ExternalModule::new(
+            idx,
+            resolved_id.id,
+            external_module_side_effects,
+            symbol_ref,
+            assert_module_type,
+          );
           self.intermediate_normal_modules.modules[idx] = Some(ext.into());
         } else {
           self.remaining += 1;
@@ -214,6 +229,52 @@ impl ModuleLoader {
     }
   }
 
+  /// A module is only loaded once, so all importers must agree on how it's loaded. Importing without
+  /// import attributes loads the module by its extension, which only agrees with a `type` that loads
+  /// it the same way. `type: "css"` never does, it turns the css into a CSS module script.
+  fn check_asserted_module_type(
+    &self,
+    importee: ModuleIdx,
+    raw_rec: &RawImportRecord,
+    importer: &NormalModule,
+    type_by_extension: Option<&ModuleType>,
+  ) -> Option<BuildDiagnostic> {
+    let asserted =
+      raw_rec.asserted_module_type.as_ref().and_then(ModuleType::as_import_attribute_type);
+    let loaded_as = self
+      .asserted_module_types
+      .get(&importee)?
+      .as_ref()
+      .and_then(ModuleType::as_import_attribute_type);
+    let is_compatible = match (asserted, loaded_as) {
+      (Some(asserted), Some(loaded_as)) => asserted == loaded_as,
+      (None, None) => true,
+      (Some(ty), None) | (None, Some(ty)) => {
+        ty != "css" && type_by_extension.and_then(ModuleType::as_import_attribute_type) == Some(ty)
+      }
+    };
+    if is_compatible {
+      return None;
+    }
+    let describe = |ty: Option<&str>| {
+      ty.map_or_else(
+        || "without import attributes".to_string(),
+        |ty| format!("with `type: {ty:?}`"),
+      )
+    };
+    Some(BuildDiagnostic::invalid_import_attribute(
+      ArcStr::from(importer.id.as_ref()),
+      importer.source.clone(),
+      raw_rec.span,
+      format!(
+        "{:?} is imported {}, but it's already imported {} by another module.",
+        raw_rec.module_request.as_str(),
+        describe(asserted),
+        describe(loaded_as)
+      ),
+    ))
+  }
+
   #[tracing::instrument(level = "debug", skip_all)]
   pub async fn fetch_all_modules(
     mut self,
@@ -277,12 +338,22 @@ impl ModuleLoader {
                   normal_module.stable_id.as_str().into(),
                   raw_rec.span,
                 );
+                let type_by_extension =
+                  get_module_loader_from_file_extension(&info.id, &self.options.module_types);
                 let id = self.try_spawn_new_task(
                   info,
                   Some(owner),
                   false,
                   raw_rec.asserted_module_type.clone(),
                 );
+                if let Some(diagnostic) = self.check_asserted_module_type(
+                  id,
+                  &raw_rec,
+                  normal_module,
+                  type_by_extension.as_ref(),
+                ) {
+                  errors.push(diagnostic);
+                }
                 // Dynamic imported module will be considered as an entry
                 self.intermediate_normal_modules.importers[id].push(ImporterRecord {
                   kind: raw_rec.kind,
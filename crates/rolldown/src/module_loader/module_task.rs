@@ -2,7 +2,10 @@ use arcstr::ArcStr;
 use futures::future::join_all;
 use oxc::span::Span;
 use oxc_index::IndexVec;
-use rolldown_plugin::{SharedPluginDriver, __inner::resolve_id_check_external};
+use rolldown_loader_utils::text_to_string_literal;
+use rolldown_plugin::{
+  HookShouldTransformCachedModuleArgs, SharedPluginDriver, __inner::resolve_id_check_external,
+};
 use rolldown_resolver::ResolveError;
 use rolldown_rstr::Rstr;
 use rolldown_std_utils::PathExt;
@@ -15,9 +18,9 @@ use std::sync::Arc;
 use sugar_path::SugarPath;
 
 use rolldown_common::{
-  EcmaRelated, ImportKind, ImportRecordIdx, ModuleDefFormat, ModuleId, ModuleIdx, ModuleInfo,
-  ModuleLoaderMsg, ModuleType, NormalModule, NormalModuleTaskResult, RawImportRecord, ResolvedId,
-  StrOrBytes, RUNTIME_MODULE_ID,
+  CachedModuleSource, CustomField, EcmaRelated, ImportKind, ImportRecordIdx, ModuleDefFormat,
+  ModuleId, ModuleIdx, ModuleInfo, ModuleLoaderMsg, ModuleType, NormalModule,
+  NormalModuleTaskResult, RawImportRecord, ResolvedId, StrOrBytes, RUNTIME_MODULE_ID,
 };
 use rolldown_error::{
   BuildDiagnostic, BuildResult, DiagnosableArcstr, UnloadableDependencyContext,
@@ -34,9 +37,9 @@ use crate::{
 };
 
 pub struct ModuleTaskOwner {
-  source: ArcStr,
-  importer_id: Rstr,
-  importee_span: Span,
+  pub(crate) source: ArcStr,
+  pub(crate) importer_id: Rstr,
+  pub(crate) importee_span: Span,
 }
 
 impl ModuleTaskOwner {
@@ -104,14 +107,28 @@ impl ModuleTask {
         imported_ids: FxIndexSet::default(),
         dynamically_imported_ids: FxIndexSet::default(),
         exports: vec![],
+        meta: None,
       }),
     );
 
     let mut sourcemap_chain = vec![];
     let mut hook_side_effects = self.resolved_id.side_effects.take();
+    let mut meta = None;
+
+    let mut warnings = vec![];
 
-    let (mut source, module_type) =
-      self.load_source_phase(&mut sourcemap_chain, &mut hook_side_effects).await?;
+    let (mut source, mut module_type) = self
+      .load_source_phase(&mut sourcemap_chain, &mut hook_side_effects, &mut meta, &mut warnings)
+      .await?;
+
+    if matches!(module_type, ModuleType::Css)
+      && matches!(self.asserted_module_type, Some(ModuleType::Css))
+    {
+      // `import sheet from './style.css' with { type: 'css' }` imports a CSS module script, which
+      // isn't bundled as a css asset but default exports a constructable stylesheet.
+      source = StrOrBytes::Str(render_css_style_sheet_module(&source.try_into_string()?)?);
+      module_type = ModuleType::Js;
+    }
 
     let asset_view = if matches!(module_type, ModuleType::Asset) {
       let asset_source = source.into_bytes();
@@ -135,8 +152,6 @@ impl ModuleTask {
       None
     };
 
-    let mut warnings = vec![];
-
     let ret = create_ecma_view(
       &mut CreateModuleContext {
         module_index: self.module_idx,
@@ -203,6 +218,8 @@ impl ModuleTask {
       ecma_view,
       css_view,
       asset_view,
+      package_json: self.resolved_id.package_json.clone(),
+      custom_meta: meta,
     };
 
     let module_info = Arc::new(module.to_module_info(Some(&raw_import_records)));
@@ -234,6 +251,9 @@ impl ModuleTask {
     &self,
     sourcemap_chain: &mut Vec<rolldown_sourcemap::SourceMap>,
     hook_side_effects: &mut Option<rolldown_common::side_effects::HookSideEffects>,
+    meta: &mut Option<Arc<CustomField>>,
+    watch_files: &mut Vec<ArcStr>,
+    warnings: &mut Vec<BuildDiagnostic>,
   ) -> BuildResult<(StrOrBytes, ModuleType)> {
     let result = load_source(
       &self.ctx.plugin_driver,
@@ -241,8 +261,11 @@ impl ModuleTask {
       &self.ctx.fs,
       sourcemap_chain,
       hook_side_effects,
+      meta,
       &self.ctx.options,
       self.asserted_module_type.as_ref(),
+      self.owner.as_ref(),
+      warnings,
     )
     .await;
     let (source, mut module_type) = result.map_err(|err| {
@@ -264,11 +287,14 @@ impl ModuleTask {
         // Run plugin transform.
         let source = transform_source(
           &self.ctx.plugin_driver,
+          &self.ctx.options,
           &self.resolved_id,
           source,
           sourcemap_chain,
           hook_side_effects,
           &mut module_type,
+          meta,
+          watch_files,
         )
         .await?;
         source.into()
@@ -287,31 +313,77 @@ impl ModuleTask {
     Ok((source, module_type))
   }
 
-  // TODO: cache source_map_chain and hook_side_effects
-  fn load_source_with_cache(&self) -> Option<(StrOrBytes, ModuleType)> {
-    self
+  /// Returns the cached results of `load` and `transform`, unless a plugin asks to run them again.
+  async fn load_source_with_cache(&self) -> BuildResult<Option<CachedModuleSource>> {
+    // Clone the value out, so the cache isn't locked while calling plugins.
+    let Some(cached) =
+      self.ctx.cache.get_module_source(&self.resolved_id.id).map(|item| item.value().clone())
+    else {
+      return Ok(None);
+    };
+    let should_transform = self
       .ctx
-      .cache
-      .get_raw_source_and_module_type(&self.resolved_id.id)
-      .map(|item| item.value().clone())
+      .plugin_driver
+      .should_transform_cached_module(&HookShouldTransformCachedModuleArgs {
+        id: &self.resolved_id.id,
+        code: match &cached.source {
+          StrOrBytes::Str(code) => Some(code),
+          StrOrBytes::Bytes(_) => None,
+        },
+        module_type: &cached.module_type,
+      })
+      .await?;
+    Ok((!should_transform).then_some(cached))
   }
 
   async fn load_source_phase(
     &mut self,
     sourcemap_chain: &mut Vec<rolldown_sourcemap::SourceMap>,
     hook_side_effects: &mut Option<rolldown_common::side_effects::HookSideEffects>,
+    meta: &mut Option<Arc<CustomField>>,
+    warnings: &mut Vec<BuildDiagnostic>,
   ) -> BuildResult<(StrOrBytes, ModuleType)> {
-    let incremental_build_enabled = self.ctx.options.experimental.is_incremental_build_enabled();
-    if incremental_build_enabled {
-      if let Some(value) = self.load_source_with_cache() {
-        return Ok(value);
+    let is_module_cache_enabled = self.ctx.meta.is_module_cache_enabled;
+    if is_module_cache_enabled {
+      if let Some(cached) = self.load_source_with_cache().await? {
+        sourcemap_chain.extend(cached.sourcemap_chain);
+        if cached.hook_side_effects.is_some() {
+          *hook_side_effects = cached.hook_side_effects;
+        }
+        if cached.meta.is_some() {
+          *meta = cached.meta;
+        }
+        // Hooks are skipped, so the files they added need to be watched again.
+        for file in cached.watch_files {
+          self.ctx.plugin_driver.watch_files.insert(file);
+        }
+        return Ok((cached.source, cached.module_type));
       }
     }
-    let value = self.load_source_without_cache(sourcemap_chain, hook_side_effects).await?;
-    if incremental_build_enabled {
-      self.ctx.cache.insert_raw_source_and_module_type(self.resolved_id.id.clone(), value.clone());
+    let mut watch_files = vec![];
+    let (source, module_type) = self
+      .load_source_without_cache(
+        sourcemap_chain,
+        hook_side_effects,
+        meta,
+        &mut watch_files,
+        warnings,
+      )
+      .await?;
+    if is_module_cache_enabled {
+      self.ctx.cache.insert_module_source(
+        self.resolved_id.id.clone(),
+        CachedModuleSource {
+          source: source.clone(),
+          module_type: module_type.clone(),
+          sourcemap_chain: sourcemap_chain.clone(),
+          hook_side_effects: *hook_side_effects,
+          meta: meta.clone(),
+          watch_files,
+        },
+      );
     }
-    Ok(value)
+    Ok((source, module_type))
   }
 
   pub(crate) async fn resolve_id(
@@ -350,6 +422,7 @@ impl ModuleTask {
     .await
   }
 
+  #[allow(clippy::too_many_lines)]
   pub async fn resolve_dependencies(
     &mut self,
     dependencies: &IndexVec<ImportRecordIdx, RawImportRecord>,
@@ -402,6 +475,12 @@ impl ModuleTask {
                   },
                   "Module not found.".into(),
                   Some("UNRESOLVED_IMPORT"),
+                  rolldown_resolver::error::resolve_error_context(
+                    &self.ctx.fs,
+                    &e,
+                    self.resolved_id.id.as_path(),
+                    &specifier,
+                  ),
                 ));
               } else {
                 warnings.push(
@@ -415,6 +494,7 @@ impl ModuleTask {
                     },
                     "Module not found, treating it as an external dependency".into(),
                     Some("UNRESOLVED_IMPORT"),
+                    None,
                   )
                   .with_severity_warning(),
                 );
@@ -441,6 +521,12 @@ impl ModuleTask {
                 },
                 reason,
                 None,
+                rolldown_resolver::error::resolve_error_context(
+                  &self.ctx.fs,
+                  e,
+                  self.resolved_id.id.as_path(),
+                  &specifier,
+                ),
               ));
             }
           };
@@ -455,3 +541,12 @@ impl ModuleTask {
     }
   }
 }
+
+/// A constructable stylesheet holding the css, the way browsers evaluate CSS module scripts.
+fn render_css_style_sheet_module(css: &str) -> anyhow::Result<String> {
+  Ok(concat_string!(
+    "const sheet = new CSSStyleSheet();\nsheet.replaceSync(",
+    text_to_string_literal(css)?,
+    ");\nexport default sheet;\n"
+  ))
+}
@@ -92,6 +92,7 @@ impl RuntimeModuleTask {
       dynamic_import_rec_exports_usage: _,
       new_url_references,
       this_expr_replace_map: _,
+      eagerly_evaluated_imports: _,
     } = scan_result;
 
     let module = NormalModule {
@@ -140,11 +141,14 @@ impl RuntimeModuleTask {
         mutations: vec![],
         new_url_references,
         this_expr_replace_map: FxHashMap::default(),
+        eagerly_evaluated_imports: FxHashSet::default(),
         esm_namespace_in_cjs: None,
         esm_namespace_in_cjs_node_mode: None,
       },
       css_view: None,
       asset_view: None,
+      package_json: None,
+      custom_meta: None,
     };
 
     let resolved_deps = raw_import_records
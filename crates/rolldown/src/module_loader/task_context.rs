@@ -20,4 +20,6 @@ pub struct TaskContext {
 
 pub struct TaskContextMeta {
   pub replace_global_define_config: Option<ReplaceGlobalDefinesConfig>,
+  /// Reuse results of `load` and `transform` stored in [Cache] for modules that haven't changed.
+  pub is_module_cache_enabled: bool,
 }
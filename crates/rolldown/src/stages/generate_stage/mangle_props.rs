@@ -0,0 +1,261 @@
+use std::cell::Cell;
+
+use oxc::ast::{
+  ast::{
+    AssignmentTarget, AssignmentTargetMaybeDefault, AssignmentTargetProperty,
+    AssignmentTargetPropertyIdentifier, BindingProperty, IdentifierReference, ObjectProperty,
+    PropertyKey, StaticMemberExpression, StringLiteral,
+  },
+  visit::{walk, walk_mut},
+  AstBuilder, Visit, VisitMut,
+};
+use rolldown_common::{ManglePropsOptions, Module, Specifier};
+use rolldown_error::BuildResult;
+use rolldown_utils::{
+  ecmascript::short_identifier_name,
+  js_regex::HybridRegex,
+  rayon::{IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator},
+};
+use rustc_hash::{FxHashMap, FxHashSet};
+
+use crate::utils::name_cache::{read_property_name_cache, NameCacheFile};
+
+use super::GenerateStage;
+
+/// Properties with special meaning to the language or to the generated interop code.
+const ALWAYS_RESERVED: [&str; 4] = ["__esModule", "__proto__", "constructor", "prototype"];
+
+impl GenerateStage<'_> {
+  /// Renames the properties matching `mangle_props.regex` in all modules, so a property gets the
+  /// same name in every chunk.
+  pub fn mangle_props(&mut self) -> BuildResult<()> {
+    let Some(options) = &self.options.mangle_props else {
+      return Ok(());
+    };
+    let cache_path = options.name_cache.as_ref().map(|path| self.options.cwd.join(path));
+    let mut name_cache = cache_path
+      .as_deref()
+      .map(|path| read_property_name_cache(path, &self.fs))
+      .transpose()?
+      .unwrap_or_default();
+
+    let reserved = self.collect_reserved_property_names(options);
+    let runtime_id = self.link_output.runtime.id();
+    let collectors = self
+      .link_output
+      .ast_table
+      .par_iter()
+      .map(|(ast, owner)| {
+        // Properties used by the runtime helpers are never renamed.
+        let regex = (*owner != runtime_id).then_some(&options.regex);
+        let mut collector = PropertyNameCollector {
+          regex,
+          reserved: &reserved,
+          candidates: FxHashMap::default(),
+          others: FxHashSet::default(),
+        };
+        collector.visit_program(ast.program());
+        collector
+      })
+      .collect::<Vec<_>>();
+
+    let mut candidates: FxHashMap<&str, usize> = FxHashMap::default();
+    let mut taken: FxHashSet<&str> = reserved.iter().map(String::as_str).collect();
+    for collector in &collectors {
+      for (name, count) in &collector.candidates {
+        *candidates.entry(name).or_default() += count;
+      }
+      taken.extend(collector.others.iter().copied());
+    }
+    candidates.retain(|name, _| !taken.contains(name));
+
+    // Names from the cache come first so they stay stable. The rest are ordered by frequency, so the
+    // most used properties get the shortest names.
+    let mut candidates = candidates.into_iter().collect::<Vec<_>>();
+    candidates.sort_unstable_by(|(a_name, a_count), (b_name, b_count)| {
+      b_count.cmp(a_count).then_with(|| a_name.cmp(b_name))
+    });
+    let mut renames: FxHashMap<String, String> = FxHashMap::default();
+    let mut assigned: FxHashSet<String> = FxHashSet::default();
+    for (name, _) in &candidates {
+      if let Some(cached) = name_cache.get(*name) {
+        if !taken.contains(cached.as_str()) && assigned.insert(cached.clone()) {
+          renames.insert((*name).to_string(), cached.clone());
+        }
+      }
+    }
+    let mut next_name = 0;
+    for (name, _) in &candidates {
+      if renames.contains_key(*name) {
+        continue;
+      }
+      let mangled = loop {
+        let candidate = short_identifier_name(next_name);
+        next_name += 1;
+        if !taken.contains(candidate.as_str()) && !assigned.contains(&candidate) {
+          break candidate;
+        }
+      };
+      assigned.insert(mangled.clone());
+      renames.insert((*name).to_string(), mangled);
+    }
+    drop(collectors);
+
+    if !renames.is_empty() {
+      self.link_output.ast_table.par_iter_mut().for_each(|(ast, owner)| {
+        if *owner == runtime_id {
+          return;
+        }
+        ast.program.with_mut(|fields| {
+          PropertyRenamer { ast: AstBuilder::new(fields.allocator), renames: &renames }
+            .visit_program(fields.program);
+        });
+      });
+    }
+
+    if let Some(cache_path) = cache_path {
+      name_cache.extend(renames);
+      self.name_cache_files.push(NameCacheFile::from_property_name_cache(cache_path, &name_cache)?);
+    }
+    Ok(())
+  }
+
+  /// Export and import names are also property names of namespace objects and `exports`, so they
+  /// are never renamed.
+  fn collect_reserved_property_names(&self, options: &ManglePropsOptions) -> FxHashSet<String> {
+    let mut reserved = options.reserved.iter().flatten().cloned().collect::<FxHashSet<_>>();
+    reserved.extend(ALWAYS_RESERVED.iter().map(ToString::to_string));
+    for module in &self.link_output.module_table.modules {
+      let Module::Normal(module) = module else {
+        continue;
+      };
+      reserved.extend(module.named_exports.keys().map(ToString::to_string));
+      reserved.extend(
+        self.link_output.metas[module.idx].resolved_exports.keys().map(ToString::to_string),
+      );
+      reserved.extend(module.named_imports.values().filter_map(|named_import| {
+        match &named_import.imported {
+          Specifier::Literal(imported) => Some(imported.to_string()),
+          Specifier::Star => None,
+        }
+      }));
+    }
+    reserved
+  }
+}
+
+struct PropertyNameCollector<'me, 'ast> {
+  /// `None` if no property of this module may be renamed.
+  regex: Option<&'me HybridRegex>,
+  reserved: &'me FxHashSet<String>,
+  /// Property names to rename, with their number of occurrences.
+  candidates: FxHashMap<&'ast str, usize>,
+  /// Names that must not be handed out as mangled names.
+  others: FxHashSet<&'ast str>,
+}
+
+impl<'ast> PropertyNameCollector<'_, 'ast> {
+  fn record(&mut self, name: &'ast str) {
+    if self.regex.is_some_and(|regex| regex.matches(name)) && !self.reserved.contains(name) {
+      *self.candidates.entry(name).or_default() += 1;
+    } else {
+      self.others.insert(name);
+    }
+  }
+}
+
+impl<'ast> Visit<'ast> for PropertyNameCollector<'_, 'ast> {
+  fn visit_static_member_expression(&mut self, it: &StaticMemberExpression<'ast>) {
+    self.record(it.property.name.as_str());
+    walk::walk_static_member_expression(self, it);
+  }
+
+  fn visit_property_key(&mut self, it: &PropertyKey<'ast>) {
+    if let PropertyKey::StaticIdentifier(ident) = it {
+      self.record(ident.name.as_str());
+    }
+    walk::walk_property_key(self, it);
+  }
+
+  fn visit_assignment_target_property_identifier(
+    &mut self,
+    it: &AssignmentTargetPropertyIdentifier<'ast>,
+  ) {
+    self.record(it.binding.name.as_str());
+    walk::walk_assignment_target_property_identifier(self, it);
+  }
+
+  // Quoted names, e.g. `obj["a"]` or `"a" in obj`, are not renamed but still may not be reused.
+  fn visit_string_literal(&mut self, it: &StringLiteral<'ast>) {
+    self.others.insert(it.value.as_str());
+  }
+}
+
+struct PropertyRenamer<'me, 'ast> {
+  ast: AstBuilder<'ast>,
+  renames: &'me FxHashMap<String, String>,
+}
+
+impl PropertyRenamer<'_, '_> {
+  fn renames_key(&self, key: &PropertyKey) -> bool {
+    matches!(key, PropertyKey::StaticIdentifier(ident) if self.renames.contains_key(ident.name.as_str()))
+  }
+}
+
+impl<'ast> VisitMut<'ast> for PropertyRenamer<'_, 'ast> {
+  fn visit_static_member_expression(&mut self, it: &mut StaticMemberExpression<'ast>) {
+    if let Some(renamed) = self.renames.get(it.property.name.as_str()) {
+      it.property.name = self.ast.atom(renamed);
+    }
+    walk_mut::walk_static_member_expression(self, it);
+  }
+
+  fn visit_object_property(&mut self, it: &mut ObjectProperty<'ast>) {
+    if self.renames_key(&it.key) {
+      it.shorthand = false;
+    }
+    walk_mut::walk_object_property(self, it);
+  }
+
+  fn visit_binding_property(&mut self, it: &mut BindingProperty<'ast>) {
+    if self.renames_key(&it.key) {
+      it.shorthand = false;
+    }
+    walk_mut::walk_binding_property(self, it);
+  }
+
+  fn visit_property_key(&mut self, it: &mut PropertyKey<'ast>) {
+    if let PropertyKey::StaticIdentifier(ident) = it {
+      if let Some(renamed) = self.renames.get(ident.name.as_str()) {
+        ident.name = self.ast.atom(renamed);
+      }
+    }
+    walk_mut::walk_property_key(self, it);
+  }
+
+  fn visit_assignment_target_property(&mut self, it: &mut AssignmentTargetProperty<'ast>) {
+    // `({ _a } = obj)` becomes `({ a: _a } = obj)`.
+    if let AssignmentTargetProperty::AssignmentTargetPropertyIdentifier(prop) = it {
+      if let Some(renamed) = self.renames.get(prop.binding.name.as_str()) {
+        let key = self.ast.property_key_identifier_name(prop.binding.span, self.ast.atom(renamed));
+        let binding = self.ast.alloc(IdentifierReference {
+          span: prop.binding.span,
+          name: prop.binding.name.clone(),
+          reference_id: Cell::new(prop.binding.reference_id.get()),
+        });
+        let binding = match prop.init.take() {
+          Some(init) => self.ast.assignment_target_maybe_default_assignment_target_with_default(
+            prop.span,
+            AssignmentTarget::AssignmentTargetIdentifier(binding),
+            init,
+          ),
+          None => AssignmentTargetMaybeDefault::AssignmentTargetIdentifier(binding),
+        };
+        *it = self.ast.assignment_target_property_assignment_target_property_property(
+          prop.span, key, binding, false,
+        );
+      }
+    }
+    walk_mut::walk_assignment_target_property(self, it);
+  }
+}
@@ -1,24 +1,85 @@
+use oxc_index::IndexVec;
+use rolldown_common::{ChunkIdx, Comments};
 use rolldown_ecmascript::EcmaCompiler;
 use rolldown_error::BuildResult;
-use rolldown_sourcemap::collapse_sourcemaps;
-use rolldown_utils::rayon::{IntoParallelRefMutIterator, ParallelIterator};
+use rolldown_sourcemap::{collapse_sourcemaps, flatten_index_sourcemap};
+use rolldown_utils::rayon::{
+  IntoParallelRefIterator, IntoParallelRefMutIterator, ParallelIterator,
+};
+use rustc_hash::FxHashMap;
 
-use crate::type_alias::IndexAssets;
+use crate::{
+  chunk_graph::ChunkGraph,
+  type_alias::IndexAssets,
+  utils::{
+    legal_comments::split_legal_comments_reference,
+    name_cache::{read_binding_name_cache, NameCacheFile},
+  },
+};
 
 use super::GenerateStage;
 
+/// Maps the rendered top-level names of a chunk to the module stable id and name of the binding.
+type TopLevelOrigins<'a> = FxHashMap<&'a str, (&'a str, &'a str)>;
+
 impl GenerateStage<'_> {
-  pub fn minify_assets(&mut self, assets: &mut IndexAssets) -> BuildResult<()> {
-    if self.options.minify {
-      assets.par_iter_mut().try_for_each(|asset| -> anyhow::Result<()> {
+  pub fn minify_assets(
+    &mut self,
+    chunk_graph: &ChunkGraph,
+    assets: &mut IndexAssets,
+  ) -> BuildResult<()> {
+    let Some(minify_options) = &self.options.minify else {
+      return Ok(());
+    };
+    let mut name_cache = self
+      .options
+      .mangle_name_cache
+      .as_deref()
+      .map(|path| read_binding_name_cache(path, &self.fs))
+      .transpose()?;
+    let chunk_origins = name_cache.as_ref().map(|_| self.collect_top_level_origins(chunk_graph));
+
+    let cache_updates = assets
+      .par_iter_mut()
+      .map(|asset| -> anyhow::Result<Vec<(String, String, String)>> {
         match asset.meta {
           rolldown_common::InstantiationKind::Ecma(_) => {
+            if let Some(index_map) = asset.index_map.take() {
+              asset.map = Some(flatten_index_sourcemap(&index_map));
+            }
+            let origins = chunk_origins.as_ref().map(|origins| &origins[asset.origin_chunk]);
+            let mut top_level_names = origins.map(|origins| {
+              let name_cache =
+                name_cache.as_ref().expect("origins are only collected with a cache");
+              origins
+                .iter()
+                .filter_map(|(rendered, (module, name))| {
+                  let cached = name_cache.get(*module)?.get(*name)?;
+                  Some(((*rendered).to_string(), cached.clone()))
+                })
+                .collect::<FxHashMap<_, _>>()
+            });
+            // The reference to the external legal comments would be dropped by the minifier.
+            let (code, legal_comments_reference) =
+              if matches!(self.options.comments, Comments::External) {
+                split_legal_comments_reference(asset.content.try_as_inner_str()?)
+              } else {
+                (asset.content.try_as_inner_str()?, None)
+              };
             // TODO: Do we need to ensure `asset.filename` to be absolute path?
-            let (minified_content, new_map) = EcmaCompiler::minify(
-              asset.content.try_as_inner_str()?,
+            let (mut minified_content, new_map) = EcmaCompiler::minify(
+              code,
+              minify_options,
+              top_level_names.as_mut(),
               asset.map.is_some(),
               &asset.filename,
             );
+            if let Some(reference) = legal_comments_reference {
+              if !minified_content.is_empty() && !minified_content.ends_with('\n') {
+                minified_content.push('\n');
+              }
+              minified_content.push_str(reference);
+            }
             asset.content = minified_content.into();
             match (&asset.map, &new_map) {
               (Some(origin_map), Some(new_map)) => {
@@ -28,13 +89,54 @@ impl GenerateStage<'_> {
                 // TODO: Map is dirty. Should we reset the `asset.map` to `None`?
               }
             }
+            let (Some(origins), Some(top_level_names)) = (origins, top_level_names) else {
+              return Ok(vec![]);
+            };
+            Ok(
+              top_level_names
+                .into_iter()
+                .filter_map(|(rendered, mangled)| {
+                  let (module, name) = origins.get(rendered.as_str())?;
+                  Some(((*module).to_string(), (*name).to_string(), mangled))
+                })
+                .collect(),
+            )
           }
-          rolldown_common::InstantiationKind::None => {}
+          rolldown_common::InstantiationKind::None => Ok(vec![]),
         }
-        Ok(())
-      })?;
+      })
+      .collect::<anyhow::Result<Vec<_>>>()?;
+
+    if let (Some(path), Some(name_cache)) = (&self.options.mangle_name_cache, name_cache.as_mut()) {
+      for (module, name, mangled) in cache_updates.into_iter().flatten() {
+        name_cache.entry(module).or_default().insert(name, mangled);
+      }
+      self.name_cache_files.push(NameCacheFile::from_binding_name_cache(path.clone(), name_cache)?);
     }
 
     Ok(())
   }
+
+  fn collect_top_level_origins<'a>(
+    &'a self,
+    chunk_graph: &'a ChunkGraph,
+  ) -> IndexVec<ChunkIdx, TopLevelOrigins<'a>> {
+    let modules = &self.link_output.module_table.modules;
+    let symbol_db = &self.link_output.symbol_db;
+    chunk_graph
+      .chunk_table
+      .par_iter()
+      .map(|chunk| {
+        chunk
+          .canonical_names
+          .iter()
+          .map(|(symbol_ref, rendered)| {
+            let module = modules[symbol_ref.owner].stable_id();
+            (rendered.as_str(), (module, symbol_ref.name(symbol_db)))
+          })
+          .collect()
+      })
+      .collect::<Vec<_>>()
+      .into()
+  }
 }
@@ -5,6 +5,7 @@ use oxc::ast::VisitMut;
 use oxc_index::IndexVec;
 use rolldown_ecmascript_utils::AstSnippet;
 use rolldown_error::BuildResult;
+use rolldown_fs::OsFileSystem;
 use rolldown_std_utils::OptionExt;
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -37,12 +38,14 @@ use crate::{
     },
     extract_meaningful_input_name_from_path::try_extract_meaningful_input_name_from_path,
     finalize_normal_module,
+    name_cache::NameCacheFile,
   },
   BundleOutput, SharedOptions,
 };
 
 mod code_splitting;
 mod compute_cross_chunk_links;
+mod mangle_props;
 mod minify_assets;
 mod render_chunk_to_assets;
 
@@ -50,6 +53,9 @@ pub struct GenerateStage<'a> {
   link_output: &'a mut LinkStageOutput,
   options: &'a SharedOptions,
   plugin_driver: &'a SharedPluginDriver,
+  fs: OsFileSystem,
+  /// Name caches updated by mangling, returned with the bundle output.
+  name_cache_files: Vec<NameCacheFile>,
 }
 
 impl<'a> GenerateStage<'a> {
@@ -57,8 +63,9 @@ impl<'a> GenerateStage<'a> {
     link_output: &'a mut LinkStageOutput,
     options: &'a SharedOptions,
     plugin_driver: &'a SharedPluginDriver,
+    fs: OsFileSystem,
   ) -> Self {
-    Self { link_output, options, plugin_driver }
+    Self { link_output, options, plugin_driver, fs, name_cache_files: vec![] }
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -85,16 +92,22 @@ impl<'a> GenerateStage<'a> {
       );
     });
 
+    self.mangle_props()?;
+
     let ast_table_iter = self.link_output.ast_table.par_iter_mut();
-    ast_table_iter
+    let finalizer_errors = ast_table_iter
       .filter(|(_ast, owner)| {
+        // The runtime module is rendered as an import of the helpers if they are not inlined.
+        if *owner == self.link_output.runtime.id() && !self.options.runtime_helpers.is_inline() {
+          return false;
+        }
         self.link_output.module_table.modules[*owner]
           .as_normal()
           .is_some_and(|m| m.meta.is_included())
       })
-      .for_each(|(ast, owner)| {
+      .flat_map(|(ast, owner)| {
         let Module::Normal(module) = &self.link_output.module_table.modules[*owner] else {
-          return;
+          return vec![];
         };
         let chunk_id = chunk_graph.module_to_chunk[module.idx].unwrap();
         let chunk = &chunk_graph.chunk_table[chunk_id];
@@ -103,6 +116,7 @@ impl<'a> GenerateStage<'a> {
           finalize_normal_module(
             module,
             ScopeHoistingFinalizerContext {
+              plugin_driver: self.plugin_driver,
               canonical_names: &chunk.canonical_names,
               id: module.idx,
               symbol_db: &self.link_output.symbol_db,
@@ -117,27 +131,40 @@ impl<'a> GenerateStage<'a> {
               keep_name_statement_to_insert: Vec::new(),
             },
             ast,
-          );
+          )
         } else {
           ast.program.with_mut(|fields| {
             let (oxc_program, alloc) = (fields.program, fields.allocator);
+            let ctx = IsolatingModuleFinalizerContext {
+              module,
+              modules: &self.link_output.module_table.modules,
+              symbol_db: &self.link_output.symbol_db,
+              options: self.options,
+              plugin_driver: self.plugin_driver,
+              chunk,
+            };
             let mut finalizer = IsolatingModuleFinalizer {
               alloc,
               scope: &module.scope,
-              ctx: &IsolatingModuleFinalizerContext {
-                module,
-                modules: &self.link_output.module_table.modules,
-                symbol_db: &self.link_output.symbol_db,
-              },
+              used_names: ctx.collect_used_names(),
+              ctx: &ctx,
               snippet: AstSnippet::new(alloc),
               generated_imports_set: FxHashSet::default(),
               generated_imports: oxc::allocator::Vec::new_in(alloc),
               generated_exports: oxc::allocator::Vec::new_in(alloc),
+              namespace_names: FxHashMap::default(),
+              default_export_name: None,
+              errors: vec![],
             };
             finalizer.visit_program(oxc_program);
-          });
+            finalizer.errors
+          })
         }
-      });
+      })
+      .collect::<Vec<_>>();
+    if !finalizer_errors.is_empty() {
+      return Err(finalizer_errors.into());
+    }
 
     self.render_chunk_to_assets(&mut chunk_graph).await
   }
@@ -254,7 +281,7 @@ impl<'a> GenerateStage<'a> {
         .await?;
 
       chunk.modules.iter().copied().filter_map(|idx| modules[idx].as_normal()).for_each(|module| {
-        if module.asset_view.is_some() {
+        if module.asset_view.as_ref().is_some_and(|view| view.data_url.is_none()) {
           let hash_placeholder = extracted_asset_hash_pattern
             .as_ref()
             .map(|p| hash_placeholder_generator.generate(p.len.unwrap_or(8)));
@@ -300,15 +327,22 @@ impl<'a> GenerateStage<'a> {
     chunk_graph.chunk_table.iter().for_each(|chunk| {
       let mut module_idx_to_filenames = FxHashMap::default();
       // replace asset name in ecma view
-      chunk.asset_preliminary_filenames.iter().for_each(|(module_idx, preliminary)| {
+      chunk.modules.iter().for_each(|module_idx| {
         let Module::Normal(module) = &mut self.link_output.module_table.modules[*module_idx] else {
           return;
         };
-        let asset_filename: ArcStr = preliminary.as_str().into();
+        let Some(asset_view) = &module.asset_view else {
+          return;
+        };
+        // Inlined assets are referenced by their data URLs.
+        let asset_filename: ArcStr = match &asset_view.data_url {
+          Some(data_url) => data_url.clone(),
+          None => chunk.asset_preliminary_filenames[module_idx].as_str().into(),
+        };
         module.ecma_view.mutations.push(Box::new(ImportMetaRolldownAssetReplacer {
           asset_filename: asset_filename.clone(),
         }));
-        module_idx_to_filenames.insert(module_idx, asset_filename);
+        module_idx_to_filenames.insert(*module_idx, asset_filename);
       });
       // replace asset name in css view
       chunk.modules.iter().for_each(|module_idx| {
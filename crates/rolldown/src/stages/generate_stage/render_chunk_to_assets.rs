@@ -1,16 +1,20 @@
 use std::path::Path;
 
+use arcstr::ArcStr;
 use futures::future::try_join_all;
 use oxc_index::{index_vec, IndexVec};
 use rolldown_common::{
-  Asset, InstantiationKind, ModuleRenderArgs, ModuleRenderOutput, Output, OutputAsset, OutputChunk,
-  SourceMapType,
+  Asset, Chunk, Comments, EmittedAsset, InstantiationKind, Module, ModuleRenderArgs,
+  ModuleRenderOutput, Output, OutputAsset, OutputChunk, RuntimeHelpers, SourceMapType, StrOrBytes,
 };
+use rolldown_ecmascript::collect_legal_comments;
 use rolldown_error::{BuildDiagnostic, BuildResult};
+use rolldown_sourcemap::{IndexSourceMap, SourceMap};
 use rolldown_utils::{
   concat_string,
   indexmap::FxIndexSet,
   rayon::{IntoParallelRefIterator, ParallelIterator},
+  xxhash::xxhash_base64_url,
 };
 use sugar_path::SugarPath;
 
@@ -22,8 +26,17 @@ use crate::{
   type_alias::{IndexChunkToAssets, IndexInstantiatedChunks},
   types::generator::{GenerateContext, Generator},
   utils::{
-    augment_chunk_hash::augment_chunk_hash, chunk::finalize_chunks::finalize_assets,
-    render_chunks::render_chunks, uuid::uuid_v4_string_from_u128,
+    apply_log_rules::apply_log_rules,
+    augment_chunk_hash::augment_chunk_hash,
+    chunk::{
+      finalize_chunks::finalize_assets, render_runtime_helpers::render_shared_runtime_helpers,
+    },
+    debug_id::{render_debug_id_manifest, render_debug_id_snippet, DebugIdManifestEntry},
+    legal_comments::{
+      render_legal_comments, render_legal_comments_reference, render_license_report,
+    },
+    render_chunks::render_chunks,
+    uuid::uuid_v4_string_from_u128,
   },
   BundleOutput,
 };
@@ -45,6 +58,8 @@ impl GenerateStage<'_> {
 
     augment_chunk_hash(self.plugin_driver, &mut instantiated_chunks).await?;
 
+    self.externalize_legal_comments(chunk_graph, &mut instantiated_chunks);
+
     let mut assets = finalize_assets(
       chunk_graph,
       instantiated_chunks,
@@ -52,12 +67,14 @@ impl GenerateStage<'_> {
       self.options.hash_characters,
     );
 
-    self.minify_assets(&mut assets)?;
+    self.minify_assets(chunk_graph, &mut assets)?;
 
     let mut output = Vec::with_capacity(assets.len());
     let mut output_assets = vec![];
+    let mut debug_id_manifest_entries = vec![];
     for Asset {
       mut map,
+      mut index_map,
       meta: rendered_chunk,
       content: code,
       file_dir,
@@ -69,53 +86,74 @@ impl GenerateStage<'_> {
       if let InstantiationKind::Ecma(ecma_meta) = rendered_chunk {
         let mut code = code.try_into_string()?;
         let rendered_chunk = ecma_meta.rendered_chunk;
-        if let Some(map) = map.as_mut() {
+        let chunk_map = match (map.as_mut(), index_map.as_mut()) {
+          (Some(map), _) => Some(ChunkSourceMap::Flat(map)),
+          (None, Some(index_map)) => Some(ChunkSourceMap::Index(index_map)),
+          (None, None) => None,
+        };
+        if let Some(mut chunk_map) = chunk_map {
           let file_base_name =
             Path::new(rendered_chunk.filename.as_str()).file_name().expect("should have file name");
-          map.set_file(file_base_name.to_string_lossy().as_ref());
+          chunk_map.set_file(file_base_name.to_string_lossy().as_ref());
 
           let map_filename = format!("{}.map", rendered_chunk.filename.as_str());
           let map_path = file_dir.join(&map_filename);
 
-          if let Some(source_map_ignore_list) = &self.options.sourcemap_ignore_list {
-            let mut x_google_ignore_list = vec![];
-            for (index, source) in map.get_sources().enumerate() {
-              if source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await? {
-                #[allow(clippy::cast_possible_truncation)]
-                x_google_ignore_list.push(index as u32);
+          for map in chunk_map.maps_mut() {
+            if let Some(source_map_ignore_list) = &self.options.sourcemap_ignore_list {
+              let mut x_google_ignore_list = vec![];
+              for (index, source) in map.get_sources().enumerate() {
+                if source_map_ignore_list.call(source, map_path.to_string_lossy().as_ref()).await? {
+                  #[allow(clippy::cast_possible_truncation)]
+                  x_google_ignore_list.push(index as u32);
+                }
+              }
+              if !x_google_ignore_list.is_empty() {
+                map.set_x_google_ignore_list(x_google_ignore_list);
               }
             }
-            if !x_google_ignore_list.is_empty() {
-              map.set_x_google_ignore_list(x_google_ignore_list);
-            }
-          }
 
-          if let Some(sourcemap_path_transform) = &self.options.sourcemap_path_transform {
-            let mut sources = Vec::with_capacity(map.get_sources().count());
-            for source in map.get_sources() {
-              sources.push(
-                sourcemap_path_transform.call(source, map_path.to_string_lossy().as_ref()).await?,
-              );
+            if let Some(sourcemap_path_transform) = &self.options.sourcemap_path_transform {
+              let mut sources = Vec::with_capacity(map.get_sources().count());
+              for source in map.get_sources() {
+                sources.push(
+                  sourcemap_path_transform
+                    .call(source, map_path.to_string_lossy().as_ref())
+                    .await?,
+                );
+              }
+              map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
             }
+
+            // Normalize the windows path at final.
+            let sources =
+              map.get_sources().map(|x| x.to_slash_lossy().to_string()).collect::<Vec<_>>();
             map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
           }
 
           if self.options.sourcemap_debug_ids && self.options.sourcemap.is_some() {
             let debug_id_str = uuid_v4_string_from_u128(rendered_chunk.debug_id);
-            map.set_debug_id(&debug_id_str);
+            chunk_map.set_debug_id(&debug_id_str);
+            if let Some(global) = &self.options.sourcemap_debug_ids_global {
+              code.push('\n');
+              code.push_str(&render_debug_id_snippet(global, &debug_id_str));
+            }
             code.push_str("\n//# debugId=");
             code.push_str(debug_id_str.as_str());
+            if self.options.sourcemap_debug_ids_manifest.is_some() {
+              debug_id_manifest_entries.push(DebugIdManifestEntry {
+                file: rendered_chunk.filename.to_string(),
+                map: (!matches!(self.options.sourcemap, Some(SourceMapType::Inline)))
+                  .then(|| map_filename.clone()),
+                debug_id: debug_id_str,
+              });
+            }
           }
 
-          // Normalize the windows path at final.
-          let sources =
-            map.get_sources().map(|x| x.to_slash_lossy().to_string()).collect::<Vec<_>>();
-          map.set_sources(sources.iter().map(std::convert::AsRef::as_ref).collect::<Vec<_>>());
-
           if let Some(sourcemap) = &self.options.sourcemap {
             match sourcemap {
               SourceMapType::File | SourceMapType::Hidden => {
-                let source = map.to_json_string();
+                let source = chunk_map.to_json_string();
                 output_assets.push(Output::Asset(Box::new(OutputAsset {
                   filename: map_filename.as_str().into(),
                   source: source.into(),
@@ -133,7 +171,7 @@ impl GenerateStage<'_> {
                 }
               }
               SourceMapType::Inline => {
-                let data_url = map.to_data_url();
+                let data_url = chunk_map.to_data_url();
                 code.push_str("\n//# sourceMappingURL=");
                 code.push_str(&data_url);
               }
@@ -173,6 +211,51 @@ impl GenerateStage<'_> {
       }
     }
 
+    if let Some(manifest_filename) = &self.options.sourcemap_debug_ids_manifest {
+      if self.options.sourcemap_debug_ids && self.options.sourcemap.is_some() {
+        output_assets.push(Output::Asset(Box::new(OutputAsset {
+          filename: manifest_filename.as_str().into(),
+          source: render_debug_id_manifest(debug_id_manifest_entries).into(),
+          original_file_names: vec![],
+          names: vec![],
+        })));
+      }
+    }
+
+    if let Some(license_report) = &self.options.license_report {
+      let modules = self
+        .link_output
+        .module_table
+        .modules
+        .iter()
+        .filter_map(Module::as_normal)
+        .filter(|module| module.is_included());
+      self.plugin_driver.file_emitter.emit_file(EmittedAsset {
+        name: None,
+        original_file_name: None,
+        file_name: Some(license_report.as_str().into()),
+        source: render_license_report(modules).into(),
+      });
+    }
+
+    if let RuntimeHelpers::Shared(file_name) = &self.options.runtime_helpers {
+      let runtime = &self.link_output.module_table.modules[self.link_output.runtime.id()];
+      if let Some(runtime) = runtime.as_normal().filter(|runtime| runtime.meta.is_included()) {
+        output.push(Output::Asset(Box::new(OutputAsset {
+          filename: file_name.as_str().into(),
+          source: render_shared_runtime_helpers(
+            self.options,
+            runtime,
+            &self.link_output.ast_table[runtime.ecma_ast_idx()].0,
+            &self.link_output.symbol_db,
+          )
+          .into(),
+          original_file_names: vec![],
+          names: vec![],
+        })));
+      }
+    }
+
     // Make sure order of assets are deterministic
     // TODO: use `preliminary_filename` on `Output::Asset` instead
     output_assets.sort_unstable_by(|a, b| a.filename().cmp(b.filename()));
@@ -189,6 +272,7 @@ impl GenerateStage<'_> {
 
     output.extend(output_assets);
 
+    apply_log_rules(self.options, &mut errors, &mut warnings);
     if !errors.is_empty() {
       return Err(errors.into());
     }
@@ -197,6 +281,7 @@ impl GenerateStage<'_> {
       assets: output,
       warnings,
       watch_files: self.plugin_driver.watch_files.iter().map(|f| f.clone()).collect(),
+      name_cache_files: std::mem::take(&mut self.name_cache_files),
     })
   }
 
@@ -317,6 +402,88 @@ impl GenerateStage<'_> {
       .collect::<Vec<_>>();
     chunk_to_codegen_ret
   }
+
+  /// With `comments: "external"`, moves the legal comments of each ecma chunk to an emitted asset
+  /// and appends a comment referencing it. It runs before hashing, so the hash of a chunk changes
+  /// along with its legal comments.
+  fn externalize_legal_comments(
+    &self,
+    chunk_graph: &ChunkGraph,
+    instantiated_chunks: &mut IndexInstantiatedChunks,
+  ) {
+    if !matches!(self.options.comments, Comments::External) {
+      return;
+    }
+    let file_emitter = &self.plugin_driver.file_emitter;
+    let mut combined_legal_comments = FxIndexSet::default();
+    for chunk in instantiated_chunks.iter_mut() {
+      let (InstantiationKind::Ecma(ecma_meta), StrOrBytes::Str(code)) =
+        (&chunk.kind, &mut chunk.content)
+      else {
+        continue;
+      };
+      let legal_comments =
+        self.collect_chunk_legal_comments(&chunk_graph.chunk_table[chunk.origin_chunk]);
+      if legal_comments.is_empty() {
+        continue;
+      }
+      let legal_filename = match &self.options.legal_comments_file {
+        Some(file) => {
+          // The reference to the combined file is the same for every chunk, so the legal comments
+          // are hashed separately.
+          let legal_comments_hash =
+            xxhash_base64_url(render_legal_comments(&legal_comments).as_bytes());
+          chunk.augment_chunk_hash = Some(concat_string!(
+            chunk.augment_chunk_hash.as_deref().unwrap_or_default(),
+            legal_comments_hash
+          ));
+          combined_legal_comments.extend(legal_comments);
+          ArcStr::from(file.as_str())
+        }
+        None => file_emitter.get_file_name(&file_emitter.emit_file(EmittedAsset {
+          name: Some(concat_string!(ecma_meta.rendered_chunk.name, ".LEGAL.txt")),
+          original_file_name: None,
+          file_name: None,
+          source: render_legal_comments(&legal_comments).into(),
+        })),
+      };
+      let chunk_path = chunk.file_dir.join(chunk.preliminary_filename.as_str());
+      let reference = chunk
+        .file_dir
+        .join(legal_filename.as_str())
+        .relative(chunk_path.parent().expect("should have parent dir"))
+        .to_slash_lossy()
+        .to_string();
+      if !code.is_empty() && !code.ends_with('\n') {
+        code.push('\n');
+      }
+      code.push_str(&render_legal_comments_reference(&reference));
+    }
+
+    if let Some(legal_comments_file) = &self.options.legal_comments_file {
+      if !combined_legal_comments.is_empty() {
+        file_emitter.emit_file(EmittedAsset {
+          name: None,
+          original_file_name: None,
+          file_name: Some(legal_comments_file.as_str().into()),
+          source: render_legal_comments(&combined_legal_comments).into(),
+        });
+      }
+    }
+  }
+
+  /// The distinct legal comments of the included modules of `chunk`, in module order.
+  fn collect_chunk_legal_comments(&self, chunk: &Chunk) -> FxIndexSet<String> {
+    chunk
+      .modules
+      .iter()
+      .filter_map(|&module_idx| self.link_output.module_table.modules[module_idx].as_normal())
+      .filter(|module| module.is_included())
+      .flat_map(|module| {
+        collect_legal_comments(self.link_output.ast_table[module.ecma_ast_idx()].0.program())
+      })
+      .collect()
+  }
 }
 
 enum SortingFileType {
@@ -325,6 +492,52 @@ enum SortingFileType {
   Asset = 2,
 }
 
+/// The source map of an ecma chunk, which is either flattened or an index map.
+enum ChunkSourceMap<'a> {
+  Flat(&'a mut SourceMap),
+  Index(&'a mut IndexSourceMap),
+}
+
+impl ChunkSourceMap<'_> {
+  fn set_file(&mut self, file: &str) {
+    match self {
+      Self::Flat(map) => map.set_file(file),
+      Self::Index(index_map) => index_map.set_file(file),
+    }
+  }
+
+  fn set_debug_id(&mut self, debug_id: &str) {
+    match self {
+      Self::Flat(map) => map.set_debug_id(debug_id),
+      Self::Index(index_map) => index_map.set_debug_id(debug_id),
+    }
+  }
+
+  /// The maps whose `sources` need to be post-processed.
+  fn maps_mut(&mut self) -> Vec<&mut SourceMap> {
+    match self {
+      Self::Flat(map) => vec![&mut **map],
+      Self::Index(index_map) => {
+        index_map.sections_mut().iter_mut().map(|section| &mut section.map).collect()
+      }
+    }
+  }
+
+  fn to_json_string(&self) -> String {
+    match self {
+      Self::Flat(map) => map.to_json_string(),
+      Self::Index(index_map) => index_map.to_json_string(),
+    }
+  }
+
+  fn to_data_url(&self) -> String {
+    match self {
+      Self::Flat(map) => map.to_data_url(),
+      Self::Index(index_map) => index_map.to_data_url(),
+    }
+  }
+}
+
 #[inline]
 fn get_sorting_file_type(output: &Output) -> SortingFileType {
   match output {
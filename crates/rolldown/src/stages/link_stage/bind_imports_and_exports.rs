@@ -638,6 +638,7 @@ impl BindImportsAndExportsContext<'_> {
         MatchImportKind::NoMatch => {
           let importee = &self.index_modules[rec.resolved_module];
           self.errors.push(BuildDiagnostic::missing_export(
+            module.id.to_string(),
             module.stable_id.to_string(),
             importee.stable_id().to_string(),
             module.source.clone(),
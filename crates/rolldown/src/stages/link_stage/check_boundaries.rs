@@ -0,0 +1,101 @@
+use std::collections::VecDeque;
+
+use oxc_index::IndexVec;
+use rolldown_common::{Module, ModuleIdx};
+use rolldown_error::BuildDiagnostic;
+use rolldown_utils::pattern_filter::{self, StringOrRegex};
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Reports imports forbidden by `checks.boundaries`, along with the chain of imports leading from
+  /// an entry to the offending importer.
+  pub(super) fn check_boundaries(&mut self) {
+    let Some(rules) = self.options.checks.boundaries.as_ref() else {
+      return;
+    };
+    let rules = rules
+      .iter()
+      .filter(|rule| rule.platform.map_or(true, |platform| platform == self.options.platform))
+      .collect::<Vec<_>>();
+    if rules.is_empty() {
+      return;
+    }
+
+    let mut importers: Option<IndexVec<ModuleIdx, Option<ModuleIdx>>> = None;
+    for module in self.module_table.modules.iter().filter_map(Module::as_normal) {
+      for rec in &module.import_records {
+        let importee = &self.module_table.modules[rec.resolved_module];
+        let Some(rule) = rules.iter().find(|rule| {
+          rule.from.as_ref().map_or(true, |from| is_matched(from, &module.id, &module.stable_id))
+            && is_matched(&rule.disallow, importee.id(), importee.stable_id())
+        }) else {
+          continue;
+        };
+
+        let importers = importers.get_or_insert_with(|| self.compute_first_importers());
+        self.errors.push(BuildDiagnostic::boundary_violation(
+          module.id.to_string(),
+          module.source.clone(),
+          importee.id().to_string(),
+          rec.state.span,
+          self.import_chain_to(module.idx, importers),
+          rule.message.clone(),
+        ));
+      }
+    }
+  }
+
+  /// Finds, for each reachable module, the module that first imports it in a breadth-first walk
+  /// from the entries. Following these importers back gives the shortest chain from an entry.
+  fn compute_first_importers(&self) -> IndexVec<ModuleIdx, Option<ModuleIdx>> {
+    let mut importers = IndexVec::from_vec(vec![None; self.module_table.modules.len()]);
+    let mut visited = IndexVec::from_vec(vec![false; self.module_table.modules.len()]);
+    let mut queue = VecDeque::new();
+    for entry in &self.entries {
+      if !visited[entry.id] {
+        visited[entry.id] = true;
+        queue.push_back(entry.id);
+      }
+    }
+
+    while let Some(idx) = queue.pop_front() {
+      let Module::Normal(module) = &self.module_table.modules[idx] else {
+        continue;
+      };
+      for rec in &module.import_records {
+        if !visited[rec.resolved_module] {
+          visited[rec.resolved_module] = true;
+          importers[rec.resolved_module] = Some(idx);
+          queue.push_back(rec.resolved_module);
+        }
+      }
+    }
+    importers
+  }
+
+  fn import_chain_to(
+    &self,
+    idx: ModuleIdx,
+    importers: &IndexVec<ModuleIdx, Option<ModuleIdx>>,
+  ) -> Vec<String> {
+    let mut chain = vec![self.module_table.modules[idx].id().to_string()];
+    let mut current = idx;
+    while let Some(importer) = importers[current] {
+      chain.push(self.module_table.modules[importer].id().to_string());
+      current = importer;
+    }
+    chain.reverse();
+    chain
+  }
+}
+
+fn is_matched(pattern: &StringOrRegex, id: &str, stable_id: &str) -> bool {
+  pattern_filter::filter(
+    None::<&[StringOrRegex]>,
+    Some(std::slice::from_ref(pattern)),
+    id,
+    stable_id,
+  )
+  .inner()
+}
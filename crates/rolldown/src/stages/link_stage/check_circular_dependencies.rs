@@ -0,0 +1,208 @@
+use std::collections::VecDeque;
+
+use oxc::semantic::SymbolFlags;
+use oxc_index::IndexVec;
+use rolldown_common::{GetLocalDb, Module, ModuleIdx, NormalModule};
+use rolldown_error::{BuildDiagnostic, CircularDependencyEagerAccess};
+use rolldown_utils::pattern_filter::{self, StringOrRegex};
+use rustc_hash::FxHashSet;
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Reports each group of modules that import each other once, with the shortest cycle through the
+  /// first executed module of the group.
+  ///
+  /// Modules matching `checks.circular_dependency_ignore` are removed from the module graph first,
+  /// so cycles passing through them are not reported.
+  pub(super) fn check_circular_dependencies(&mut self) {
+    if !self.options.checks.circular_dependency.unwrap_or(false) {
+      return;
+    }
+
+    let ignore = self.options.checks.circular_dependency_ignore.as_deref().unwrap_or_default();
+    let graph = self
+      .module_table
+      .modules
+      .iter()
+      .map(|module| match module {
+        Module::Normal(module) => {
+          if is_ignored(ignore, module) {
+            return vec![];
+          }
+          module
+            .import_records
+            .iter()
+            .filter(|rec| rec.kind.is_static())
+            .map(|rec| rec.resolved_module)
+            .filter(|idx| {
+              self.module_table.modules[*idx]
+                .as_normal()
+                .is_some_and(|importee| !is_ignored(ignore, importee))
+            })
+            .collect()
+        }
+        Module::External(_) => vec![],
+      })
+      .collect::<IndexVec<ModuleIdx, Vec<ModuleIdx>>>();
+
+    let mut groups = strongly_connected_components(&graph)
+      .into_iter()
+      .filter(|group| group.len() > 1 || graph[group[0]].contains(&group[0]))
+      .map(|group| {
+        let first = group
+          .iter()
+          .copied()
+          .min_by_key(|idx| self.module_table.modules[*idx].exec_order())
+          .expect("groups are never empty");
+        (first, group)
+      })
+      .collect::<Vec<_>>();
+    groups.sort_by_key(|(first, _)| self.module_table.modules[*first].exec_order());
+
+    for (first, group) in groups {
+      let members = group.iter().copied().collect::<FxHashSet<_>>();
+      let paths = shortest_cycle(&graph, &members, first)
+        .into_iter()
+        .map(|idx| self.module_table.modules[idx].id().to_string())
+        .collect();
+      let eager_access = self.find_eager_access(&group, &members);
+      self.warnings.push(
+        BuildDiagnostic::circular_dependency(paths, group.len(), eager_access)
+          .with_severity_warning(),
+      );
+    }
+  }
+
+  /// Finds a module of the group reading a `let`, `const` or `class` binding of another module of
+  /// the group that is executed after it.
+  fn find_eager_access(
+    &self,
+    group: &[ModuleIdx],
+    members: &FxHashSet<ModuleIdx>,
+  ) -> Option<CircularDependencyEagerAccess> {
+    let mut importers = group
+      .iter()
+      .filter_map(|idx| self.module_table.modules[*idx].as_normal())
+      .collect::<Vec<_>>();
+    importers.sort_by_key(|module| module.exec_order);
+
+    importers.into_iter().find_map(|importer| {
+      let mut imports = importer.eagerly_evaluated_imports.iter().copied().collect::<Vec<_>>();
+      imports.sort_unstable();
+      imports.into_iter().find_map(|import_ref| {
+        let canonical_ref = self.symbols.canonical_ref_for(import_ref);
+        let importee = self.module_table.modules[canonical_ref.owner].as_normal()?;
+        let flags = self.symbols.local_db(canonical_ref.owner).get_flags(canonical_ref.symbol);
+        let is_lexical = flags.intersects(SymbolFlags::BlockScopedVariable | SymbolFlags::Class);
+        (is_lexical && members.contains(&importee.idx) && importee.exec_order > importer.exec_order)
+          .then(|| CircularDependencyEagerAccess {
+            importer: importer.id.to_string(),
+            importee: importee.id.to_string(),
+            binding: import_ref.name(&self.symbols).to_string(),
+          })
+      })
+    })
+  }
+}
+
+fn is_ignored(patterns: &[StringOrRegex], module: &NormalModule) -> bool {
+  !patterns.is_empty()
+    && pattern_filter::filter(
+      None::<&[StringOrRegex]>,
+      Some(patterns),
+      &module.id,
+      &module.stable_id,
+    )
+    .inner()
+}
+
+/// Tarjan's algorithm, without recursion to not overflow the stack on deep module graphs.
+fn strongly_connected_components(
+  graph: &IndexVec<ModuleIdx, Vec<ModuleIdx>>,
+) -> Vec<Vec<ModuleIdx>> {
+  const UNVISITED: u32 = u32::MAX;
+  let mut index = IndexVec::from_vec(vec![UNVISITED; graph.len()]);
+  let mut low_link = IndexVec::from_vec(vec![UNVISITED; graph.len()]);
+  let mut on_stack = IndexVec::from_vec(vec![false; graph.len()]);
+  let mut stack = vec![];
+  let mut next_index = 0;
+  let mut components = vec![];
+
+  for root in graph.indices() {
+    if index[root] != UNVISITED {
+      continue;
+    }
+    index[root] = next_index;
+    low_link[root] = next_index;
+    next_index += 1;
+    stack.push(root);
+    on_stack[root] = true;
+    let mut call_stack = vec![(root, 0)];
+
+    while let Some((node, next_edge)) = call_stack.last_mut() {
+      let node = *node;
+      if let Some(&target) = graph[node].get(*next_edge) {
+        *next_edge += 1;
+        if index[target] == UNVISITED {
+          index[target] = next_index;
+          low_link[target] = next_index;
+          next_index += 1;
+          stack.push(target);
+          on_stack[target] = true;
+          call_stack.push((target, 0));
+        } else if on_stack[target] {
+          low_link[node] = low_link[node].min(index[target]);
+        }
+        continue;
+      }
+
+      call_stack.pop();
+      if let Some((parent, _)) = call_stack.last() {
+        low_link[*parent] = low_link[*parent].min(low_link[node]);
+      }
+      if low_link[node] == index[node] {
+        let mut component = vec![];
+        while let Some(member) = stack.pop() {
+          on_stack[member] = false;
+          component.push(member);
+          if member == node {
+            break;
+          }
+        }
+        components.push(component);
+      }
+    }
+  }
+  components
+}
+
+/// Breadth-first search from `start` back to itself, only walking through `members`.
+fn shortest_cycle(
+  graph: &IndexVec<ModuleIdx, Vec<ModuleIdx>>,
+  members: &FxHashSet<ModuleIdx>,
+  start: ModuleIdx,
+) -> Vec<ModuleIdx> {
+  let mut parents: IndexVec<ModuleIdx, Option<ModuleIdx>> =
+    IndexVec::from_vec(vec![None; graph.len()]);
+  let mut queue = VecDeque::from([start]);
+  while let Some(node) = queue.pop_front() {
+    for &target in &graph[node] {
+      if target == start {
+        let mut cycle = vec![start, node];
+        let mut current = node;
+        while let Some(parent) = parents[current] {
+          cycle.push(parent);
+          current = parent;
+        }
+        cycle.reverse();
+        return cycle;
+      }
+      if members.contains(&target) && target != start && parents[target].is_none() {
+        parents[target] = Some(node);
+        queue.push_back(target);
+      }
+    }
+  }
+  unreachable!("every module of a strongly connected component is on a cycle")
+}
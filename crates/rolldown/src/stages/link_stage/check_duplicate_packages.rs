@@ -0,0 +1,78 @@
+use std::path::Path;
+
+use oxc_index::IndexVec;
+use rolldown_common::{Module, ModuleIdx};
+use rolldown_error::{BuildDiagnostic, DuplicatePackageCopy};
+use rustc_hash::FxHashMap;
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Warns about packages whose modules come from more than one `package.json`, which usually means
+  /// several versions or install paths of the same package are bundled.
+  pub(super) fn check_duplicate_packages(&mut self) {
+    if !self.options.checks.duplicate_package.unwrap_or(false) {
+      return;
+    }
+
+    // package name -> package directory -> copy
+    let mut packages: FxHashMap<&str, FxHashMap<&Path, DuplicatePackageCopy>> =
+      FxHashMap::default();
+    let mut package_of_module: IndexVec<ModuleIdx, Option<(&str, &Path)>> =
+      IndexVec::from_vec(vec![None; self.module_table.modules.len()]);
+    for module in self.module_table.modules.iter().filter_map(Module::as_normal) {
+      let Some(package_json) = module.package_json.as_ref() else {
+        continue;
+      };
+      let (Some(name), Some(dir)) = (package_json.name.as_deref(), package_json.path.parent())
+      else {
+        continue;
+      };
+      package_of_module[module.idx] = Some((name, dir));
+      let copy =
+        packages.entry(name).or_default().entry(dir).or_insert_with(|| DuplicatePackageCopy {
+          version: package_json.version.clone(),
+          path: dir.to_string_lossy().into_owned(),
+          bytes: 0,
+          importers: vec![],
+        });
+      copy.bytes += module.source.len()
+        + module.css_view.as_ref().map_or(0, |view| view.source.len())
+        + module.asset_view.as_ref().map_or(0, |view| view.source.len());
+    }
+
+    for module in self.module_table.modules.iter().filter_map(Module::as_normal) {
+      for rec in &module.import_records {
+        let Some((name, dir)) = package_of_module[rec.resolved_module] else {
+          continue;
+        };
+        // Imports between modules of the same copy don't tell who pulled the copy in.
+        if package_of_module[module.idx].is_some_and(|(_, importer_dir)| importer_dir == dir) {
+          continue;
+        }
+        if let Some(copy) = packages.get_mut(name).and_then(|copies| copies.get_mut(dir)) {
+          copy.importers.push(module.id.to_string());
+        }
+      }
+    }
+
+    let mut duplicates = packages
+      .into_iter()
+      .filter(|(_, copies)| copies.len() > 1)
+      .map(|(name, copies)| {
+        let mut copies = copies.into_values().collect::<Vec<_>>();
+        copies.sort_by(|a, b| a.path.cmp(&b.path));
+        for copy in &mut copies {
+          copy.importers.sort_unstable();
+          copy.importers.dedup();
+        }
+        (name.to_string(), copies)
+      })
+      .collect::<Vec<_>>();
+    duplicates.sort_by(|a, b| a.0.cmp(&b.0));
+
+    self.warnings.extend(duplicates.into_iter().map(|(name, copies)| {
+      BuildDiagnostic::duplicate_package(name, copies).with_severity_warning()
+    }));
+  }
+}
@@ -0,0 +1,31 @@
+use rolldown_common::Module;
+use rolldown_error::BuildDiagnostic;
+
+use crate::asset::asset_to_data_url;
+
+use super::LinkStage;
+
+impl LinkStage<'_> {
+  /// Inlines assets accepted by `assets_inline_limit` as data URLs. References to them in js and css
+  /// are replaced with the data URL in the generate stage.
+  pub(super) fn inline_small_assets(&mut self) {
+    let assets_inline_limit = &self.options.assets_inline_limit;
+    for module in self.module_table.modules.iter_mut().filter_map(Module::as_normal_mut) {
+      let Some(asset_view) = module.asset_view.as_mut() else {
+        continue;
+      };
+      match assets_inline_limit.should_inline(&module.id, &asset_view.source) {
+        Ok(true) => {}
+        Ok(false) => continue,
+        Err(err) => {
+          self.errors.push(BuildDiagnostic::unhandleable_error(err));
+          continue;
+        }
+      }
+      match asset_to_data_url(&module.id, &asset_view.source) {
+        Ok(data_url) => asset_view.data_url = Some(data_url.into()),
+        Err(err) => self.errors.push(BuildDiagnostic::unhandleable_error(err)),
+      }
+    }
+  }
+}
@@ -27,7 +27,11 @@ use crate::{
 use super::scan_stage::ScanStageOutput;
 
 mod bind_imports_and_exports;
+mod check_boundaries;
+mod check_circular_dependencies;
+mod check_duplicate_packages;
 mod generate_lazy_export;
+mod inline_assets;
 mod sort_modules;
 pub(crate) mod tree_shaking;
 mod wrapping;
@@ -111,16 +115,20 @@ impl<'a> LinkStage<'a> {
   #[tracing::instrument(level = "debug", skip_all)]
   pub fn link(mut self) -> LinkStageOutput {
     self.sort_modules();
+    self.check_boundaries();
+    self.check_duplicate_packages();
 
     self.determine_module_exports_kind();
     self.wrap_modules();
     self.generate_lazy_export();
     self.determine_side_effects();
     self.bind_imports_and_exports();
+    self.check_circular_dependencies();
     self.create_exports_for_ecma_modules();
     self.reference_needed_symbols();
     self.include_statements();
     self.patch_module_dependencies();
+    self.inline_small_assets();
     tracing::trace!("meta {:#?}", self.metas.iter_enumerated().collect::<Vec<_>>());
 
     LinkStageOutput {
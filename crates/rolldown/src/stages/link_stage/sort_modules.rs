@@ -1,7 +1,6 @@
 use std::iter;
 
 use rolldown_common::{Module, ModuleIdx};
-use rolldown_error::BuildDiagnostic;
 use rolldown_utils::rustc_hash::FxHashSetExt;
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -46,28 +45,11 @@ impl LinkStage<'_> {
     let mut stack_indexes_of_executing_id = FxHashMap::default();
 
     let mut sorted_modules = Vec::with_capacity(self.module_table.modules.len());
-    let mut circular_dependencies = FxHashSet::default();
 
     while let Some(status) = execution_stack.pop() {
       match status {
         Status::ToBeExecuted(id) => {
           if executed_ids.contains(&id) {
-            if self.options.checks.circular_dependency.unwrap_or(false) {
-              // Try to check if there is a circular dependency
-              if let Some(index) = stack_indexes_of_executing_id.get(&id).copied() {
-                // Executing
-                let cycles = execution_stack[index..]
-                  .iter()
-                  .filter_map(|action| match action {
-                    // Only modules with `Status::WaitForExit` are on the execution chain
-                    Status::ToBeExecuted(_) => None,
-                    Status::WaitForExit(id) => Some(*id),
-                  })
-                  .chain(iter::once(id))
-                  .collect::<Box<[_]>>();
-                circular_dependencies.insert(cycles);
-              }
-            }
             // It's already executed in other import chain, no need to execute again
           } else {
             executed_ids.insert(id);
@@ -108,18 +90,6 @@ impl LinkStage<'_> {
       }
     }
 
-    if !circular_dependencies.is_empty() {
-      for cycle in circular_dependencies {
-        let paths = cycle
-          .iter()
-          .filter_map(|id| {
-            self.module_table.modules[*id].as_normal().map(|module| module.id.to_string())
-          })
-          .collect::<Vec<_>>();
-        self.warnings.push(BuildDiagnostic::circular_dependency(paths).with_severity_warning());
-      }
-    }
-
     self.sorted_modules = sorted_modules;
     debug_assert_eq!(
       self.sorted_modules.first().copied(),
@@ -17,6 +17,9 @@ struct Context<'a> {
   is_module_included_vec: &'a mut IndexVec<ModuleIdx, bool>,
   tree_shaking: bool,
   runtime_id: ModuleIdx,
+  /// Helpers imported from somewhere else bring their own dependencies, so references between
+  /// statements of the runtime module are not followed.
+  runtime_helpers_inlined: bool,
   metas: &'a LinkingMetadataVec,
   used_symbol_refs: &'a mut FxHashSet<SymbolRef>,
 }
@@ -109,6 +112,10 @@ fn include_statement(ctx: &mut Context, module: &NormalModule, stmt_info_id: Stm
   // include the statement itself
   *is_included = true;
 
+  if module.idx == ctx.runtime_id && !ctx.runtime_helpers_inlined {
+    return;
+  }
+
   stmt_info.referenced_symbols.iter().for_each(|reference_ref| match reference_ref {
     SymbolOrMemberExprRef::Symbol(symbol_ref) => {
       include_symbol(ctx, *symbol_ref);
@@ -147,6 +154,7 @@ impl LinkStage<'_> {
       is_module_included_vec: &mut is_module_included_vec,
       tree_shaking: self.options.treeshake.enabled(),
       runtime_id: self.runtime.id(),
+      runtime_helpers_inlined: self.options.runtime_helpers.is_inline(),
       // used_exports_info_vec: &mut used_exports_info_vec,
       metas: &self.metas,
       used_symbol_refs: &mut self.used_symbol_refs,
@@ -24,6 +24,7 @@ pub struct ScanStage {
   fs: OsFileSystem,
   resolver: SharedResolver,
   cache: Arc<Cache>,
+  watch_mode: bool,
 }
 
 #[derive(Debug)]
@@ -44,8 +45,9 @@ impl ScanStage {
     fs: OsFileSystem,
     resolver: SharedResolver,
     cache: Arc<Cache>,
+    watch_mode: bool,
   ) -> Self {
-    Self { options, plugin_driver, fs, resolver, cache }
+    Self { options, plugin_driver, fs, resolver, cache, watch_mode }
   }
 
   #[tracing::instrument(level = "debug", skip_all)]
@@ -60,6 +62,7 @@ impl ScanStage {
       Arc::clone(&self.resolver),
       Arc::clone(&self.plugin_driver),
       Arc::clone(&self.cache),
+      self.watch_mode,
     )?;
 
     // For `pluginContext.emitFile` with `type: chunk`, support it at buildStart hook.
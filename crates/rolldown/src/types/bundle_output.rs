@@ -2,9 +2,13 @@ use arcstr::ArcStr;
 use rolldown_common::Output;
 use rolldown_error::BuildDiagnostic;
 
+use crate::utils::name_cache::NameCacheFile;
+
 #[derive(Default)]
 pub struct BundleOutput {
   pub warnings: Vec<BuildDiagnostic>,
   pub assets: Vec<Output>,
   pub watch_files: Vec<ArcStr>,
+  /// Written by `Bundler::write` only.
+  pub(crate) name_cache_files: Vec<NameCacheFile>,
 }
@@ -0,0 +1,86 @@
+use rolldown_common::{LogAction, LogRule, NormalizedBundlerOptions};
+use rolldown_error::{BuildDiagnostic, BuildResult, DiagnosticOptions, Severity};
+use rolldown_utils::pattern_filter::{self, StringOrRegex};
+
+/// Moves diagnostics between `errors` and `warnings`, or drops them, according to `log_rules`.
+/// Diagnostics that no rule matches stay where they are.
+pub fn apply_log_rules(
+  options: &NormalizedBundlerOptions,
+  errors: &mut Vec<BuildDiagnostic>,
+  warnings: &mut Vec<BuildDiagnostic>,
+) {
+  if options.log_rules.is_empty() {
+    return;
+  }
+
+  let diagnostic_options = DiagnosticOptions { cwd: options.cwd.clone() };
+  let diagnostics = std::mem::take(errors).into_iter().chain(std::mem::take(warnings));
+  for diagnostic in diagnostics {
+    match matched_action(options, &diagnostic, &diagnostic_options) {
+      Some(LogAction::Ignore) => {}
+      Some(LogAction::Warn) => warnings.push(diagnostic.with_severity(Severity::Warning)),
+      Some(LogAction::Error) => errors.push(diagnostic.with_severity(Severity::Error)),
+      None => match diagnostic.severity() {
+        Severity::Error => errors.push(diagnostic),
+        Severity::Warning => warnings.push(diagnostic),
+      },
+    }
+  }
+}
+
+/// Applies `log_rules` to the errors of a failed build, including the ones returned early by a stage.
+/// A failed build doesn't report warnings, so downgraded errors are dropped like ignored ones. The
+/// build can't be resumed either, so if the rules leave no error, the errors are kept as they are.
+pub fn apply_log_rules_to_errors<T>(
+  options: &NormalizedBundlerOptions,
+  result: BuildResult<T>,
+) -> BuildResult<T> {
+  let Err(errors) = result else {
+    return result;
+  };
+  if options.log_rules.is_empty() {
+    return Err(errors);
+  }
+
+  let diagnostic_options = DiagnosticOptions { cwd: options.cwd.clone() };
+  let is_still_failed = errors.iter().any(|diagnostic| {
+    match matched_action(options, diagnostic, &diagnostic_options) {
+      Some(action) => matches!(action, LogAction::Error),
+      None => matches!(diagnostic.severity(), Severity::Error),
+    }
+  });
+  if !is_still_failed {
+    return Err(errors);
+  }
+
+  let mut errors = errors.into_vec();
+  apply_log_rules(options, &mut errors, &mut vec![]);
+  Err(errors.into())
+}
+
+fn matched_action(
+  options: &NormalizedBundlerOptions,
+  diagnostic: &BuildDiagnostic,
+  opts: &DiagnosticOptions,
+) -> Option<LogAction> {
+  options.log_rules.iter().find(|rule| is_matched(rule, diagnostic, opts)).map(|rule| rule.action)
+}
+
+fn is_matched(rule: &LogRule, diagnostic: &BuildDiagnostic, opts: &DiagnosticOptions) -> bool {
+  if rule.code.as_ref().is_some_and(|code| *code != diagnostic.kind().to_string()) {
+    return false;
+  }
+  match &rule.id {
+    Some(pattern) => diagnostic.id().is_some_and(|id| {
+      let stable_id = opts.stabilize_path(&id);
+      pattern_filter::filter(
+        None::<&[StringOrRegex]>,
+        Some(std::slice::from_ref(pattern)),
+        &id,
+        &stable_id,
+      )
+      .inner()
+    }),
+    None => true,
+  }
+}
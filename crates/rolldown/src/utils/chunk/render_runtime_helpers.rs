@@ -0,0 +1,136 @@
+use std::sync::Arc;
+
+use itertools::Itertools;
+use oxc::ast::ast::Statement;
+use rolldown_common::{
+  NormalModule, NormalizedBundlerOptions, OutputFormat, RuntimeHelpers, SymbolRefDb,
+};
+use rolldown_ecmascript::{EcmaAst, EcmaCompiler};
+use rolldown_ecmascript_utils::TakeIn;
+use rolldown_sourcemap::Source;
+use rolldown_std_utils::PathBufExt;
+use rolldown_utils::concat_string;
+use sugar_path::SugarPath;
+
+use crate::types::generator::GenerateContext;
+
+/// With `RuntimeHelpers::External` or `RuntimeHelpers::Shared`, the runtime module is rendered as an
+/// import of the helpers it would declare, instead of the helpers themselves.
+///
+/// Tree shaking doesn't follow references between helpers in these modes, so only helpers that are
+/// used by other modules are imported.
+pub fn render_runtime_helpers_import(
+  ctx: &GenerateContext<'_>,
+  runtime: &NormalModule,
+) -> Option<Arc<[Box<dyn Source + Send + Sync>]>> {
+  let specifier = match &ctx.options.runtime_helpers {
+    RuntimeHelpers::Inline => return None,
+    RuntimeHelpers::External(specifier) => specifier.clone(),
+    RuntimeHelpers::Shared(file_name) => ctx
+      .chunk
+      .import_path_for_file(&shared_runtime_helpers_absolute_filename(ctx.options, file_name)),
+  };
+
+  let symbol_db = &ctx.link_output.symbol_db;
+  let helpers = runtime
+    .stmt_infos
+    .iter()
+    .filter(|stmt_info| stmt_info.is_included)
+    .flat_map(|stmt_info| stmt_info.declared_symbols.iter())
+    .filter(|symbol_ref| !runtime.named_imports.contains_key(*symbol_ref))
+    .map(|symbol_ref| (symbol_ref.name(symbol_db), ctx.chunk.canonical_names[symbol_ref].as_str()))
+    .sorted_unstable()
+    .collect_vec();
+
+  if helpers.is_empty() {
+    return None;
+  }
+
+  let code =
+    match ctx.options.format {
+      OutputFormat::Cjs => {
+        let bindings =
+          helpers
+            .iter()
+            .map(|(name, local)| {
+              if name == local {
+                (*name).to_string()
+              } else {
+                concat_string!(name, ": ", local)
+              }
+            })
+            .join(", ");
+        concat_string!("const { ", bindings, " } = require(\"", specifier, "\");")
+      }
+      _ => {
+        let specifiers = helpers
+          .iter()
+          .map(|(name, local)| {
+            if name == local {
+              (*name).to_string()
+            } else {
+              concat_string!(name, " as ", local)
+            }
+          })
+          .join(", ");
+        concat_string!("import { ", specifiers, " } from \"", specifier, "\";")
+      }
+    };
+
+  let sources: Vec<Box<dyn Source + Send + Sync>> = vec![
+    Box::new(concat_string!("//#region ", runtime.debug_id)),
+    Box::new(code),
+    Box::new("//#endregion"),
+  ];
+  Some(Arc::from(sources.into_boxed_slice()))
+}
+
+/// Renders the asset emitted for `RuntimeHelpers::Shared`. It contains every helper of the runtime
+/// module, so bundles built with different sets of used helpers could share it.
+///
+/// The runtime module isn't finalized if helpers aren't inlined, so its ast still declares every
+/// helper with its original name.
+pub fn render_shared_runtime_helpers(
+  options: &NormalizedBundlerOptions,
+  runtime: &NormalModule,
+  runtime_ast: &EcmaAst,
+  symbol_db: &SymbolRefDb,
+) -> String {
+  // Skip the first statement, which declares the namespace object of the runtime module.
+  let helpers = runtime
+    .stmt_infos
+    .iter()
+    .skip(1)
+    .flat_map(|stmt_info| stmt_info.declared_symbols.iter())
+    .filter(|symbol_ref| !runtime.named_imports.contains_key(*symbol_ref))
+    .map(|symbol_ref| symbol_ref.name(symbol_db))
+    .sorted_unstable()
+    .dedup()
+    .join(", ");
+
+  // Some helpers are declared with `export var`. Exports are added at the end of the file instead.
+  let mut ast = runtime_ast.clone_with_another_arena();
+  ast.program.with_mut(|fields| {
+    for stmt in &mut fields.program.body {
+      let Statement::ExportNamedDeclaration(export_decl) = stmt else {
+        continue;
+      };
+      let Some(decl) = export_decl.declaration.as_mut() else {
+        continue;
+      };
+      *stmt = Statement::from(decl.take_in(fields.allocator));
+    }
+  });
+  let body = EcmaCompiler::print(&ast, "", false).code;
+  match options.format {
+    OutputFormat::Cjs => concat_string!(body, "module.exports = { ", helpers, " };\n"),
+    _ => concat_string!(body, "export { ", helpers, " };\n"),
+  }
+}
+
+pub fn shared_runtime_helpers_absolute_filename(
+  options: &NormalizedBundlerOptions,
+  file_name: &str,
+) -> String {
+  file_name.absolutize_with(options.cwd.join(&options.out_dir)).expect_into_string()
+}
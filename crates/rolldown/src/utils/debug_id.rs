@@ -0,0 +1,31 @@
+/// Registers `debug_id` in `globalThis[global]`, keyed by the stack trace of an error created here,
+/// which contains the url of the chunk.
+pub fn render_debug_id_snippet(global: &str, debug_id: &str) -> String {
+  let global = serde_json::to_string(global).expect("strings are always serializable");
+  format!(
+    r#";(function(){{try{{var g=typeof globalThis!=="undefined"?globalThis:typeof self!=="undefined"?self:typeof window!=="undefined"?window:typeof global!=="undefined"?global:{{}};var s=new g.Error().stack;if(s){{g[{global}]=g[{global}]||{{}};g[{global}][s]="{debug_id}"}}}}catch(e){{}}}})();"#
+  )
+}
+
+pub struct DebugIdManifestEntry {
+  pub file: String,
+  /// `None` for inline source maps.
+  pub map: Option<String>,
+  pub debug_id: String,
+}
+
+pub fn render_debug_id_manifest(mut entries: Vec<DebugIdManifestEntry>) -> String {
+  entries.sort_unstable_by(|a, b| a.file.cmp(&b.file));
+  let chunks = entries
+    .into_iter()
+    .map(|entry| {
+      serde_json::json!({
+        "file": entry.file,
+        "map": entry.map,
+        "debugId": entry.debug_id,
+      })
+    })
+    .collect::<Vec<_>>();
+  serde_json::to_string_pretty(&serde_json::json!({ "chunks": chunks }))
+    .expect("json values are always serializable")
+}
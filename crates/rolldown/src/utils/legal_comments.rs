@@ -0,0 +1,77 @@
+use std::collections::BTreeMap;
+
+use rolldown_common::NormalModule;
+use rolldown_utils::{concat_string, indexmap::FxIndexSet};
+
+const LEGAL_COMMENTS_REFERENCE_PREFIX: &str = "/*! For license information please see ";
+
+/// Appended to a chunk whose legal comments were moved to `file`, a path relative to the chunk.
+pub fn render_legal_comments_reference(file: &str) -> String {
+  concat_string!(LEGAL_COMMENTS_REFERENCE_PREFIX, file, " */")
+}
+
+/// Splits `code` into the code before the reference rendered by
+/// [`render_legal_comments_reference`] and the reference, if `code` ends with one.
+pub fn split_legal_comments_reference(code: &str) -> (&str, Option<&str>) {
+  let last_line_start = code.rfind('\n').map_or(0, |index| index + 1);
+  let last_line = &code[last_line_start..];
+  if last_line.starts_with(LEGAL_COMMENTS_REFERENCE_PREFIX) && last_line.ends_with("*/") {
+    (&code[..last_line_start], Some(last_line))
+  } else {
+    (code, None)
+  }
+}
+
+pub fn render_legal_comments(comments: &FxIndexSet<String>) -> String {
+  let mut content = String::new();
+  for comment in comments {
+    content.push_str(comment);
+    content.push_str("\n\n");
+  }
+  content.truncate(content.trim_end().len());
+  content.push('\n');
+  content
+}
+
+struct LicenseReportPackage<'a> {
+  license: Option<&'a str>,
+  module_ids: Vec<&'a str>,
+}
+
+/// Groups the included modules under `node_modules` by the name and version of their `package.json`.
+pub fn render_license_report<'a>(modules: impl Iterator<Item = &'a NormalModule>) -> String {
+  let mut packages: BTreeMap<(&str, Option<&str>), LicenseReportPackage> = BTreeMap::new();
+  for module in modules {
+    let Some(package_json) = &module.package_json else {
+      continue;
+    };
+    let Some(name) = &package_json.name else {
+      continue;
+    };
+    if !package_json.path.components().any(|component| component.as_os_str() == "node_modules") {
+      continue;
+    }
+    packages
+      .entry((name.as_str(), package_json.version.as_deref()))
+      .or_insert_with(|| LicenseReportPackage {
+        license: package_json.license.as_deref(),
+        module_ids: vec![],
+      })
+      .module_ids
+      .push(&module.stable_id);
+  }
+  let packages = packages
+    .into_iter()
+    .map(|((name, version), mut package)| {
+      package.module_ids.sort_unstable();
+      serde_json::json!({
+        "name": name,
+        "version": version,
+        "license": package.license,
+        "modules": package.module_ids,
+      })
+    })
+    .collect::<Vec<_>>();
+  serde_json::to_string_pretty(&serde_json::json!({ "packages": packages }))
+    .expect("json values are always serializable")
+}
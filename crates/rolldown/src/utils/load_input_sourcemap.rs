@@ -0,0 +1,90 @@
+use std::path::Path;
+
+use rolldown_fs::FileSystem;
+use rolldown_sourcemap::SourceMap;
+use rolldown_utils::base64::from_standard_base64;
+use sugar_path::SugarPath;
+
+const SOURCE_MAPPING_URL_PREFIXES: [&str; 2] = ["//# sourceMappingURL=", "//@ sourceMappingURL="];
+
+/// Returns the url of the trailing `//# sourceMappingURL=` comment of `code`, if any.
+fn find_source_mapping_url(code: &str) -> Option<&str> {
+  let last_line = code.lines().rev().map(str::trim).find(|line| !line.is_empty())?;
+  SOURCE_MAPPING_URL_PREFIXES
+    .iter()
+    .find_map(|prefix| last_line.strip_prefix(prefix))
+    .map(str::trim)
+    .filter(|url| !url.is_empty())
+}
+
+/// Loads the source map referenced by the `sourceMappingURL` comment of a module read from disk.
+/// Both data urls and paths relative to the module are supported.
+///
+/// `sources` of the returned map are absolute paths, like the maps returned by plugins, and missing
+/// `sourcesContent` are read from disk. An `Err` describes why the referenced map can't be used.
+pub fn load_input_sourcemap(
+  id: &str,
+  code: &str,
+  fs: &dyn FileSystem,
+) -> Result<Option<SourceMap>, String> {
+  let Some(url) = find_source_mapping_url(code) else {
+    return Ok(None);
+  };
+  let module_dir = Path::new(id).parent().unwrap_or(Path::new(""));
+
+  let (json, map_dir) = if let Some(data_url) = url.strip_prefix("data:") {
+    let (_, base64) = data_url
+      .split_once(";base64,")
+      .ok_or_else(|| "Only base64 encoded data urls are supported.".to_string())?;
+    let decoded = from_standard_base64(base64).map_err(|err| err.to_string())?;
+    (String::from_utf8(decoded).map_err(|err| err.to_string())?, module_dir.to_path_buf())
+  } else {
+    let map_path = module_dir.join(url).normalize();
+    let json = fs
+      .read_to_string(&map_path)
+      .map_err(|err| format!("Failed to read \"{url}\": {}.", err.kind()))?;
+    let map_dir = map_path.parent().unwrap_or(module_dir).to_path_buf();
+    (json, map_dir)
+  };
+
+  let mut map = SourceMap::from_json_string(&json).map_err(|err| format!("{err:?}"))?;
+
+  let line_count = code.lines().count();
+  if map.get_tokens().any(|token| token.get_dst_line() as usize >= line_count) {
+    return Err("The source map has mappings beyond the end of the file, it may be stale.".into());
+  }
+
+  let source_root = map_dir.join(map.get_source_root().unwrap_or_default());
+  let sources = map
+    .get_sources()
+    .map(|source| {
+      if source.contains("://") {
+        source.to_string()
+      } else {
+        source_root.join(source).normalize().to_string_lossy().into_owned()
+      }
+    })
+    .collect::<Vec<_>>();
+  let mut source_contents = map
+    .get_source_contents()
+    .map(|contents| contents.map(ToString::to_string).collect::<Vec<_>>())
+    .unwrap_or_default();
+  source_contents.resize(sources.len(), String::new());
+  for (content, source) in source_contents.iter_mut().zip(&sources) {
+    if content.is_empty() {
+      *content = fs.read_to_string(Path::new(source)).unwrap_or_default();
+    }
+  }
+
+  map.set_sources(sources.iter().map(String::as_str).collect());
+  map.set_source_contents(source_contents.iter().map(String::as_str).collect());
+  Ok(Some(map))
+}
+
+#[test]
+fn test_find_source_mapping_url() {
+  assert_eq!(find_source_mapping_url("a;\n//# sourceMappingURL=a.js.map\n"), Some("a.js.map"));
+  assert_eq!(find_source_mapping_url("a;\n//@ sourceMappingURL=a.js.map"), Some("a.js.map"));
+  assert_eq!(find_source_mapping_url("//# sourceMappingURL=a.js.map\na;"), None);
+  assert_eq!(find_source_mapping_url("a;"), None);
+}
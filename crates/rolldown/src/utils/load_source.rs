@@ -1,21 +1,31 @@
-use std::path::Path;
+use std::{path::Path, sync::Arc};
 
 use rolldown_common::{
-  side_effects::HookSideEffects, ModuleType, NormalizedBundlerOptions, ResolvedId, StrOrBytes,
+  side_effects::HookSideEffects, CustomField, ModuleType, NormalizedBundlerOptions, ResolvedId,
+  StrOrBytes,
 };
+use rolldown_error::BuildDiagnostic;
 use rolldown_plugin::{HookLoadArgs, PluginDriver};
 use rolldown_sourcemap::SourceMap;
 use rustc_hash::FxHashMap;
 use sugar_path::SugarPath;
 
+use crate::{
+  module_loader::module_task::ModuleTaskOwner, utils::load_input_sourcemap::load_input_sourcemap,
+};
+
+#[allow(clippy::too_many_arguments)]
 pub async fn load_source(
   plugin_driver: &PluginDriver,
   resolved_id: &ResolvedId,
   fs: &dyn rolldown_fs::FileSystem,
   sourcemap_chain: &mut Vec<SourceMap>,
   side_effects: &mut Option<HookSideEffects>,
+  meta: &mut Option<Arc<CustomField>>,
   options: &NormalizedBundlerOptions,
   asserted_module_type: Option<&ModuleType>,
+  owner: Option<&ModuleTaskOwner>,
+  warnings: &mut Vec<BuildDiagnostic>,
 ) -> anyhow::Result<(StrOrBytes, ModuleType)> {
   let (maybe_source, maybe_module_type) = if let Some(load_hook_output) =
     plugin_driver.load(&HookLoadArgs { id: &resolved_id.id }).await?
@@ -24,6 +34,9 @@ pub async fn load_source(
     if let Some(v) = load_hook_output.side_effects {
       *side_effects = Some(v);
     }
+    if let Some(v) = load_hook_output.meta {
+      *meta = Some(v);
+    }
 
     (Some(load_hook_output.code), load_hook_output.module_type)
   } else if resolved_id.ignored {
@@ -38,15 +51,31 @@ pub async fn load_source(
       Some(user_specified_type) if user_specified_type == asserted => false,
       _ => true,
     };
-    if is_type_conflicted {
-      // TODO: emit a warning about the type conflict
+    if let (true, Some(owner)) = (is_type_conflicted, owner) {
+      warnings.push(
+        BuildDiagnostic::invalid_import_attribute(
+          owner.importer_id.as_str().into(),
+          owner.source.clone(),
+          owner.importee_span,
+          format!(
+            "{:?} is imported as `{asserted}`, but the `load` hook returned module type `{}`. The module will be treated as `{asserted}`.",
+            resolved_id.id.as_str(),
+            maybe_module_type.as_ref().map(ToString::to_string).unwrap_or_default()
+          ),
+        )
+        .with_severity_warning(),
+      );
     }
   }
 
-  match (maybe_source, maybe_module_type) {
+  let read_from_disk = maybe_source.is_none();
+  let loaded: anyhow::Result<(StrOrBytes, ModuleType)> = match (maybe_source, maybe_module_type) {
     (Some(source), Some(module_type)) => Ok((source.into(), module_type)),
     (source, None) => {
-      let guessed = get_module_loader_from_file_extension(&resolved_id.id, &options.module_types);
+      // Import attributes take precedence over the loader configured for the extension.
+      let guessed = asserted_module_type
+        .cloned()
+        .or_else(|| get_module_loader_from_file_extension(&resolved_id.id, &options.module_types));
       match (source, guessed) {
         (None, None) => {
           // - Unknown module type,
@@ -91,11 +120,42 @@ pub async fn load_source(
         unreachable!("Invalid state")
       }
     }
+  };
+  let (source, module_type) = loaded?;
+
+  // Maps of files read from disk can only be found through their `sourceMappingURL` comments. They
+  // aren't needed if no source map is generated.
+  if options.is_sourcemap_enabled()
+    && read_from_disk
+    && matches!(module_type, ModuleType::Js | ModuleType::Jsx | ModuleType::Ts | ModuleType::Tsx)
+  {
+    if let StrOrBytes::Str(code) = &source {
+      push_input_sourcemap(resolved_id, code, fs, sourcemap_chain, warnings);
+    }
+  }
+
+  Ok((source, module_type))
+}
+
+fn push_input_sourcemap(
+  resolved_id: &ResolvedId,
+  code: &str,
+  fs: &dyn rolldown_fs::FileSystem,
+  sourcemap_chain: &mut Vec<SourceMap>,
+  warnings: &mut Vec<BuildDiagnostic>,
+) {
+  match load_input_sourcemap(&resolved_id.id, code, fs) {
+    Ok(Some(map)) => sourcemap_chain.push(map),
+    Ok(None) => {}
+    Err(reason) => warnings.push(
+      BuildDiagnostic::input_sourcemap_error(resolved_id.id.to_string(), reason)
+        .with_severity_warning(),
+    ),
   }
 }
 
 /// ref: https://github.com/evanw/esbuild/blob/9c13ae1f06dfa909eb4a53882e3b7e4216a503fe/internal/bundler/bundler.go#L1161-L1183
-fn get_module_loader_from_file_extension<S: AsRef<str>>(
+pub fn get_module_loader_from_file_extension<S: AsRef<str>>(
   id: S,
   module_types: &FxHashMap<String, ModuleType>,
 ) -> Option<ModuleType> {
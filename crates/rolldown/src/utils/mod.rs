@@ -2,18 +2,24 @@ use oxc::ast::VisitMut;
 use rolldown_common::NormalModule;
 use rolldown_ecmascript::EcmaAst;
 use rolldown_ecmascript_utils::{AstSnippet, TakeIn};
+use rolldown_error::BuildDiagnostic;
 use rustc_hash::FxHashSet;
 
 use super::module_finalizers::scope_hoisting::{
   ScopeHoistingFinalizer, ScopeHoistingFinalizerContext,
 };
 pub mod apply_inner_plugins;
+pub mod apply_log_rules;
 pub mod augment_chunk_hash;
 pub mod chunk;
+pub mod debug_id;
 pub mod ecma_visitors;
 pub mod extract_meaningful_input_name_from_path;
+pub mod legal_comments;
 pub mod load_entry_module;
+pub mod load_input_sourcemap;
 pub mod load_source;
+pub mod name_cache;
 pub mod normalize_options;
 pub mod parse_to_ecma_ast;
 pub mod pre_process_ecma_ast;
@@ -30,7 +36,7 @@ pub fn finalize_normal_module(
   module: &NormalModule,
   ctx: ScopeHoistingFinalizerContext<'_>,
   ast: &mut EcmaAst,
-) {
+) -> Vec<BuildDiagnostic> {
   ast.program.with_mut(|fields| {
     let (oxc_program, alloc) = (fields.program, fields.allocator);
     let mut finalizer = ScopeHoistingFinalizer {
@@ -41,8 +47,10 @@ pub fn finalize_normal_module(
       comments: oxc_program.comments.take_in(alloc),
       namespace_alias_symbol_id: FxHashSet::default(),
       interested_namespace_alias_ref_id: FxHashSet::default(),
+      errors: Vec::new(),
     };
     finalizer.visit_program(oxc_program);
     oxc_program.comments = finalizer.comments.take_in(alloc);
-  });
+    finalizer.errors
+  })
 }
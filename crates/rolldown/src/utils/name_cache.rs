@@ -0,0 +1,74 @@
+use std::{
+  collections::BTreeMap,
+  path::{Path, PathBuf},
+};
+
+use anyhow::Context;
+use rolldown_fs::FileSystem;
+
+/// Mangled names of top-level bindings, keyed by module stable id and then by binding name.
+pub type BindingNameCache = BTreeMap<String, BTreeMap<String, String>>;
+
+/// Mangled property names, keyed by the original property name.
+pub type PropertyNameCache = BTreeMap<String, String>;
+
+pub fn read_binding_name_cache(
+  path: &Path,
+  fs: &dyn FileSystem,
+) -> anyhow::Result<BindingNameCache> {
+  let Some(content) = read_name_cache(path, fs)? else {
+    return Ok(BindingNameCache::default());
+  };
+  serde_json::from_str(&content)
+    .with_context(|| format!("Failed to parse the name cache {}", path.display()))
+}
+
+pub fn read_property_name_cache(
+  path: &Path,
+  fs: &dyn FileSystem,
+) -> anyhow::Result<PropertyNameCache> {
+  let Some(content) = read_name_cache(path, fs)? else {
+    return Ok(PropertyNameCache::default());
+  };
+  serde_json::from_str(&content)
+    .with_context(|| format!("Failed to parse the name cache {}", path.display()))
+}
+
+/// A missing file is treated as an empty cache.
+fn read_name_cache(path: &Path, fs: &dyn FileSystem) -> anyhow::Result<Option<String>> {
+  if !fs.exists(path) {
+    return Ok(None);
+  }
+  fs.read_to_string(path)
+    .map(Some)
+    .with_context(|| format!("Failed to read the name cache {}", path.display()))
+}
+
+/// An updated name cache. It's written by `Bundler::write` along with the assets, while
+/// `Bundler::generate` leaves the file untouched.
+#[derive(Debug)]
+pub struct NameCacheFile {
+  pub path: PathBuf,
+  pub content: String,
+}
+
+impl NameCacheFile {
+  pub fn from_binding_name_cache(
+    path: PathBuf,
+    name_cache: &BindingNameCache,
+  ) -> anyhow::Result<Self> {
+    Ok(Self::new(path, serde_json::to_string_pretty(name_cache)?))
+  }
+
+  pub fn from_property_name_cache(
+    path: PathBuf,
+    name_cache: &PropertyNameCache,
+  ) -> anyhow::Result<Self> {
+    Ok(Self::new(path, serde_json::to_string_pretty(name_cache)?))
+  }
+
+  fn new(path: PathBuf, mut content: String) -> Self {
+    content.push('\n');
+    Self { path, content }
+  }
+}
@@ -2,9 +2,10 @@ use std::path::Path;
 
 use oxc::transformer::InjectGlobalVariablesConfig;
 use rolldown_common::{
-  Comments, GlobalsOutputOption, InjectImport, ModuleType, NormalizedBundlerOptions, OutputFormat,
-  Platform,
+  Comments, GlobalsOutputOption, InjectImport, MinifyOptions, ModuleType, NormalizedBundlerOptions,
+  OutputFormat, Platform, RawMinifyOptions, RuntimeHelpers,
 };
+use rolldown_ecmascript::{CompressOptions, MangleOptions};
 use rolldown_error::{BuildDiagnostic, InvalidOptionType};
 use rustc_hash::{FxHashMap, FxHashSet};
 
@@ -38,6 +39,31 @@ fn verify_raw_options(raw_options: &crate::BundlerOptions) -> Vec<BuildDiagnosti
     _ => {}
   }
 
+  match raw_options.format {
+    Some(format @ (OutputFormat::Umd | OutputFormat::Iife | OutputFormat::App))
+      if raw_options.runtime_helpers.as_ref().is_some_and(|helpers| !helpers.is_inline()) =>
+    {
+      warnings.push(
+        BuildDiagnostic::invalid_option(InvalidOptionType::UnsupportedRuntimeHelpersFormat(
+          format.to_string(),
+        ))
+        .with_severity_warning(),
+      );
+    }
+    _ => {}
+  }
+
+  if let Some(RawMinifyOptions::Option(MinifyOptions { mangle: Some(mangle), .. })) =
+    &raw_options.minify
+  {
+    if mangle.name_cache.is_some() && mangle.top_level != Some(true) {
+      warnings.push(
+        BuildDiagnostic::invalid_option(InvalidOptionType::NameCacheWithoutTopLevel)
+          .with_severity_warning(),
+      );
+    }
+  }
+
   warnings
 }
 
@@ -54,11 +80,13 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     }
   });
 
-  let minify = raw_options.minify.unwrap_or(false);
+  let comments = raw_options.comments.unwrap_or(Comments::Preserve);
+  let raw_minify = raw_options.minify.take();
+  let minify = raw_minify.clone().and_then(|minify| normalize_minify_options(minify, comments));
 
   let mut raw_define = raw_options.define.unwrap_or_default();
   if matches!(platform, Platform::Browser) && !raw_define.contains_key("process.env.NODE_ENV") {
-    if minify {
+    if minify.is_some() {
       raw_define.insert("process.env.NODE_ENV".to_string(), "'production'".to_string());
     } else {
       raw_define.insert("process.env.NODE_ENV".to_string(), "'development'".to_string());
@@ -156,11 +184,26 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     },
   );
 
+  let cwd = raw_options
+    .cwd
+    .take()
+    .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir"));
+  let mangle_name_cache = minify
+    .as_ref()
+    .and_then(|minify| minify.mangle.as_ref())
+    .filter(|mangle| mangle.top_level)
+    .and_then(|_| match raw_minify {
+      Some(RawMinifyOptions::Option(options)) => options.mangle?.name_cache,
+      _ => None,
+    })
+    .map(|path| cwd.join(path));
+
+  // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L2767
+  let profiler_names = raw_options.profiler_names.unwrap_or(minify.is_none());
+
   let normalized = NormalizedBundlerOptions {
     input: raw_options.input.unwrap_or_default(),
-    cwd: raw_options
-      .cwd
-      .unwrap_or_else(|| std::env::current_dir().expect("Failed to get current dir")),
+    cwd,
     external: raw_options.external,
     treeshake: raw_options.treeshake,
     platform,
@@ -173,6 +216,7 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
       .asset_filenames
       .unwrap_or_else(|| "assets/[name]-[hash][extname]".to_string())
       .into(),
+    assets_inline_limit: raw_options.assets_inline_limit.unwrap_or_default(),
     css_entry_filenames: raw_options
       .css_entry_filenames
       .unwrap_or_else(|| "[name].css".to_string().into()),
@@ -195,10 +239,16 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     sourcemap_ignore_list: raw_options.sourcemap_ignore_list,
     sourcemap_path_transform: raw_options.sourcemap_path_transform,
     sourcemap_debug_ids: raw_options.sourcemap_debug_ids.unwrap_or(false),
+    sourcemap_debug_ids_global: raw_options.sourcemap_debug_ids_global,
+    sourcemap_debug_ids_manifest: raw_options.sourcemap_debug_ids_manifest,
+    sourcemap_index_map: raw_options.sourcemap_index_map.unwrap_or(false),
+    sourcemap_quality: raw_options.sourcemap_quality.unwrap_or_default(),
     shim_missing_exports: raw_options.shim_missing_exports.unwrap_or(false),
     module_types: loaders,
     experimental,
     minify,
+    mangle_name_cache,
+    mangle_props: raw_options.mangle_props,
     define,
     inject: raw_options.inject.unwrap_or_default(),
     oxc_inject_global_variables_config,
@@ -207,16 +257,59 @@ pub fn normalize_options(mut raw_options: crate::BundlerOptions) -> NormalizeOpt
     inline_dynamic_imports,
     advanced_chunks: raw_options.advanced_chunks,
     checks: raw_options.checks.unwrap_or_default(),
-    // https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L2767
-    profiler_names: raw_options.profiler_names.unwrap_or(!raw_options.minify.unwrap_or(false)),
+    log_rules: raw_options.log_rules.unwrap_or_default(),
+    profiler_names,
     jsx: raw_options.jsx.unwrap_or_default(),
     watch: raw_options.watch.unwrap_or_default(),
-    comments: raw_options.comments.unwrap_or(Comments::Preserve),
+    comments,
+    legal_comments_file: raw_options.legal_comments_file,
+    license_report: raw_options.license_report,
     drop_labels: FxHashSet::from_iter(raw_options.drop_labels.unwrap_or_default()),
     target: raw_options.target.unwrap_or_default(),
     keep_names: raw_options.keep_names.unwrap_or_default(),
     polyfill_require: raw_options.polyfill_require.unwrap_or(true),
+    runtime_helpers: match format {
+      OutputFormat::Esm | OutputFormat::Cjs => raw_options.runtime_helpers.unwrap_or_default(),
+      OutputFormat::App | OutputFormat::Iife | OutputFormat::Umd => RuntimeHelpers::Inline,
+    },
   };
 
   NormalizeOptionsReturn { options: normalized, resolve_options: raw_resolve, warnings }
 }
+
+fn normalize_minify_options(
+  raw: RawMinifyOptions,
+  comments: Comments,
+) -> Option<rolldown_ecmascript::MinifyOptions> {
+  let options = match raw {
+    RawMinifyOptions::Boolean(false) => return None,
+    RawMinifyOptions::Boolean(true) => MinifyOptions::default(),
+    RawMinifyOptions::Option(options) => options,
+  };
+  // External legal comments are written to a separate asset instead.
+  let legal_comments = !matches!(comments, Comments::None | Comments::External);
+  if options.whitespace_only.unwrap_or(false) {
+    return Some(rolldown_ecmascript::MinifyOptions {
+      compress: None,
+      mangle: None,
+      legal_comments,
+    });
+  }
+  let compress = options.compress.unwrap_or_default();
+  let mangle = options.mangle.unwrap_or_default();
+  Some(rolldown_ecmascript::MinifyOptions {
+    compress: Some(CompressOptions {
+      drop_console: compress.drop_console.unwrap_or(false),
+      drop_debugger: compress.drop_debugger.unwrap_or(true),
+      pure_funcs: compress.pure_funcs.unwrap_or_default(),
+      passes: compress.passes.unwrap_or(1),
+    }),
+    mangle: Some(MangleOptions {
+      top_level: mangle.top_level.unwrap_or(false),
+      keep_fnames: mangle.keep_fnames.unwrap_or(false),
+      keep_classnames: mangle.keep_classnames.unwrap_or(false),
+      reserved: mangle.reserved.unwrap_or_default(),
+    }),
+    legal_comments,
+  })
+}
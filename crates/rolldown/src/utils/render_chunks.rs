@@ -2,7 +2,7 @@ use anyhow::Result;
 use futures::future::try_join_all;
 use rolldown_common::{InstantiationKind, SharedNormalizedBundlerOptions};
 use rolldown_plugin::{HookRenderChunkArgs, SharedPluginDriver};
-use rolldown_sourcemap::collapse_sourcemaps;
+use rolldown_sourcemap::{collapse_sourcemaps, flatten_index_sourcemap};
 
 use crate::type_alias::IndexInstantiatedChunks;
 
@@ -26,6 +26,12 @@ pub async fn render_chunks<'a>(
         .await?;
 
       asset.content = render_chunk_ret.0.into();
+      if !render_chunk_ret.1.is_empty() {
+        // Sections can't be chained with the maps returned by the hooks.
+        if let Some(index_map) = asset.index_map.take() {
+          asset.map = Some(flatten_index_sourcemap(&index_map));
+        }
+      }
       if let Some(asset_map) = &asset.map {
         if !render_chunk_ret.1.is_empty() {
           let mut sourcemap_chain = Vec::with_capacity(render_chunk_ret.1.len() + 1);
@@ -1,7 +1,9 @@
 use std::sync::Arc;
 
-use rolldown_common::{ModuleRenderOutput, NormalModule, NormalizedBundlerOptions};
-use rolldown_sourcemap::{collapse_sourcemaps, Source, SourceMapSource};
+use rolldown_common::{
+  ModuleRenderOutput, NormalModule, NormalizedBundlerOptions, SourceMapQuality,
+};
+use rolldown_sourcemap::{collapse_sourcemaps, lines_only_sourcemap, Source, SourceMapSource};
 use rolldown_utils::concat_string;
 
 pub fn render_ecma_module(
@@ -15,7 +17,10 @@ pub fn render_ecma_module(
     let mut sources: Vec<Box<dyn rolldown_sourcemap::Source + Send + Sync>> = vec![];
     sources.push(Box::new(concat_string!("//#region ", module.debug_id)));
 
-    let enable_sourcemap = options.sourcemap.is_some() && !module.is_virtual();
+    let sourcemap_quality = options.sourcemap_quality_of(&module.id);
+    let enable_sourcemap = options.sourcemap.is_some()
+      && !module.is_virtual()
+      && sourcemap_quality != SourceMapQuality::Excluded;
 
     // Because oxc codegen sourcemap is last of sourcemap chain,
     // If here no extra sourcemap need remapping, we using it as final module sourcemap.
@@ -31,6 +36,10 @@ pub fn render_ecma_module(
         }
         Some(collapse_sourcemaps(sourcemap_chain))
       };
+      let sourcemap = match sourcemap_quality {
+        SourceMapQuality::Cheap => sourcemap.as_ref().map(lines_only_sourcemap),
+        _ => sourcemap,
+      };
 
       if let Some(sourcemap) = sourcemap {
         sources.push(Box::new(
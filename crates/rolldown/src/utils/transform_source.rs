@@ -1,17 +1,36 @@
+use std::sync::Arc;
+
 use anyhow::Result;
-use rolldown_common::ModuleType;
-use rolldown_common::{side_effects::HookSideEffects, ResolvedId};
+use arcstr::ArcStr;
+use rolldown_common::{side_effects::HookSideEffects, CustomField, ResolvedId};
+use rolldown_common::{ModuleType, NormalizedBundlerOptions};
 use rolldown_plugin::PluginDriver;
 use rolldown_sourcemap::SourceMap;
 
 #[inline]
+#[allow(clippy::too_many_arguments)]
 pub async fn transform_source(
   plugin_driver: &PluginDriver,
+  options: &NormalizedBundlerOptions,
   resolved_id: &ResolvedId,
   source: String,
   sourcemap_chain: &mut Vec<SourceMap>,
   side_effects: &mut Option<HookSideEffects>,
   module_type: &mut ModuleType,
+  meta: &mut Option<Arc<CustomField>>,
+  watch_files: &mut Vec<ArcStr>,
 ) -> Result<String> {
-  plugin_driver.transform(&resolved_id.id, source, sourcemap_chain, side_effects, module_type).await
+  let sourcemap_quality = options.sourcemap_quality_of(&resolved_id.id);
+  plugin_driver
+    .transform(
+      &resolved_id.id,
+      source,
+      sourcemap_chain,
+      side_effects,
+      module_type,
+      meta,
+      watch_files,
+      sourcemap_quality,
+    )
+    .await
 }
@@ -57,6 +57,7 @@ impl WatcherTask {
 
     self.emitter.emit(WatcherEvent::Event(BundleEvent::BundleStart))?;
 
+    bundler.watch_mode = true;
     bundler.plugin_driver.clear();
     for file in changed_files {
       bundler.cache.invalidate(file);
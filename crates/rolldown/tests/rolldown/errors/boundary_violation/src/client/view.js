@@ -0,0 +1,6 @@
+import { format } from '../shared/format.js'
+import { query } from '../server/db.js'
+
+export function render() {
+  console.log(format(query()))
+}
@@ -0,0 +1,5 @@
+import { format } from '../shared/format.js'
+
+export function query() {
+  return format(42)
+}
@@ -0,0 +1,4 @@
+import text from './data.json' with { type: 'text' }
+import './foo.js'
+
+console.log(text)
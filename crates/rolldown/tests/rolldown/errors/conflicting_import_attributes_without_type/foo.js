@@ -0,0 +1,3 @@
+import sheet from './a.css' with { type: 'css' }
+
+document.adoptedStyleSheets = [sheet]
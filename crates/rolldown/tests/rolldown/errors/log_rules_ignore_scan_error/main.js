@@ -0,0 +1,2 @@
+import './vendor.js'
+import './lib.js'
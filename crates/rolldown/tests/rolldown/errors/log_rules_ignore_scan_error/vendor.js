@@ -0,0 +1 @@
+import './vendor-missing.js'
@@ -0,0 +1 @@
+console.log(eval('1'))
@@ -0,0 +1,5 @@
+import { foo } from './Foo.js'
+import { bar } from './bar.js'
+import { baz } from './baz'
+
+console.log(foo, bar, baz)
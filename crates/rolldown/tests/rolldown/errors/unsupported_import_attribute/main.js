@@ -0,0 +1,3 @@
+import module from './module.wasm' with { type: 'webassembly' }
+
+console.log(module)
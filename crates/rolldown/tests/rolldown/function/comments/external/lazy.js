@@ -0,0 +1,2 @@
+//! lazy legal comment
+export const lazy = 'lazy';
@@ -0,0 +1,6 @@
+/*! main legal comment */
+import { lib } from 'lib';
+
+/* regular comment */
+lib();
+import('./lazy.js');
@@ -0,0 +1,3 @@
+/*! shared legal comment */
+/*! a legal comment */
+console.log('a');
@@ -0,0 +1,2 @@
+/*! shared legal comment */
+console.log('b');
@@ -0,0 +1,3 @@
+/*! main legal comment */
+/* regular comment */
+export const value = 'value'
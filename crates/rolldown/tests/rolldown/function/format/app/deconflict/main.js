@@ -0,0 +1,8 @@
+import { value } from './foo.js';
+
+const foo_exports = 'local';
+function main_default() {}
+
+console.log(value, foo_exports, main_default);
+
+export default function () {}
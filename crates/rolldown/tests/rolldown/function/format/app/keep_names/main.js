@@ -0,0 +1,5 @@
+import fn from './fn.js';
+import Cls from './cls.js';
+import arrow from './arrow.js';
+
+console.log(fn.name, Cls.name, arrow.name);
@@ -0,0 +1,6 @@
+import { count, increment } from './counter.js';
+
+increment();
+console.log({ count });
+
+export { count };
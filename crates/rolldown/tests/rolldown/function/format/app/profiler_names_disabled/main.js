@@ -0,0 +1,2 @@
+'use strict';
+export const foo = 1;
@@ -0,0 +1,17 @@
+export class Counter {
+  constructor() {
+    this._count = 0
+    this._keep = 'kept'
+  }
+  _increment() {
+    this._count++
+    return this
+  }
+  get value() {
+    return this._count
+  }
+}
+
+export function createState(_count) {
+  return { _count, _label: 'state' }
+}
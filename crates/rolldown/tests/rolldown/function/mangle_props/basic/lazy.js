@@ -0,0 +1,3 @@
+export function readCount(counter) {
+  return counter._count
+}
@@ -0,0 +1,20 @@
+import assert from 'node:assert'
+import { Counter, createState } from './counter'
+
+const counter = new Counter()._increment()._increment()
+assert.strictEqual(counter.value, 2)
+assert.strictEqual(counter._keep, 'kept')
+assert.deepStrictEqual(Object.keys(counter).filter((key) => key.startsWith('_')), ['_keep'])
+
+let { _count, _label: label = 'fallback' } = createState(3)
+assert.strictEqual(_count, 3)
+assert.strictEqual(label, 'state')
+;({ _count } = createState(4))
+assert.strictEqual(_count, 4)
+// Quoted names are never renamed, so unquoted uses of the same name are kept too.
+const quoted = { _quoted: 1 }
+assert.strictEqual(quoted['_quoted'], 1)
+
+import('./lazy').then(({ readCount }) => {
+  assert.strictEqual(readCount(counter), 2)
+})
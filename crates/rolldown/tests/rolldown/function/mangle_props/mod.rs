@@ -0,0 +1 @@
+mod name_cache;
@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+
+const state = { _first: 1, _second: 2 }
+assert.strictEqual(state._first + state._second, 3)
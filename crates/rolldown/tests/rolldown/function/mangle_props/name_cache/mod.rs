@@ -0,0 +1,35 @@
+use rolldown::{BundlerOptions, InputItem, ManglePropsOptions};
+use rolldown_testing::{
+  abs_file_dir,
+  utils::{bundle_with_name_cache, NAME_CACHE_COPY},
+};
+use rolldown_utils::js_regex::HybridRegex;
+
+/// `name-cache.json` has no entry for `_second`, so it gets a fresh name while `_first` keeps its
+/// cached name.
+#[tokio::test(flavor = "multi_thread")]
+async fn test() {
+  let cwd = abs_file_dir!();
+  let options = BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(cwd.clone()),
+    mangle_props: Some(ManglePropsOptions {
+      regex: HybridRegex::new("^_").unwrap(),
+      reserved: None,
+      name_cache: Some(NAME_CACHE_COPY.to_string()),
+    }),
+    ..Default::default()
+  };
+
+  let (code, cache) = bundle_with_name_cache(&cwd, options).await;
+
+  assert_eq!(cache["_first"], "a");
+  assert!(code.contains("a: 1"), "{code}");
+
+  let second = cache["_second"].as_str().unwrap();
+  assert_ne!(second, "a");
+  assert!(code.contains(&format!("{second}: 2")), "{code}");
+}
@@ -0,0 +1,14 @@
+import assert from 'node:assert'
+
+class Container {}
+
+function createServices() {
+  class UserService {}
+  class Logger {}
+  const services = [UserService, Logger]
+  console.log('registered', services.length)
+  return services.map((service) => service.name)
+}
+
+assert.equal(Container.name, 'Container')
+assert.deepStrictEqual(createServices(), ['UserService', 'Logger'])
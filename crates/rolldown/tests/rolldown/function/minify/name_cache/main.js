@@ -0,0 +1,14 @@
+import assert from 'node:assert'
+import { step } from './shared'
+
+export function createCounter() {
+  let count = 0
+  return () => (count += step)
+}
+
+function double(value) {
+  return value * step
+}
+
+assert.equal(double(2), 4)
+assert.equal(createCounter()(), 2)
@@ -0,0 +1,41 @@
+use rolldown::{BundlerOptions, InputItem, MinifyMangleOptions, MinifyOptions, RawMinifyOptions};
+use rolldown_testing::{
+  abs_file_dir,
+  utils::{bundle_with_name_cache, NAME_CACHE_COPY},
+};
+
+/// `name-cache.json` has no entry for `double`, so it gets a fresh name while the other bindings
+/// keep their cached names.
+#[tokio::test(flavor = "multi_thread")]
+async fn test() {
+  let cwd = abs_file_dir!();
+  let options = BundlerOptions {
+    input: Some(vec![InputItem {
+      name: Some("main".to_string()),
+      import: "./main.js".to_string(),
+    }]),
+    cwd: Some(cwd.clone()),
+    minify: Some(RawMinifyOptions::Option(MinifyOptions {
+      mangle: Some(MinifyMangleOptions {
+        top_level: Some(true),
+        name_cache: Some(NAME_CACHE_COPY.to_string()),
+        ..Default::default()
+      }),
+      ..Default::default()
+    })),
+    ..Default::default()
+  };
+
+  let (code, cache) = bundle_with_name_cache(&cwd, options).await;
+
+  assert_eq!(cache["main.js"]["createCounter"], "c");
+  assert_eq!(cache["node:assert"]["assert"], "b");
+  assert_eq!(cache["shared.js"]["step"], "a");
+  assert!(code.contains("import b from\"node:assert\""), "{code}");
+  assert!(code.contains("const a=2"), "{code}");
+  assert!(code.contains("export{c as createCounter}"), "{code}");
+
+  let double = cache["main.js"]["double"].as_str().unwrap();
+  assert!(!["a", "b", "c"].contains(&double), "{double} is already taken");
+  assert!(code.contains(&format!("function {double}(")), "{code}");
+}
@@ -0,0 +1 @@
+export const step = 2
@@ -0,0 +1,17 @@
+import assert from 'node:assert'
+
+/*! trace.js v1.0.0 | MIT License */
+function trace(message) {
+  throw new Error(`trace should be dropped: ${message}`)
+}
+
+function mount(selector) {
+  const $el = { selector }
+  const children = []
+  console.info('mounting', selector)
+  trace('mount')
+  children.push($el)
+  return children
+}
+
+assert.deepStrictEqual(mount('#app'), [{ selector: '#app' }])
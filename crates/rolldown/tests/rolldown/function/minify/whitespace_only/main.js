@@ -0,0 +1,8 @@
+import assert from 'node:assert'
+
+function add(left, right) {
+  debugger
+  return left + right
+}
+
+assert.equal(add(1, 2), 3)
@@ -0,0 +1,2 @@
+mod mangle_props;
+mod minify;
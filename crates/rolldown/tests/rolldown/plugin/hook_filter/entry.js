@@ -0,0 +1,4 @@
+import { foo } from './foo.js'
+import { bar } from './bar.js'
+
+console.log(foo, bar)
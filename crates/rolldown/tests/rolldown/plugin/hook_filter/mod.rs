@@ -0,0 +1,66 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, InputItem};
+use rolldown_plugin::{
+  GeneralHookFilter, HookFilter, HookTransformArgs, HookTransformOutput, HookTransformReturn,
+  Plugin, PluginHookMeta, SharedTransformPluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rolldown_utils::pattern_filter::StringOrRegex;
+
+#[derive(Debug)]
+struct TestPlugin;
+
+impl Plugin for TestPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "TestPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    assert!(args.id.ends_with("foo.js"), "`{}` should be filtered out", args.id);
+    Ok(Some(HookTransformOutput {
+      code: Some(args.code.replace("'foo'", "'transformed foo'")),
+      ..Default::default()
+    }))
+  }
+
+  fn transform_meta(&self) -> Option<PluginHookMeta> {
+    Some(PluginHookMeta {
+      order: None,
+      filter: Some(HookFilter {
+        id: Some(GeneralHookFilter {
+          include: None,
+          exclude: Some(vec![StringOrRegex::String("**/bar.js".to_string())]),
+        }),
+        code: Some(GeneralHookFilter {
+          include: Some(vec![StringOrRegex::String("'foo'".to_string())]),
+          exclude: None,
+        }),
+        ..Default::default()
+      }),
+    })
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn skip_hooks_not_matching_filter() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(TestPlugin)],
+    )
+    .await;
+}
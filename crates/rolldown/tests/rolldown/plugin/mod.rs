@@ -1 +1,6 @@
+mod hook_filter;
+mod options_hook;
 mod plugin_context;
+mod render_hooks;
+mod should_transform_cached_module;
+mod transform_watch_files;
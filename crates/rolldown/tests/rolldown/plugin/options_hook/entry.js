@@ -0,0 +1 @@
+export const version = __VERSION__
@@ -0,0 +1,129 @@
+use std::{
+  borrow::Cow,
+  sync::{
+    atomic::{AtomicBool, Ordering},
+    Arc,
+  },
+};
+
+use rolldown::{Bundler, BundlerOptions, InputItem, OutputFormat};
+use rolldown_common::Output;
+use rolldown_plugin::{HookOptionsReturn, Plugin};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+#[derive(Debug)]
+struct TestPlugin;
+
+impl Plugin for TestPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "TestPlugin".into()
+  }
+
+  async fn options(&self, options: &BundlerOptions) -> HookOptionsReturn {
+    let mut options = options.clone();
+    options
+      .define
+      .get_or_insert_with(Default::default)
+      .insert("__VERSION__".to_string(), "'1.0.0'".to_string());
+    Ok(Some(options))
+  }
+
+  async fn output_options(&self, options: &BundlerOptions) -> HookOptionsReturn {
+    assert!(options.define.is_some(), "`output_options` should run after `options`");
+    Ok(Some(BundlerOptions { format: Some(OutputFormat::Cjs), ..options.clone() }))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn replace_options_in_options_hooks() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(TestPlugin)],
+    )
+    .await;
+}
+
+#[derive(Debug)]
+struct FailingPlugin;
+
+impl Plugin for FailingPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "FailingPlugin".into()
+  }
+
+  async fn options(&self, _options: &BundlerOptions) -> HookOptionsReturn {
+    Err(anyhow::anyhow!("invalid options"))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn report_errors_of_options_hooks_in_build() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("entry".to_string()),
+        import: "./entry.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(FailingPlugin)],
+  );
+
+  let Err(errors) = bundler.generate().await else {
+    panic!("`options` hook error should fail the build");
+  };
+  assert!(errors.iter().any(|err| err.to_diagnostic().to_string().contains("invalid options")));
+}
+
+/// Fails in the first build only.
+#[derive(Debug, Default)]
+struct FailingOncePlugin {
+  has_failed: AtomicBool,
+}
+
+impl Plugin for FailingOncePlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "FailingOncePlugin".into()
+  }
+
+  async fn options(&self, options: &BundlerOptions) -> HookOptionsReturn {
+    if !self.has_failed.swap(true, Ordering::Relaxed) {
+      return Err(anyhow::anyhow!("invalid options"));
+    }
+    TestPlugin.options(options).await
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn rerun_options_hooks_after_a_failed_build() {
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("entry".to_string()),
+        import: "./entry.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      ..Default::default()
+    },
+    vec![Arc::new(FailingOncePlugin::default())],
+  );
+
+  assert!(bundler.generate().await.is_err(), "the first build should fail");
+
+  let output = bundler.generate().await.expect("the second build should run the hooks again");
+  let Output::Chunk(chunk) = &output.assets[0] else {
+    panic!("the first output should be the entry chunk");
+  };
+  assert!(chunk.code.contains(r#""1.0.0""#), "`options` hook should apply in the second build");
+}
@@ -0,0 +1,5 @@
+export const meta = import.meta.foo;
+
+export const bareMeta = import.meta;
+
+export const bar = import('./bar.js');
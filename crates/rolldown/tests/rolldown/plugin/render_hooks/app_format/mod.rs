@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem, OutputFormat};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+use super::TestPlugin;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn render_app_format_with_render_hooks() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        format: Some(OutputFormat::App),
+        ..Default::default()
+      },
+      vec![Arc::new(TestPlugin)],
+    )
+    .await;
+}
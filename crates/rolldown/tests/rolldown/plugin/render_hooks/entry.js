@@ -0,0 +1,7 @@
+export const url = new URL('./foo.txt', import.meta.url);
+
+export const meta = import.meta.foo;
+
+export const bareMeta = import.meta;
+
+export const bar = import('./bar.js');
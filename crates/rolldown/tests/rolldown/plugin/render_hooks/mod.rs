@@ -0,0 +1,74 @@
+use std::{borrow::Cow, sync::Arc};
+
+use rolldown::{BundlerOptions, ExperimentalOptions, InputItem};
+use rolldown_plugin::{
+  HookInjectionOutputReturn, HookRenderDynamicImportArgs, HookRenderDynamicImportOutput,
+  HookRenderDynamicImportReturn, HookResolveFileUrlArgs, HookResolveImportMetaArgs, Plugin,
+  PluginContext,
+};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+mod app_format;
+
+#[derive(Debug)]
+struct TestPlugin;
+
+impl Plugin for TestPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "TestPlugin".into()
+  }
+
+  fn resolve_import_meta(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveImportMetaArgs<'_>,
+  ) -> HookInjectionOutputReturn {
+    match args.property {
+      Some("foo") => Ok(Some(format!("'{}: foo'", args.chunk_id))),
+      None => Ok(Some("({ foo: 'bare' })".to_string())),
+      _ => Ok(None),
+    }
+  }
+
+  fn resolve_file_url(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookResolveFileUrlArgs<'_>,
+  ) -> HookInjectionOutputReturn {
+    Ok(Some(format!("getAssetUrl('{}', '{}')", args.file_name, args.relative_path)))
+  }
+
+  fn render_dynamic_import(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderDynamicImportArgs<'_>,
+  ) -> HookRenderDynamicImportReturn {
+    Ok(Some(HookRenderDynamicImportOutput {
+      left: "customImport(".to_string(),
+      right: ")".to_string(),
+    }))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn render_with_render_hooks() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("entry".to_string()),
+          import: "./entry.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        experimental: Some(ExperimentalOptions {
+          resolve_new_url_to_asset: Some(true),
+          ..Default::default()
+        }),
+        ..Default::default()
+      },
+      vec![Arc::new(TestPlugin)],
+    )
+    .await;
+}
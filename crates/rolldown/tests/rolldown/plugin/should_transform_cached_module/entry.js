@@ -0,0 +1,2 @@
+import './foo.js'
+import './bar.js'
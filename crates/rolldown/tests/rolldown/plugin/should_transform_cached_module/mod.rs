@@ -0,0 +1,99 @@
+use std::{
+  borrow::Cow,
+  sync::{Arc, Mutex},
+};
+
+use rolldown::{Bundler, BundlerOptions, ExperimentalOptions, InputItem};
+use rolldown_common::ModuleInfo;
+use rolldown_plugin::{
+  typedmap::TypedMapKey, CustomField, HookNoopReturn, HookShouldTransformCachedModuleArgs,
+  HookShouldTransformCachedModuleReturn, HookTransformArgs, HookTransformOutput,
+  HookTransformReturn, Plugin, PluginContext, SharedTransformPluginContext,
+};
+use rolldown_testing::abs_file_dir;
+use sugar_path::SugarPath;
+
+#[derive(Hash, PartialEq, Eq)]
+struct TransformedBy;
+
+impl TypedMapKey for TransformedBy {
+  type Value = String;
+}
+
+#[derive(Debug, Default)]
+struct TestPlugin {
+  transformed: Mutex<Vec<String>>,
+  parsed_with_meta: Mutex<Vec<String>>,
+}
+
+impl Plugin for TestPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "TestPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    _ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    let file_name = args.id.as_path().file_name().unwrap().to_string_lossy().into_owned();
+    self.transformed.lock().unwrap().push(file_name);
+    let meta = CustomField::new();
+    meta.insert(TransformedBy, "TestPlugin".to_string());
+    Ok(Some(HookTransformOutput { meta: Some(Arc::new(meta)), ..Default::default() }))
+  }
+
+  async fn module_parsed(
+    &self,
+    _ctx: &PluginContext,
+    module_info: Arc<ModuleInfo>,
+  ) -> HookNoopReturn {
+    if module_info.meta.as_ref().is_some_and(|meta| meta.get(&TransformedBy).is_some()) {
+      let file_name = module_info.id.as_path().file_name().unwrap().to_string_lossy().into_owned();
+      self.parsed_with_meta.lock().unwrap().push(file_name);
+    }
+    Ok(())
+  }
+
+  async fn should_transform_cached_module(
+    &self,
+    _ctx: &PluginContext,
+    args: &HookShouldTransformCachedModuleArgs<'_>,
+  ) -> HookShouldTransformCachedModuleReturn {
+    Ok(args.id.ends_with("foo.js"))
+  }
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn reuse_cached_module_unless_plugins_reject() {
+  let plugin = Arc::new(TestPlugin::default());
+  let mut bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("entry".to_string()),
+        import: "./entry.js".to_string(),
+      }]),
+      cwd: Some(abs_file_dir!()),
+      experimental: Some(ExperimentalOptions {
+        incremental_build: Some(true),
+        ..Default::default()
+      }),
+      ..Default::default()
+    },
+    vec![Arc::clone(&plugin) as _],
+  );
+
+  bundler.generate().await.expect("First build should succeed");
+  let mut transformed = std::mem::take(&mut *plugin.transformed.lock().unwrap());
+  transformed.sort();
+  assert_eq!(transformed, ["bar.js", "entry.js", "foo.js"]);
+
+  bundler.generate().await.expect("Second build should succeed");
+  let transformed = std::mem::take(&mut *plugin.transformed.lock().unwrap());
+  assert_eq!(transformed, ["foo.js"]);
+
+  // The meta returned by `transform` is restored for cached modules.
+  let mut parsed_with_meta = std::mem::take(&mut *plugin.parsed_with_meta.lock().unwrap());
+  parsed_with_meta.sort();
+  assert_eq!(parsed_with_meta, ["bar.js", "bar.js", "entry.js", "entry.js", "foo.js", "foo.js"]);
+}
@@ -0,0 +1,93 @@
+use std::{borrow::Cow, path::Path, sync::Arc, time::Duration};
+
+use rolldown::{Bundler, BundlerOptions, ExperimentalOptions, InputItem, Watcher};
+use rolldown_common::{BundleEvent, WatcherEvent};
+use rolldown_plugin::{
+  HookTransformArgs, HookTransformOutput, HookTransformReturn, Plugin, SharedTransformPluginContext,
+};
+use tokio::sync::Mutex;
+
+/// Inlines the content of `dep.txt` into `entry.js`, like a css preprocessor inlines partials.
+#[derive(Debug)]
+struct InlineDepPlugin;
+
+impl Plugin for InlineDepPlugin {
+  fn name(&self) -> Cow<'static, str> {
+    "InlineDepPlugin".into()
+  }
+
+  async fn transform(
+    &self,
+    ctx: SharedTransformPluginContext,
+    args: &HookTransformArgs<'_>,
+  ) -> HookTransformReturn {
+    if !args.id.ends_with("entry.js") {
+      return Ok(None);
+    }
+    let dep = Path::new(args.id).with_file_name("dep.txt");
+    ctx.add_watch_file(&dep.to_string_lossy());
+    let content = std::fs::read_to_string(&dep)?;
+    Ok(Some(HookTransformOutput {
+      code: Some(format!("console.log({content:?});")),
+      ..Default::default()
+    }))
+  }
+}
+
+/// Returns the content of the output after the next build, or `None` if the build fails or
+/// doesn't finish in time.
+async fn read_output_after_next_build(watcher: &Watcher, output: &Path) -> Option<String> {
+  let emitter = watcher.emitter();
+  let rx = emitter.rx.lock().await;
+  loop {
+    match rx.recv_timeout(Duration::from_secs(10)).ok()? {
+      WatcherEvent::Event(BundleEvent::BundleEnd(_)) => {
+        return std::fs::read_to_string(output).ok()
+      }
+      WatcherEvent::Event(BundleEvent::Error(_)) => return None,
+      _ => {}
+    }
+  }
+}
+
+// The watcher blocks some worker threads while waiting for events.
+#[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+async fn rebuild_cached_module_when_its_watch_file_changes() {
+  let cwd =
+    std::env::temp_dir().join(format!("rolldown-transform-watch-files-{}", std::process::id()));
+  std::fs::create_dir_all(&cwd).unwrap();
+  std::fs::write(cwd.join("entry.js"), "").unwrap();
+  std::fs::write(cwd.join("dep.txt"), "first").unwrap();
+
+  let bundler = Bundler::with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("entry".to_string()),
+        import: "./entry.js".to_string(),
+      }]),
+      cwd: Some(cwd.clone()),
+      experimental: Some(ExperimentalOptions {
+        incremental_build: Some(true),
+        ..Default::default()
+      }),
+      ..Default::default()
+    },
+    vec![Arc::new(InlineDepPlugin)],
+  );
+  let watcher = Arc::new(Watcher::new(vec![Arc::new(Mutex::new(bundler))], None).unwrap());
+  let running_watcher = Arc::clone(&watcher);
+  tokio::spawn(async move { running_watcher.start().await });
+
+  let output = cwd.join("dist/entry.js");
+  let first = read_output_after_next_build(&watcher, &output).await;
+  // `entry.js` itself is unchanged, but its cached transform result depends on `dep.txt`.
+  std::fs::write(cwd.join("dep.txt"), "second").unwrap();
+  let second = read_output_after_next_build(&watcher, &output).await;
+
+  // Close the watcher before asserting, otherwise a failure would hang the runtime.
+  watcher.close().await.unwrap();
+  std::fs::remove_dir_all(&cwd).unwrap();
+
+  assert!(first.is_some_and(|output| output.contains("first")));
+  assert!(second.is_some_and(|output| output.contains("second")));
+}
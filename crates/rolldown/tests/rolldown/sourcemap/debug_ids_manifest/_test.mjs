@@ -0,0 +1,30 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import path from 'node:path'
+
+const dist = path.resolve(import.meta.dirname, 'dist')
+const manifest = JSON.parse(fs.readFileSync(path.join(dist, 'debug-ids.json'), 'utf8'))
+assert.deepStrictEqual(
+  manifest.chunks.map((chunk) => [chunk.file, chunk.map]),
+  [
+    ['lazy.js', 'lazy.js.map'],
+    ['main.js', 'main.js.map'],
+  ],
+)
+
+for (const chunk of manifest.chunks) {
+  const code = fs.readFileSync(path.join(dist, chunk.file), 'utf8')
+  assert.ok(code.endsWith(`//# debugId=${chunk.debugId}\n//# sourceMappingURL=${chunk.map}`))
+  const map = JSON.parse(fs.readFileSync(path.join(dist, chunk.map), 'utf8'))
+  assert.strictEqual(map.debugId, chunk.debugId)
+}
+
+const { lazy } = await import('./dist/main.js')
+await lazy
+const registered = Object.entries(globalThis._debugIds)
+for (const chunk of manifest.chunks) {
+  assert.ok(
+    registered.some(([stack, debugId]) => stack.includes(chunk.file) && debugId === chunk.debugId),
+    `${chunk.file} is not registered`,
+  )
+}
@@ -0,0 +1,20 @@
+import assert from 'node:assert'
+import fs from 'node:fs'
+import path from 'node:path'
+
+const dist = path.resolve(import.meta.dirname, 'dist')
+const code = fs.readFileSync(path.join(dist, 'main.js'), 'utf8')
+const map = JSON.parse(fs.readFileSync(path.join(dist, 'main.js.map'), 'utf8'))
+
+assert.strictEqual(map.version, 3)
+assert.strictEqual(map.file, 'main.js')
+assert.deepStrictEqual(
+  map.sections.map((section) => section.map.sources),
+  [['../foo.js'], ['../main.js']],
+)
+
+const lines = code.split('\n')
+for (const section of map.sections) {
+  assert.strictEqual(section.offset.column, 0)
+  assert.strictEqual(lines[section.offset.line - 1], `//#region ${section.map.sources[0].slice(3)}`)
+}
@@ -0,0 +1,3 @@
+import { greet } from './sdk/greet.js'
+
+console.log(greet('rolldown'))
@@ -0,0 +1,4 @@
+export function greet(name) {
+  return `hello ${name}`;
+}
+//# sourceMappingURL=data:application/json;charset=utf-8;base64,eyJ2ZXJzaW9uIjogMywgInNvdXJjZXMiOiBbIi4uL3NyYy9ncmVldC50cyJdLCAic291cmNlc0NvbnRlbnQiOiBbImV4cG9ydCBmdW5jdGlvbiBncmVldChuYW1lOiBzdHJpbmcpOiBzdHJpbmcge1xuICByZXR1cm4gYGhlbGxvICR7bmFtZX1gXG59XG4iXSwgIm5hbWVzIjogW10sICJtYXBwaW5ncyI6ICJBQUFBLGdCQUFnQixNQUFNO0VBQ3BCLE9BQU87QUFDVCJ9
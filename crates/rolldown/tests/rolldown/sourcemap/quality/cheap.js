@@ -0,0 +1 @@
+export const cheap = { value: 1 }; console.log(cheap.value);
@@ -0,0 +1 @@
+export const excluded = { value: 2 }; console.log(excluded.value);
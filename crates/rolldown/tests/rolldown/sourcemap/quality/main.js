@@ -0,0 +1,4 @@
+import { cheap } from './cheap.js';
+import { excluded } from './excluded.js';
+
+export const full = { value: cheap.value + excluded.value }; console.log(full.value);
@@ -0,0 +1,12 @@
+import assert from 'node:assert'
+import './main.css'
+import small from './small.png'
+import icon from './icon.svg'
+import large from './large.txt'
+
+assert(small.startsWith('data:image/png;base64,'))
+assert(icon.startsWith('data:image/svg+xml,'))
+assert(large.endsWith('.txt'))
+
+const url = new URL('./small.png', import.meta.url)
+assert.equal(url.href, small)
@@ -0,0 +1,6 @@
+import assert from 'node:assert'
+import small from './small.png'
+import icon from './icon.svg'
+
+assert(small.endsWith('.png'))
+assert(icon.startsWith('data:image/svg+xml,'))
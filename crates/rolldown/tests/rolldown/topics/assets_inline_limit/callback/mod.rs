@@ -0,0 +1,32 @@
+use std::{path::Path, sync::Arc};
+
+use rolldown::{AssetsInlineLimit, BundlerOptions, InputItem, ModuleType};
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+use rustc_hash::FxHashMap;
+
+#[tokio::test(flavor = "multi_thread")]
+async fn test() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        module_types: Some(FxHashMap::from_iter([
+          (".png".to_string(), ModuleType::Asset),
+          (".svg".to_string(), ModuleType::Asset),
+        ])),
+        // Only svgs are inlined, regardless of their size.
+        assets_inline_limit: Some(AssetsInlineLimit::Fn(Arc::new(|id, _content| {
+          Ok(Path::new(id).extension().is_some_and(|ext| ext == "svg").then_some(true))
+        }))),
+        ..Default::default()
+      },
+      vec![],
+    )
+    .await;
+}
@@ -0,0 +1,4 @@
+import { double } from './b.js'
+
+export const base = 21
+export const value = double()
@@ -0,0 +1,7 @@
+import { base } from './a.js'
+
+const doubled = base * 2
+
+export function double() {
+  return doubled
+}
@@ -0,0 +1,3 @@
+import { value } from './a.js'
+
+console.log(value)
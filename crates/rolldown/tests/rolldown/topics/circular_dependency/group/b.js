@@ -0,0 +1,11 @@
+import { c } from './c.js'
+import { d } from './d.js'
+import { a } from './a.js'
+
+export function b() {
+  return 'b' + c() + d()
+}
+
+export function callA() {
+  return a()
+}
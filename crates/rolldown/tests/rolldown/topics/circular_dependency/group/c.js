@@ -0,0 +1,9 @@
+import { a } from './a.js'
+
+export function c() {
+  return 'c'
+}
+
+export function callA() {
+  return a()
+}
@@ -0,0 +1,9 @@
+import { callA } from './b.js'
+
+export function d() {
+  return 'd'
+}
+
+export function callB() {
+  return callA()
+}
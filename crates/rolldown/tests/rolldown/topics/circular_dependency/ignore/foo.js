@@ -0,0 +1,9 @@
+import { bar } from './bar.js'
+
+export function foo() {
+  return 'foo'
+}
+
+export function callBar() {
+  return bar()
+}
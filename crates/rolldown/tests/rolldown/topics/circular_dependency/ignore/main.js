@@ -0,0 +1,5 @@
+import { lib } from './vendor/lib.js'
+import { foo } from './foo.js'
+
+assert.strictEqual(lib(), 'lib')
+assert.strictEqual(foo(), 'foo')
@@ -0,0 +1,5 @@
+import { version } from 'shared'
+import { libVersion } from 'lib'
+
+assert.strictEqual(version, '1.0.0')
+assert.strictEqual(libVersion, '1.0.0')
@@ -0,0 +1,12 @@
+import assert from 'node:assert'
+import data from './data.json' with { type: 'json' }
+import readme from './README.md' with { type: 'text' }
+import bytes from './hello.dat' with { type: 'bytes' }
+
+assert.deepEqual(data, { name: 'rolldown' })
+assert.equal(readme, '# Hello\n')
+assert(bytes instanceof Uint8Array)
+assert.equal(new TextDecoder().decode(bytes), 'hello')
+
+const { default: lazyData } = await import('./lazy.json', { with: { type: 'json' } })
+assert.deepEqual(lazyData, { lazy: true })
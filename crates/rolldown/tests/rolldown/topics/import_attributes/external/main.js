@@ -0,0 +1,6 @@
+import data from 'external-data' with { type: 'json' }
+import sheet from 'external-style' with { type: 'css' }
+export * from 'external-data' with { type: 'json' }
+
+console.log(data, sheet)
+import('external-data', { with: { type: 'json' } })
@@ -0,0 +1 @@
+mod assets_inline_limit;
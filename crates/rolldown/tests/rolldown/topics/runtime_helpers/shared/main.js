@@ -0,0 +1,4 @@
+import assert from 'node:assert'
+import cjs from './cjs.js'
+
+assert.strictEqual(cjs.value, 'cjs')
@@ -0,0 +1,4 @@
+const assert = require("node:assert")
+const cjs = require("./cjs.js")
+
+assert.strictEqual(cjs.value, 'cjs')
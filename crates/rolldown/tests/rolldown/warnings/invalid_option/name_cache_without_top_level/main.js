@@ -0,0 +1,5 @@
+function double(value) {
+  return value * 2
+}
+
+export const result = double(2)
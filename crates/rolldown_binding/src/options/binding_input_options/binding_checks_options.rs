@@ -1,11 +1,60 @@
-#[napi_derive::napi(object)]
+use rolldown_common::Platform;
+
+use crate::options::plugin::types::binding_js_or_regex::{
+  bindingify_string_or_regex_array, BindingStringOrRegex,
+};
+
+#[napi_derive::napi(object, object_to_js = false)]
 #[derive(Debug, Default)]
 pub struct BindingChecksOptions {
   pub circular_dependency: Option<bool>,
+  pub circular_dependency_ignore: Option<Vec<BindingStringOrRegex>>,
+  pub duplicate_package: Option<bool>,
+  pub boundaries: Option<Vec<BindingBoundaryRule>>,
 }
 
-impl From<BindingChecksOptions> for rolldown_common::ChecksOptions {
-  fn from(value: BindingChecksOptions) -> Self {
-    Self { circular_dependency: value.circular_dependency }
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct BindingBoundaryRule {
+  pub from: Option<BindingStringOrRegex>,
+  pub disallow: BindingStringOrRegex,
+  #[napi(ts_type = "'node' | 'browser' | 'neutral'")]
+  pub platform: Option<String>,
+  pub message: Option<String>,
+}
+
+impl TryFrom<BindingChecksOptions> for rolldown_common::ChecksOptions {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingChecksOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      circular_dependency: value.circular_dependency,
+      circular_dependency_ignore: value
+        .circular_dependency_ignore
+        .map(bindingify_string_or_regex_array),
+      duplicate_package: value.duplicate_package,
+      boundaries: value
+        .boundaries
+        .map(|rules| rules.into_iter().map(TryInto::try_into).collect::<anyhow::Result<Vec<_>>>())
+        .transpose()?,
+    })
+  }
+}
+
+impl TryFrom<BindingBoundaryRule> for rolldown_common::BoundaryRule {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingBoundaryRule) -> Result<Self, Self::Error> {
+    Ok(Self {
+      from: value.from.map(Into::into),
+      disallow: value.disallow.into(),
+      platform: value
+        .platform
+        .as_deref()
+        .map(Platform::try_from)
+        .transpose()
+        .map_err(|err| anyhow::anyhow!("Invalid value for `checks.boundaries` option: {err}"))?,
+      message: value.message,
+    })
   }
 }
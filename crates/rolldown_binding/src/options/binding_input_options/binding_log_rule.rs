@@ -0,0 +1,24 @@
+use crate::options::plugin::types::binding_js_or_regex::BindingStringOrRegex;
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct BindingLogRule {
+  pub code: Option<String>,
+  pub id: Option<BindingStringOrRegex>,
+  #[napi(ts_type = "'ignore' | 'warn' | 'error'")]
+  pub action: String,
+}
+
+impl TryFrom<BindingLogRule> for rolldown_common::LogRule {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingLogRule) -> Result<Self, Self::Error> {
+    let action = match value.action.as_str() {
+      "ignore" => rolldown_common::LogAction::Ignore,
+      "warn" => rolldown_common::LogAction::Warn,
+      "error" => rolldown_common::LogAction::Error,
+      _ => anyhow::bail!("Invalid value for `logRules` option: {}", value.action),
+    };
+    Ok(Self { code: value.code, id: value.id.map(TryInto::try_into).transpose()?, action })
+  }
+}
@@ -8,6 +8,7 @@ pub struct BindingResolveOptions {
   pub alias: Option<Vec<AliasItem>>,
   pub alias_fields: Option<Vec<Vec<String>>>,
   pub condition_names: Option<Vec<String>>,
+  pub dedupe: Option<Vec<String>>,
   pub exports_fields: Option<Vec<Vec<String>>>,
   pub extensions: Option<Vec<String>>,
   pub extension_alias: Option<Vec<ExtensionAliasItem>>,
@@ -29,6 +30,7 @@ impl From<BindingResolveOptions> for rolldown::ResolveOptions {
       }),
       alias_fields: value.alias_fields,
       condition_names: value.condition_names,
+      dedupe: value.dedupe,
       exports_fields: value.exports_fields,
       extensions: value.extensions,
       extension_alias: value.extension_alias.map(|alias| {
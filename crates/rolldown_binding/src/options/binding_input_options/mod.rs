@@ -3,11 +3,13 @@ mod binding_experimental_options;
 pub mod binding_inject_import;
 mod binding_input_item;
 mod binding_jsx;
+mod binding_log_rule;
 mod binding_resolve_options;
 mod binding_treeshake;
 mod binding_watch_option;
 
 use derive_more::Debug;
+use napi::bindgen_prelude::{Buffer, Either};
 use napi_derive::napi;
 use rustc_hash::FxBuildHasher;
 use std::collections::HashMap;
@@ -15,6 +17,7 @@ use std::collections::HashMap;
 use binding_inject_import::BindingInjectImport;
 use binding_input_item::BindingInputItem;
 use binding_jsx::BindingJsx;
+use binding_log_rule::BindingLogRule;
 use binding_resolve_options::BindingResolveOptions;
 use binding_watch_option::BindingWatchOption;
 
@@ -85,4 +88,8 @@ pub struct BindingInputOptions {
   pub watch: Option<BindingWatchOption>,
   pub keep_names: Option<bool>,
   pub checks: Option<binding_checks_options::BindingChecksOptions>,
+  pub log_rules: Option<Vec<BindingLogRule>>,
+  #[debug(skip)]
+  #[napi(ts_type = "number | ((id: string, content: Uint8Array) => VoidNullable<boolean>)")]
+  pub assets_inline_limit: Option<Either<u32, JsCallback<(String, Buffer), Option<bool>>>>,
 }
@@ -0,0 +1,21 @@
+use crate::options::plugin::types::binding_js_or_regex::BindingStringOrRegex;
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct BindingManglePropsOptions {
+  pub regex: BindingStringOrRegex,
+  pub reserved: Option<Vec<String>>,
+  pub name_cache: Option<String>,
+}
+
+impl TryFrom<BindingManglePropsOptions> for rolldown::ManglePropsOptions {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingManglePropsOptions) -> Result<Self, Self::Error> {
+    Ok(Self {
+      regex: value.regex.try_into()?,
+      reserved: value.reserved,
+      name_cache: value.name_cache,
+    })
+  }
+}
@@ -0,0 +1,48 @@
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct BindingMinifyOptions {
+  pub compress: Option<BindingMinifyCompressOptions>,
+  pub mangle: Option<BindingMinifyMangleOptions>,
+  /// Only remove whitespace. `compress` and `mangle` are ignored.
+  pub whitespace_only: Option<bool>,
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct BindingMinifyCompressOptions {
+  pub drop_console: Option<bool>,
+  pub drop_debugger: Option<bool>,
+  pub pure_funcs: Option<Vec<String>>,
+  pub passes: Option<u32>,
+}
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct BindingMinifyMangleOptions {
+  pub top_level: Option<bool>,
+  pub keep_fnames: Option<bool>,
+  pub keep_classnames: Option<bool>,
+  pub reserved: Option<Vec<String>>,
+  pub name_cache: Option<String>,
+}
+
+impl From<BindingMinifyOptions> for rolldown::MinifyOptions {
+  fn from(value: BindingMinifyOptions) -> Self {
+    Self {
+      compress: value.compress.map(|compress| rolldown::MinifyCompressOptions {
+        drop_console: compress.drop_console,
+        drop_debugger: compress.drop_debugger,
+        pure_funcs: compress.pure_funcs,
+        passes: compress.passes,
+      }),
+      mangle: value.mangle.map(|mangle| rolldown::MinifyMangleOptions {
+        top_level: mangle.top_level,
+        keep_fnames: mangle.keep_fnames,
+        keep_classnames: mangle.keep_classnames,
+        reserved: mangle.reserved,
+        name_cache: mangle.name_cache,
+      }),
+      whitespace_only: value.whitespace_only,
+    }
+  }
+}
@@ -0,0 +1,20 @@
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct BindingRuntimeHelpers {
+  /// Import the used helpers from this specifier.
+  pub external: Option<String>,
+  /// Emit all helpers as a separate asset with this file name.
+  pub shared: Option<String>,
+}
+
+impl TryFrom<BindingRuntimeHelpers> for rolldown::RuntimeHelpers {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingRuntimeHelpers) -> Result<Self, Self::Error> {
+    match (value.external, value.shared) {
+      (Some(specifier), None) => Ok(Self::External(specifier)),
+      (None, Some(file_name)) => Ok(Self::Shared(file_name)),
+      _ => anyhow::bail!("`runtimeHelpers` option expects exactly one of `external` and `shared`"),
+    }
+  }
+}
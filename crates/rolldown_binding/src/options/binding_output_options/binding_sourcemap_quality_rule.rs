@@ -0,0 +1,23 @@
+use crate::options::plugin::types::binding_js_or_regex::BindingStringOrRegex;
+
+#[napi_derive::napi(object, object_to_js = false)]
+#[derive(Debug)]
+pub struct BindingSourcemapQualityRule {
+  pub test: BindingStringOrRegex,
+  #[napi(ts_type = "'full' | 'cheap' | 'excluded'")]
+  pub quality: String,
+}
+
+impl TryFrom<BindingSourcemapQualityRule> for rolldown::SourceMapQualityRule {
+  type Error = anyhow::Error;
+
+  fn try_from(value: BindingSourcemapQualityRule) -> Result<Self, Self::Error> {
+    let quality = match value.quality.as_str() {
+      "full" => rolldown::SourceMapQuality::Full,
+      "cheap" => rolldown::SourceMapQuality::Cheap,
+      "excluded" => rolldown::SourceMapQuality::Excluded,
+      _ => anyhow::bail!("Invalid value for `sourcemapQuality` option: {}", value.quality),
+    };
+    Ok(Self { test: value.test.try_into()?, quality })
+  }
+}
@@ -1,5 +1,9 @@
 mod binding_advanced_chunks_options;
+mod binding_mangle_props_options;
+mod binding_minify_options;
 mod binding_pre_rendered_chunk;
+mod binding_runtime_helpers;
+mod binding_sourcemap_quality_rule;
 
 use derive_more::Debug;
 use napi::Either;
@@ -7,7 +11,11 @@ use napi_derive::napi;
 use rustc_hash::FxHashMap;
 
 use binding_advanced_chunks_options::BindingAdvancedChunksOptions;
+use binding_mangle_props_options::BindingManglePropsOptions;
+use binding_minify_options::BindingMinifyOptions;
 use binding_pre_rendered_chunk::PreRenderedChunk;
+use binding_runtime_helpers::BindingRuntimeHelpers;
+use binding_sourcemap_quality_rule::BindingSourcemapQualityRule;
 
 use super::plugin::BindingPluginOrParallelJsPluginPlaceholder;
 use crate::types::{
@@ -97,9 +105,13 @@ pub struct BindingOutputOptions {
   #[napi(ts_type = "(source: string, sourcemapPath: string) => boolean")]
   pub sourcemap_ignore_list: Option<JsCallback<(String, String), bool>>,
   pub sourcemap_debug_ids: Option<bool>,
+  pub sourcemap_debug_ids_global: Option<String>,
+  pub sourcemap_debug_ids_manifest: Option<String>,
   #[debug(skip)]
   #[napi(ts_type = "(source: string, sourcemapPath: string) => string")]
   pub sourcemap_path_transform: Option<JsCallback<(String, String), String>>,
+  pub sourcemap_index_map: Option<bool>,
+  pub sourcemap_quality: Option<Vec<BindingSourcemapQualityRule>>,
   // sourcemapExcludeSources: boolean;
   // sourcemapFile: string | undefined;
   // strict: boolean;
@@ -107,10 +119,16 @@ pub struct BindingOutputOptions {
   // validate: boolean;
 
   // --- Enhanced options
-  pub minify: Option<bool>,
+  #[napi(ts_type = "boolean | BindingMinifyOptions")]
+  pub minify: Option<Either<bool, BindingMinifyOptions>>,
+  pub mangle_props: Option<BindingManglePropsOptions>,
   pub advanced_chunks: Option<BindingAdvancedChunksOptions>,
-  #[napi(ts_type = "'none' | 'preserve-legal'")]
+  #[napi(ts_type = "'none' | 'preserve-legal' | 'external'")]
   pub comments: Option<String>,
+  pub legal_comments_file: Option<String>,
+  pub license_report: Option<String>,
   pub polyfill_require: Option<bool>,
   pub target: Option<String>,
+  #[napi(ts_type = "'inline' | BindingRuntimeHelpers")]
+  pub runtime_helpers: Option<Either<String, BindingRuntimeHelpers>>,
 }
@@ -315,7 +315,7 @@ impl TryFrom<BindingBuiltinPlugin> for Arc<dyn Pluginable> {
   fn try_from(plugin: BindingBuiltinPlugin) -> Result<Self, Self::Error> {
     Ok(match plugin.__name {
       BindingBuiltinPluginName::WasmHelper => Arc::new(WasmHelperPlugin {}),
-      BindingBuiltinPluginName::WasmFallback => Arc::new(WasmFallbackPlugin {}),
+      BindingBuiltinPluginName::WasmFallback => Arc::new(WasmFallbackPlugin::default()),
       BindingBuiltinPluginName::ImportGlob => {
         let config = if let Some(options) = plugin.options {
           BindingGlobImportPluginConfig::from_unknown(options)?.into()
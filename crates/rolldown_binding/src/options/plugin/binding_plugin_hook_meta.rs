@@ -24,6 +24,20 @@ pub struct BindingPluginHookMeta {
 
 impl From<&BindingPluginHookMeta> for rolldown_plugin::PluginHookMeta {
   fn from(value: &BindingPluginHookMeta) -> Self {
-    rolldown_plugin::PluginHookMeta { order: value.order.map(Into::into) }
+    rolldown_plugin::PluginHookMeta { order: value.order.map(Into::into), filter: None }
+  }
+}
+
+impl BindingPluginHookMeta {
+  pub fn to_meta_with_filter(
+    meta: Option<&Self>,
+    filter: Option<rolldown_plugin::HookFilter>,
+  ) -> Option<rolldown_plugin::PluginHookMeta> {
+    if meta.is_none() && filter.is_none() {
+      return None;
+    }
+    let mut ret = meta.map(rolldown_plugin::PluginHookMeta::from).unwrap_or_default();
+    ret.filter = filter;
+    Some(ret)
   }
 }
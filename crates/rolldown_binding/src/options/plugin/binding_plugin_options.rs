@@ -18,8 +18,12 @@ use super::{
     binding_hook_filter::{BindingGeneralHookFilter, BindingTransformHookFilter},
     binding_hook_load_output::BindingHookLoadOutput,
     binding_hook_render_chunk_output::BindingHookRenderChunkOutput,
+    binding_hook_render_dynamic_import_args::BindingHookRenderDynamicImportArgs,
+    binding_hook_render_dynamic_import_output::BindingHookRenderDynamicImportOutput,
+    binding_hook_resolve_file_url_args::BindingHookResolveFileUrlArgs,
     binding_hook_resolve_id_extra_args::BindingHookResolveIdExtraArgs,
     binding_hook_resolve_id_output::BindingHookResolveIdOutput,
+    binding_hook_resolve_import_meta_args::BindingHookResolveImportMetaArgs,
     binding_hook_transform_output::BindingHookTransformOutput,
     binding_plugin_transform_extra_args::BindingTransformHookExtraArgs,
   },
@@ -184,6 +188,36 @@ pub struct BindingPluginOptions {
   #[napi(ts_type = "(ctx: BindingPluginContext, chunk: RenderedChunk) => void")]
   pub outro: Option<MaybeAsyncJsCallback<(BindingPluginContext, RenderedChunk), Option<String>>>,
   pub outro_meta: Option<BindingPluginHookMeta>,
+
+  #[napi(
+    ts_type = "(ctx: BindingPluginContext, property: Nullable<string>, args: BindingHookResolveImportMetaArgs) => MaybePromise<VoidNullable<string>>"
+  )]
+  pub resolve_import_meta: Option<
+    MaybeAsyncJsCallback<
+      (BindingPluginContext, Option<String>, BindingHookResolveImportMetaArgs),
+      Option<String>,
+    >,
+  >,
+  pub resolve_import_meta_meta: Option<BindingPluginHookMeta>,
+
+  #[napi(
+    ts_type = "(ctx: BindingPluginContext, args: BindingHookResolveFileUrlArgs) => MaybePromise<VoidNullable<string>>"
+  )]
+  pub resolve_file_url: Option<
+    MaybeAsyncJsCallback<(BindingPluginContext, BindingHookResolveFileUrlArgs), Option<String>>,
+  >,
+  pub resolve_file_url_meta: Option<BindingPluginHookMeta>,
+
+  #[napi(
+    ts_type = "(ctx: BindingPluginContext, args: BindingHookRenderDynamicImportArgs) => MaybePromise<VoidNullable<BindingHookRenderDynamicImportOutput>>"
+  )]
+  pub render_dynamic_import: Option<
+    MaybeAsyncJsCallback<
+      (BindingPluginContext, BindingHookRenderDynamicImportArgs),
+      Option<BindingHookRenderDynamicImportOutput>,
+    >,
+  >,
+  pub render_dynamic_import_meta: Option<BindingPluginHookMeta>,
 }
 
 impl Debug for BindingPluginOptions {
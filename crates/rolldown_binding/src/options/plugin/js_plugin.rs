@@ -5,21 +5,13 @@ use crate::types::{
   js_callback::MaybeAsyncJsCallbackExt,
 };
 use anyhow::Ok;
-use rolldown::ModuleType;
 use rolldown_plugin::{Plugin, __inner::SharedPluginable, typedmap::TypedMapKey};
-use rolldown_utils::pattern_filter::{self, FilterResult};
-use std::{
-  borrow::Cow,
-  ops::Deref,
-  path::{Path, PathBuf},
-  sync::Arc,
-};
-use sugar_path::SugarPath;
+use std::{borrow::Cow, ops::Deref, sync::Arc};
 
 use super::{
+  binding_plugin_hook_meta::BindingPluginHookMeta,
   binding_transform_context::BindingTransformPluginContext,
   types::{
-    binding_hook_filter::BindingTransformHookFilter,
     binding_hook_resolve_id_extra_args::BindingHookResolveIdExtraArgs,
     binding_plugin_transform_extra_args::BindingTransformHookExtraArgs,
   },
@@ -87,23 +79,6 @@ impl Plugin for JsPlugin {
   ) -> rolldown_plugin::HookResolveIdReturn {
     let Some(cb) = &self.resolve_id else { return Ok(None) };
 
-    if let Some(resolve_id_filter) = &self.inner.resolve_id_filter {
-      let stabilized_path = Path::new(args.specifier).relative(ctx.cwd());
-      let normalized_id = stabilized_path.to_string_lossy();
-
-      let matched = pattern_filter::filter(
-        resolve_id_filter.exclude.as_deref(),
-        resolve_id_filter.include.as_deref(),
-        args.specifier,
-        &normalized_id,
-      )
-      .inner();
-
-      if !matched {
-        return Ok(None);
-      }
-    }
-
     let extra_args = BindingHookResolveIdExtraArgs {
       is_entry: args.is_entry,
       kind: args.kind.to_string(),
@@ -126,7 +101,10 @@ impl Plugin for JsPlugin {
   }
 
   fn resolve_id_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
-    self.resolve_id_meta.as_ref().map(Into::into)
+    BindingPluginHookMeta::to_meta_with_filter(
+      self.resolve_id_meta.as_ref(),
+      self.resolve_id_filter.clone().map(Into::into),
+    )
   }
 
   async fn resolve_dynamic_import(
@@ -160,23 +138,6 @@ impl Plugin for JsPlugin {
   ) -> rolldown_plugin::HookLoadReturn {
     let Some(cb) = &self.load else { return Ok(None) };
 
-    if let Some(load_filter) = &self.load_filter {
-      let stabilized_path = Path::new(args.id).relative(ctx.cwd());
-      let normalized_id = stabilized_path.to_string_lossy();
-
-      let matched = pattern_filter::filter(
-        load_filter.exclude.as_deref(),
-        load_filter.include.as_deref(),
-        args.id,
-        &normalized_id,
-      )
-      .inner();
-
-      if !matched {
-        return Ok(None);
-      }
-    }
-
     cb.await_call((ctx.clone().into(), args.id.to_string()))
       .await?
       .map(TryInto::try_into)
@@ -184,7 +145,10 @@ impl Plugin for JsPlugin {
   }
 
   fn load_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
-    self.load_meta.as_ref().map(Into::into)
+    BindingPluginHookMeta::to_meta_with_filter(
+      self.load_meta.as_ref(),
+      self.load_filter.clone().map(Into::into),
+    )
   }
 
   async fn transform(
@@ -194,16 +158,6 @@ impl Plugin for JsPlugin {
   ) -> rolldown_plugin::HookTransformReturn {
     let Some(cb) = &self.transform else { return Ok(None) };
 
-    if !filter_transform(
-      self.transform_filter.as_ref(),
-      args.id,
-      ctx.inner.cwd(),
-      args.module_type,
-      args.code,
-    )? {
-      return Ok(None);
-    }
-
     let extra_args = BindingTransformHookExtraArgs { module_type: args.module_type.to_string() };
 
     cb.await_call((
@@ -218,7 +172,10 @@ impl Plugin for JsPlugin {
   }
 
   fn transform_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
-    self.transform_meta.as_ref().map(Into::into)
+    BindingPluginHookMeta::to_meta_with_filter(
+      self.transform_meta.as_ref(),
+      self.transform_filter.clone().map(Into::into),
+    )
   }
 
   async fn module_parsed(
@@ -363,6 +320,63 @@ impl Plugin for JsPlugin {
     self.footer_meta.as_ref().map(Into::into)
   }
 
+  // The hooks below are called synchronously while modules are rendered, so they block on the JS
+  // callbacks.
+  fn resolve_import_meta(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookResolveImportMetaArgs<'_>,
+  ) -> rolldown_plugin::HookInjectionOutputReturn {
+    if let Some(cb) = &self.resolve_import_meta {
+      Ok(futures::executor::block_on(cb.await_call((
+        ctx.clone().into(),
+        args.property.map(str::to_string),
+        args.into(),
+      )))?)
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn resolve_import_meta_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.resolve_import_meta_meta.as_ref().map(Into::into)
+  }
+
+  fn resolve_file_url(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookResolveFileUrlArgs<'_>,
+  ) -> rolldown_plugin::HookInjectionOutputReturn {
+    if let Some(cb) = &self.resolve_file_url {
+      Ok(futures::executor::block_on(cb.await_call((ctx.clone().into(), args.into())))?)
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn resolve_file_url_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.resolve_file_url_meta.as_ref().map(Into::into)
+  }
+
+  fn render_dynamic_import(
+    &self,
+    ctx: &rolldown_plugin::PluginContext,
+    args: &rolldown_plugin::HookRenderDynamicImportArgs<'_>,
+  ) -> rolldown_plugin::HookRenderDynamicImportReturn {
+    if let Some(cb) = &self.render_dynamic_import {
+      Ok(
+        futures::executor::block_on(cb.await_call((ctx.clone().into(), args.into())))?
+          .map(Into::into),
+      )
+    } else {
+      Ok(None)
+    }
+  }
+
+  fn render_dynamic_import_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.render_dynamic_import_meta.as_ref().map(Into::into)
+  }
+
   async fn render_chunk(
     &self,
     ctx: &rolldown_plugin::PluginContext,
@@ -517,66 +531,3 @@ impl Plugin for JsPlugin {
     self.close_watcher_meta.as_ref().map(Into::into)
   }
 }
-
-/// If the transform hook is filtered out and need to be skipped.
-/// Using `Option<bool>` for better programming experience.
-/// return `None` means it is early return, should not be skipped.
-/// return `Some(false)` means it should be skipped.
-/// return `Some(true)` means it should not be skipped.
-/// Since transform has three different filter, so we need to check all of them.
-fn filter_transform(
-  transform_filter: Option<&BindingTransformHookFilter>,
-  id: &str,
-  cwd: &PathBuf,
-  module_type: &ModuleType,
-  code: &str,
-) -> anyhow::Result<bool> {
-  let Some(transform_filter) = transform_filter else {
-    return Ok(true);
-  };
-
-  let mut fallback_ret = if let Some(ref module_type_filter) = transform_filter.module_type {
-    if module_type_filter.iter().any(|ty| ty.as_ref() == module_type) {
-      return Ok(true);
-    }
-    false
-  } else {
-    true
-  };
-
-  if let Some(ref id_filter) = transform_filter.id {
-    let stabilized_path = Path::new(id).relative(cwd);
-    let normalized_id = stabilized_path.to_string_lossy();
-
-    let id_res = pattern_filter::filter(
-      id_filter.exclude.as_deref(),
-      id_filter.include.as_deref(),
-      id,
-      &normalized_id,
-    );
-
-    // it matched by `exclude` or `include`, early return
-    if let FilterResult::Match(id_res) = id_res {
-      return Ok(id_res);
-    }
-
-    fallback_ret = fallback_ret && id_res.inner();
-  }
-
-  if let Some(ref code_filter) = transform_filter.code {
-    let code_res = pattern_filter::filter_code(
-      code_filter.exclude.as_deref(),
-      code_filter.include.as_deref(),
-      code,
-    );
-
-    // it matched by `exclude` or `include`, early return
-    if let FilterResult::Match(code_res) = code_res {
-      return Ok(code_res);
-    }
-
-    fallback_ret = fallback_ret && code_res.inner();
-  }
-
-  Ok(fallback_ret)
-}
@@ -104,6 +104,10 @@ impl Plugin for ParallelJsPlugin {
     }
   }
 
+  fn resolve_id_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.first_plugin().call_resolve_id_meta()
+  }
+
   async fn load(
     &self,
     ctx: &rolldown_plugin::PluginContext,
@@ -116,6 +120,10 @@ impl Plugin for ParallelJsPlugin {
     }
   }
 
+  fn load_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.first_plugin().call_load_meta()
+  }
+
   async fn transform(
     &self,
     ctx: rolldown_plugin::SharedTransformPluginContext,
@@ -128,6 +136,10 @@ impl Plugin for ParallelJsPlugin {
     }
   }
 
+  fn transform_meta(&self) -> Option<rolldown_plugin::PluginHookMeta> {
+    self.first_plugin().call_transform_meta()
+  }
+
   async fn build_end(
     &self,
     ctx: &rolldown_plugin::PluginContext,
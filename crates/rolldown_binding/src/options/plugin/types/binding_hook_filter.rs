@@ -1,4 +1,9 @@
-use super::{binding_js_or_regex::BindingStringOrRegex, binding_module_type::BindingModuleType};
+use rolldown_plugin::{GeneralHookFilter, HookFilter};
+
+use super::{
+  binding_js_or_regex::{bindingify_string_or_regex_array, BindingStringOrRegex},
+  binding_module_type::BindingModuleType,
+};
 
 #[napi_derive::napi(object, object_to_js = false)]
 #[derive(Clone, Debug)]
@@ -15,3 +20,30 @@ pub struct BindingTransformHookFilter {
   pub module_type: Option<Vec<BindingModuleType>>,
   pub id: Option<BindingGeneralHookFilter>,
 }
+
+impl From<BindingGeneralHookFilter> for GeneralHookFilter {
+  fn from(value: BindingGeneralHookFilter) -> Self {
+    Self {
+      include: value.include.map(bindingify_string_or_regex_array),
+      exclude: value.exclude.map(bindingify_string_or_regex_array),
+    }
+  }
+}
+
+impl From<BindingGeneralHookFilter> for HookFilter {
+  fn from(value: BindingGeneralHookFilter) -> Self {
+    Self { id: Some(value.into()), ..Default::default() }
+  }
+}
+
+impl From<BindingTransformHookFilter> for HookFilter {
+  fn from(value: BindingTransformHookFilter) -> Self {
+    Self {
+      id: value.id.map(Into::into),
+      module_type: value
+        .module_type
+        .map(|types| types.into_iter().map(|ty| ty.as_ref().clone()).collect()),
+      code: value.code.map(Into::into),
+    }
+  }
+}
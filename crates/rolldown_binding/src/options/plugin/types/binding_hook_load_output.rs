@@ -21,6 +21,8 @@ impl TryFrom<BindingHookLoadOutput> for rolldown_plugin::HookLoadOutput {
       map: value.map.map(TryInto::try_into).transpose()?,
       side_effects: value.side_effects.map(Into::into),
       module_type: value.module_type.map(|ty| ModuleType::from_str_with_fallback(ty.as_str())),
+      // `meta` of JS plugins is kept on the JS side, see `PluginContextData`.
+      meta: None,
     })
   }
 }
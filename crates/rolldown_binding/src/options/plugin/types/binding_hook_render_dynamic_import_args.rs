@@ -0,0 +1,26 @@
+use crate::types::binding_normalized_options::binding_output_format;
+
+#[napi_derive::napi(object)]
+#[derive(Default, Debug)]
+pub struct BindingHookRenderDynamicImportArgs {
+  pub chunk_id: String,
+  #[napi(ts_type = "'es' | 'cjs' | 'app' | 'iife' | 'umd'")]
+  pub format: String,
+  pub module_id: String,
+  pub specifier: String,
+  pub target_module_id: String,
+}
+
+impl From<&rolldown_plugin::HookRenderDynamicImportArgs<'_>>
+  for BindingHookRenderDynamicImportArgs
+{
+  fn from(value: &rolldown_plugin::HookRenderDynamicImportArgs<'_>) -> Self {
+    Self {
+      chunk_id: value.chunk_id.to_string(),
+      format: binding_output_format(value.format),
+      module_id: value.module_id.to_string(),
+      specifier: value.specifier.to_string(),
+      target_module_id: value.target_module_id.to_string(),
+    }
+  }
+}
@@ -0,0 +1,12 @@
+#[napi_derive::napi(object)]
+#[derive(Default, Debug)]
+pub struct BindingHookRenderDynamicImportOutput {
+  pub left: String,
+  pub right: String,
+}
+
+impl From<BindingHookRenderDynamicImportOutput> for rolldown_plugin::HookRenderDynamicImportOutput {
+  fn from(value: BindingHookRenderDynamicImportOutput) -> Self {
+    Self { left: value.left, right: value.right }
+  }
+}
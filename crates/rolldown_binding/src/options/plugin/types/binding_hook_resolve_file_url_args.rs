@@ -0,0 +1,26 @@
+use crate::types::binding_normalized_options::binding_output_format;
+
+#[napi_derive::napi(object)]
+#[derive(Default, Debug)]
+pub struct BindingHookResolveFileUrlArgs {
+  pub chunk_id: String,
+  pub file_name: String,
+  #[napi(ts_type = "'es' | 'cjs' | 'app' | 'iife' | 'umd'")]
+  pub format: String,
+  pub module_id: String,
+  pub reference_id: Option<String>,
+  pub relative_path: String,
+}
+
+impl From<&rolldown_plugin::HookResolveFileUrlArgs<'_>> for BindingHookResolveFileUrlArgs {
+  fn from(value: &rolldown_plugin::HookResolveFileUrlArgs<'_>) -> Self {
+    Self {
+      chunk_id: value.chunk_id.to_string(),
+      file_name: value.file_name.to_string(),
+      format: binding_output_format(value.format),
+      module_id: value.module_id.to_string(),
+      reference_id: value.reference_id.map(str::to_string),
+      relative_path: value.relative_path.to_string(),
+    }
+  }
+}
@@ -0,0 +1,20 @@
+use crate::types::binding_normalized_options::binding_output_format;
+
+#[napi_derive::napi(object)]
+#[derive(Default, Debug)]
+pub struct BindingHookResolveImportMetaArgs {
+  pub chunk_id: String,
+  #[napi(ts_type = "'es' | 'cjs' | 'app' | 'iife' | 'umd'")]
+  pub format: String,
+  pub module_id: String,
+}
+
+impl From<&rolldown_plugin::HookResolveImportMetaArgs<'_>> for BindingHookResolveImportMetaArgs {
+  fn from(value: &rolldown_plugin::HookResolveImportMetaArgs<'_>) -> Self {
+    Self {
+      chunk_id: value.chunk_id.to_string(),
+      format: binding_output_format(value.format),
+      module_id: value.module_id.to_string(),
+    }
+  }
+}
@@ -5,8 +5,12 @@ pub mod binding_emitted_chunk;
 pub mod binding_hook_filter;
 pub mod binding_hook_load_output;
 pub mod binding_hook_render_chunk_output;
+pub mod binding_hook_render_dynamic_import_args;
+pub mod binding_hook_render_dynamic_import_output;
+pub mod binding_hook_resolve_file_url_args;
 pub mod binding_hook_resolve_id_extra_args;
 pub mod binding_hook_resolve_id_output;
+pub mod binding_hook_resolve_import_meta_args;
 pub mod binding_hook_side_effects;
 pub mod binding_hook_transform_output;
 pub mod binding_js_or_regex;
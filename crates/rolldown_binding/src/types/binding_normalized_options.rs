@@ -117,13 +117,7 @@ impl BindingNormalizedOptions {
 
   #[napi(getter, ts_return_type = "'es' | 'cjs' | 'app' | 'iife' | 'umd'")]
   pub fn format(&self) -> String {
-    match self.inner.format {
-      rolldown::OutputFormat::Esm => "es".to_string(),
-      rolldown::OutputFormat::Cjs => "cjs".to_string(),
-      rolldown::OutputFormat::App => "app".to_string(),
-      rolldown::OutputFormat::Iife => "iife".to_string(),
-      rolldown::OutputFormat::Umd => "umd".to_string(),
-    }
+    binding_output_format(self.inner.format)
   }
 
   #[napi(getter, ts_return_type = "'default' | 'named' | 'none' | 'auto'")]
@@ -228,7 +222,7 @@ impl BindingNormalizedOptions {
 
   #[napi(getter)]
   pub fn minify(&self) -> bool {
-    self.inner.minify
+    self.inner.minify.is_some()
   }
 
   #[napi(getter)]
@@ -241,3 +235,14 @@ impl BindingNormalizedOptions {
     self.inner.comments.to_string()
   }
 }
+
+/// The name of `format` in the JS API, where `Esm` is called `es`.
+pub fn binding_output_format(format: rolldown::OutputFormat) -> String {
+  match format {
+    rolldown::OutputFormat::Esm => "es".to_string(),
+    rolldown::OutputFormat::Cjs => "cjs".to_string(),
+    rolldown::OutputFormat::App => "app".to_string(),
+    rolldown::OutputFormat::Iife => "iife".to_string(),
+    rolldown::OutputFormat::Umd => "umd".to_string(),
+  }
+}
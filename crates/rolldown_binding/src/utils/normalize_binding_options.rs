@@ -12,7 +12,7 @@ use napi::bindgen_prelude::Either;
 use rolldown::{
   AddonOutputOption, AdvancedChunksOptions, BundlerOptions, ChunkFilenamesOutputOption,
   ExperimentalOptions, HashCharacters, IsExternal, MatchGroup, ModuleType, OutputExports,
-  OutputFormat, Platform,
+  OutputFormat, Platform, RawMinifyOptions,
 };
 use rolldown_plugin::__inner::SharedPluginable;
 use rolldown_utils::indexmap::FxIndexMap;
@@ -152,6 +152,15 @@ pub fn normalize_binding_options(
     shim_missing_exports: input_options.shim_missing_exports,
     name: output_options.name,
     asset_filenames: output_options.asset_file_names,
+    assets_inline_limit: input_options.assets_inline_limit.map(|value| match value {
+      Either::A(limit) => rolldown::AssetsInlineLimit::Limit(limit as usize),
+      Either::B(ts_fn) => rolldown::AssetsInlineLimit::Fn(Arc::new(move |id, content| {
+        // Assets are inlined synchronously while linking.
+        Ok(futures::executor::block_on(
+          ts_fn.invoke_async((id.to_string(), content.to_vec().into())),
+        )?)
+      })),
+    }),
     entry_filenames: normalize_chunk_file_names_option(output_options.entry_file_names)?,
     chunk_filenames: normalize_chunk_file_names_option(output_options.chunk_file_names)?,
     css_entry_filenames: normalize_chunk_file_names_option(output_options.css_entry_file_names)?,
@@ -170,6 +179,13 @@ pub fn normalize_binding_options(
     sourcemap_ignore_list,
     sourcemap_path_transform,
     sourcemap_debug_ids: output_options.sourcemap_debug_ids,
+    sourcemap_debug_ids_global: output_options.sourcemap_debug_ids_global,
+    sourcemap_debug_ids_manifest: output_options.sourcemap_debug_ids_manifest,
+    sourcemap_index_map: output_options.sourcemap_index_map,
+    sourcemap_quality: output_options
+      .sourcemap_quality
+      .map(|rules| rules.into_iter().map(TryInto::try_into).collect::<anyhow::Result<Vec<_>>>())
+      .transpose()?,
     exports: output_options.exports.map(|format_str| match format_str.as_str() {
       "auto" => OutputExports::Auto,
       "default" => OutputExports::Default,
@@ -201,7 +217,11 @@ pub fn normalize_binding_options(
       // TODO: binding
       incremental_build: None,
     }),
-    minify: output_options.minify,
+    minify: output_options.minify.map(|minify| match minify {
+      Either::A(minify) => RawMinifyOptions::Boolean(minify),
+      Either::B(options) => RawMinifyOptions::Option(options.into()),
+    }),
+    mangle_props: output_options.mangle_props.map(TryInto::try_into).transpose()?,
     extend: output_options.extend,
     define: input_options.define.map(FxIndexMap::from_iter),
     inject: input_options
@@ -229,7 +249,11 @@ pub fn normalize_binding_options(
           .collect::<Vec<_>>()
       }),
     }),
-    checks: input_options.checks.map(Into::into),
+    checks: input_options.checks.map(TryInto::try_into).transpose()?,
+    log_rules: input_options
+      .log_rules
+      .map(|rules| rules.into_iter().map(TryInto::try_into).collect::<anyhow::Result<Vec<_>>>())
+      .transpose()?,
     profiler_names: input_options.profiler_names,
     jsx: input_options.jsx.map(Into::into),
     watch: input_options.watch.map(TryInto::try_into).transpose()?,
@@ -238,16 +262,27 @@ pub fn normalize_binding_options(
       .map(|inner| match inner.as_str() {
         "none" => Ok(rolldown::Comments::None),
         "preserve-legal" => Ok(rolldown::Comments::Preserve),
+        "external" => Ok(rolldown::Comments::External),
         _ => Err(napi::Error::new(
           napi::Status::GenericFailure,
           format!("Invalid valid for `comments` option: {inner}"),
         )),
       })
       .transpose()?,
+    legal_comments_file: output_options.legal_comments_file,
+    license_report: output_options.license_report,
     drop_labels: input_options.drop_labels,
     target: output_options.target.as_deref().map(std::str::FromStr::from_str).transpose()?,
     keep_names: input_options.keep_names,
     polyfill_require: output_options.polyfill_require,
+    runtime_helpers: output_options
+      .runtime_helpers
+      .map(|runtime_helpers| match runtime_helpers {
+        Either::A(mode) if mode == "inline" => Ok(rolldown::RuntimeHelpers::Inline),
+        Either::A(mode) => Err(anyhow::format_err!("Invalid value for `runtimeHelpers`: {mode}")),
+        Either::B(runtime_helpers) => runtime_helpers.try_into(),
+      })
+      .transpose()?,
   };
 
   #[cfg(not(target_family = "wasm"))]
@@ -1,11 +1,18 @@
 use std::fmt::Debug;
 
+use arcstr::ArcStr;
+
 pub struct AssetView {
   pub source: Box<[u8]>,
+  /// Set at link time if the asset is small enough to be inlined, it's not emitted as a file then.
+  pub data_url: Option<ArcStr>,
 }
 
 impl Debug for AssetView {
   fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-    f.debug_struct("AssetView").field("source", &"Box<[u8]>").finish()
+    f.debug_struct("AssetView")
+      .field("source", &"Box<[u8]>")
+      .field("data_url", &self.data_url)
+      .finish()
   }
 }
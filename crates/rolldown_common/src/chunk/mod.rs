@@ -74,10 +74,14 @@ impl Chunk {
   }
 
   pub fn import_path_for(&self, importee: &Chunk) -> String {
+    self.import_path_for_file(importee.absolute_preliminary_filename.as_ref().unwrap())
+  }
+
+  /// Like `import_path_for`, but for a file that isn't a chunk, such as an emitted asset.
+  pub fn import_path_for_file(&self, absolute_filename: &str) -> String {
     let importer_dir =
       self.absolute_preliminary_filename.as_ref().unwrap().as_path().parent().unwrap();
-    let importee_filename = importee.absolute_preliminary_filename.as_ref().unwrap();
-    let import_path = importee_filename.relative(importer_dir).as_path().expect_to_slash();
+    let import_path = absolute_filename.relative(importer_dir).as_path().expect_to_slash();
 
     if import_path.starts_with('.') {
       import_path
@@ -126,6 +126,9 @@ pub struct EcmaView {
   /// `Span` of `new URL('path', import.meta.url)` -> `ImportRecordIdx`
   pub new_url_references: FxHashMap<Span, ImportRecordIdx>,
   pub this_expr_replace_map: FxHashMap<Span, ThisExprReplaceKind>,
+  /// Imported bindings read while the module is evaluated, i.e. outside of any function. Reading
+  /// them throws if the importee hasn't been evaluated yet, which can happen in circular imports.
+  pub eagerly_evaluated_imports: FxHashSet<SymbolRef>,
 
   /// - Represents the `import_xxx` in `const import_xxx = __toESM(require_xxx());`
   /// - Only exist when this module is a cjs module and get imported by static `import` statement.
@@ -2,11 +2,17 @@ use rolldown_utils::indexmap::FxIndexMap;
 use rustc_hash::FxHashMap;
 use std::{fmt::Debug, path::PathBuf};
 use types::advanced_chunks_options::AdvancedChunksOptions;
+use types::assets_inline_limit::AssetsInlineLimit;
 use types::checks_options::ChecksOptions;
 use types::comments::Comments;
 use types::inject_import::InjectImport;
 use types::jsx::Jsx;
+use types::log_rule::LogRule;
+use types::mangle_props_options::ManglePropsOptions;
+use types::minify_options::RawMinifyOptions;
 use types::output_option::GlobalsOutputOption;
+use types::runtime_helpers::RuntimeHelpers;
+use types::sourcemap_quality::SourceMapQualityRule;
 use types::target::ESTarget;
 use types::watch_option::WatchOption;
 
@@ -74,6 +80,13 @@ pub struct BundlerOptions {
   )]
   pub css_chunk_filenames: Option<ChunkFilenamesOutputOption>,
   pub asset_filenames: Option<String>,
+  /// Assets smaller than the limit are inlined as data URLs instead of being emitted as files.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_assets_inline_limit"),
+    schemars(with = "Option<usize>")
+  )]
+  pub assets_inline_limit: Option<AssetsInlineLimit>,
   pub dir: Option<String>,
   pub file: Option<String>,
   pub format: Option<OutputFormat>,
@@ -125,6 +138,22 @@ pub struct BundlerOptions {
   )]
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub sourcemap_debug_ids: Option<bool>,
+  /// Name of a `globalThis` property, e.g. `"_sentryDebugIds"`, in which each chunk registers its
+  /// debug id keyed by the stack trace of an error created in the chunk, so error reporters can find
+  /// the debug id of a stack frame. The snippet is appended to the chunk to keep the source map
+  /// lines intact, so errors thrown while the chunk is evaluated are not covered. Requires
+  /// `sourcemapDebugIds`.
+  pub sourcemap_debug_ids_global: Option<String>,
+  /// File name of a JSON asset listing every chunk with its source map file and debug id. Requires
+  /// `sourcemapDebugIds`.
+  pub sourcemap_debug_ids_manifest: Option<String>,
+  /// Emit source map v3 index maps with one section per rendered module instead of one flattened
+  /// map. Chunks changed by `renderChunk` hooks or by `minify` fall back to a flattened map, and
+  /// `OutputChunk::map` is only set for flattened maps.
+  pub sourcemap_index_map: Option<bool>,
+  /// Source map quality of the modules whose id matches `test`. The first matching rule wins, and
+  /// modules without a match get `full` mappings.
+  pub sourcemap_quality: Option<Vec<SourceMapQualityRule>>,
 
   /// Key is the file extension. The extension should start with a `.`. E.g. `".txt"`.
   pub module_types: Option<FxHashMap<String, ModuleType>>,
@@ -136,7 +165,9 @@ pub struct BundlerOptions {
   )]
   pub treeshake: TreeshakeOptions,
   pub experimental: Option<ExperimentalOptions>,
-  pub minify: Option<bool>,
+  pub minify: Option<RawMinifyOptions>,
+  /// Renames properties matching a regex consistently across all chunks.
+  pub mangle_props: Option<ManglePropsOptions>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     schemars(with = "Option<FxHashMap<String, String>>")
@@ -150,6 +181,8 @@ pub struct BundlerOptions {
   pub inline_dynamic_imports: Option<bool>,
   pub advanced_chunks: Option<AdvancedChunksOptions>,
   pub checks: Option<ChecksOptions>,
+  /// Ordered rules to ignore diagnostics, report them as warnings, or make them fail the build.
+  pub log_rules: Option<Vec<LogRule>>,
   #[cfg_attr(
     feature = "deserialize_bundler_options",
     serde(deserialize_with = "deserialize_jsx", default),
@@ -158,8 +191,16 @@ pub struct BundlerOptions {
   pub jsx: Option<Jsx>,
   pub watch: Option<WatchOption>,
   pub comments: Option<Comments>,
+  /// With `comments: "external"`, the legal comments of all chunks are written to this file instead
+  /// of a `<chunk>.LEGAL.txt` asset per chunk, which is named by `asset_filenames`.
+  pub legal_comments_file: Option<String>,
+  /// File name of a JSON asset that lists the bundled third-party packages with their `license`,
+  /// grouped by package.
+  pub license_report: Option<String>,
   pub target: Option<ESTarget>,
   pub polyfill_require: Option<bool>,
+  /// Defaults to `RuntimeHelpers::Inline`. Only `esm` and `cjs` formats support the other modes.
+  pub runtime_helpers: Option<RuntimeHelpers>,
 }
 
 #[cfg(feature = "deserialize_bundler_options")]
@@ -171,6 +212,17 @@ where
   Ok(deserialized.map(IsExternal::from_vec))
 }
 
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_assets_inline_limit<'de, D>(
+  deserializer: D,
+) -> Result<Option<AssetsInlineLimit>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<usize>::deserialize(deserializer)?;
+  Ok(deserialized.map(From::from))
+}
+
 #[cfg(feature = "deserialize_bundler_options")]
 fn deserialize_addon<'de, D>(deserializer: D) -> Result<Option<AddonOutputOption>, D::Error>
 where
@@ -0,0 +1,43 @@
+use std::{fmt::Debug, sync::Arc};
+
+/// Receives the id and the content of an asset. Returning `None` falls back to emitting the asset
+/// as a separate file.
+pub type AssetsInlineLimitFn = dyn Fn(&str, &[u8]) -> anyhow::Result<Option<bool>> + Send + Sync;
+
+/// Assets that should be inlined as data URLs instead of being emitted as separate files.
+#[derive(Clone)]
+pub enum AssetsInlineLimit {
+  /// Assets smaller than this byte size are inlined.
+  Limit(usize),
+  Fn(Arc<AssetsInlineLimitFn>),
+}
+
+impl Debug for AssetsInlineLimit {
+  fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+    match self {
+      Self::Limit(value) => write!(f, "AssetsInlineLimit::Limit({value:?})"),
+      Self::Fn(_) => write!(f, "AssetsInlineLimit::Fn(...)"),
+    }
+  }
+}
+
+impl Default for AssetsInlineLimit {
+  fn default() -> Self {
+    Self::Limit(0)
+  }
+}
+
+impl AssetsInlineLimit {
+  pub fn should_inline(&self, id: &str, content: &[u8]) -> anyhow::Result<bool> {
+    match self {
+      Self::Limit(limit) => Ok(content.len() < *limit),
+      Self::Fn(f) => Ok(f(id, content)?.unwrap_or(false)),
+    }
+  }
+}
+
+impl From<usize> for AssetsInlineLimit {
+  fn from(value: usize) -> Self {
+    Self::Limit(value)
+  }
+}
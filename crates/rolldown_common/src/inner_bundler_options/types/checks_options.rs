@@ -1,7 +1,10 @@
+use rolldown_utils::pattern_filter::StringOrRegex;
 #[cfg(feature = "deserialize_bundler_options")]
 use schemars::JsonSchema;
 #[cfg(feature = "deserialize_bundler_options")]
-use serde::Deserialize;
+use serde::{Deserialize, Deserializer};
+
+use super::platform::Platform;
 
 #[derive(Default, Debug, Clone)]
 #[cfg_attr(
@@ -11,4 +14,74 @@ use serde::Deserialize;
 )]
 pub struct ChecksOptions {
   pub circular_dependency: Option<bool>,
+  /// Cycles passing through modules matching these patterns are not reported. Globs are matched
+  /// against module ids relative to `cwd`, and regexes against module ids.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_patterns"),
+    schemars(with = "Option<Vec<String>>")
+  )]
+  pub circular_dependency_ignore: Option<Vec<StringOrRegex>>,
+  /// Warns when a package is bundled from several locations, e.g. from nested `node_modules`.
+  pub duplicate_package: Option<bool>,
+  /// Import edges that are not allowed. Violations are reported as `BOUNDARY_VIOLATION` errors.
+  pub boundaries: Option<Vec<BoundaryRule>>,
+}
+
+/// Forbids modules matching `from` to import modules matching `disallow`.
+///
+/// Patterns are globs matched against module ids relative to `cwd`, or regexes matched against
+/// module ids. External modules are matched by their import specifier, e.g. `node:*`.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct BoundaryRule {
+  /// The importers the rule applies to. Applies to all modules if omitted.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_from"),
+    schemars(with = "Option<String>")
+  )]
+  pub from: Option<StringOrRegex>,
+  /// The importees that are forbidden.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(deserialize_with = "deserialize_disallow"),
+    schemars(with = "String")
+  )]
+  pub disallow: StringOrRegex,
+  /// Only applies the rule when building for this platform.
+  pub platform: Option<Platform>,
+  /// Appended to the diagnostic, e.g. to explain why the import is forbidden.
+  pub message: Option<String>,
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_patterns<'de, D>(deserializer: D) -> Result<Option<Vec<StringOrRegex>>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<Vec<String>>::deserialize(deserializer)?;
+  Ok(deserialized.map(|patterns| patterns.into_iter().map(StringOrRegex::String).collect()))
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_from<'de, D>(deserializer: D) -> Result<Option<StringOrRegex>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<String>::deserialize(deserializer)?;
+  Ok(deserialized.map(StringOrRegex::String))
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_disallow<'de, D>(deserializer: D) -> Result<StringOrRegex, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = String::deserialize(deserializer)?;
+  Ok(StringOrRegex::String(deserialized))
 }
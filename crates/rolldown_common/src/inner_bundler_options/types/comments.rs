@@ -18,6 +18,8 @@ pub enum Comments {
   Preserve,
   /// Keep legal comments only
   PreserveLegal,
+  /// Move legal comments to a separate asset, which is referenced by a comment at the end of the chunk
+  External,
 }
 
 impl Display for Comments {
@@ -26,6 +28,7 @@ impl Display for Comments {
       Comments::None => write!(f, "none"),
       Comments::Preserve => write!(f, "preserve"),
       Comments::PreserveLegal => write!(f, "preserve-legal"),
+      Comments::External => write!(f, "external"),
     }
   }
 }
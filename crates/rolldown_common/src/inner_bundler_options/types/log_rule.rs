@@ -0,0 +1,52 @@
+use rolldown_utils::pattern_filter::StringOrRegex;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::{Deserialize, Deserializer};
+
+/// Decides what happens to diagnostics matching both `code` and `id`. Rules are checked in order,
+/// and the first matching rule wins.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct LogRule {
+  /// The code of diagnostics, e.g. `"EVAL"`. Matches all codes if omitted.
+  pub code: Option<String>,
+  /// A glob pattern matched against the module id relative to `cwd`, or a regex matched against
+  /// the module id. Matches all diagnostics if omitted, and never matches diagnostics that are not
+  /// about a module otherwise.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(default, deserialize_with = "deserialize_id"),
+    schemars(with = "Option<String>")
+  )]
+  pub id: Option<StringOrRegex>,
+  pub action: LogAction,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum LogAction {
+  /// Drop the diagnostic.
+  Ignore,
+  /// Report the diagnostic as a warning.
+  Warn,
+  /// Report the diagnostic as an error, which fails the build.
+  Error,
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_id<'de, D>(deserializer: D) -> Result<Option<StringOrRegex>, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let deserialized = Option::<String>::deserialize(deserializer)?;
+  Ok(deserialized.map(StringOrRegex::String))
+}
@@ -0,0 +1,42 @@
+use rolldown_utils::js_regex::HybridRegex;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::{Deserialize, Deserializer};
+
+/// Renames object properties across the whole bundle.
+///
+/// Only identifier property names are renamed: `obj._foo`, `{ _foo: 1 }`, class members and
+/// destructuring keys. Quoted names such as `obj["_foo"]` are left alone and can be used to opt out.
+/// Export names are never renamed.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct ManglePropsOptions {
+  /// Properties whose names match are renamed, e.g. `^_`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(deserialize_with = "deserialize_regex"),
+    schemars(with = "String")
+  )]
+  pub regex: HybridRegex,
+  /// Property names that are never renamed, even if they match `regex`.
+  pub reserved: Option<Vec<String>>,
+  /// Path of a JSON file, relative to `cwd`, that maps property names to their mangled names. It is
+  /// read before mangling and updated when the bundle is written, so properties keep their names
+  /// across builds.
+  pub name_cache: Option<String>,
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_regex<'de, D>(deserializer: D) -> Result<HybridRegex, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let pattern = String::deserialize(deserializer)?;
+  HybridRegex::new(&pattern)
+    .map_err(|e| serde::de::Error::custom(format!("failed to deserialize {e:?} to HybridRegex")))
+}
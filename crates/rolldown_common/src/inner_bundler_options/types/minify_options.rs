@@ -0,0 +1,70 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// `true` minifies with the default compress and mangle settings.
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(untagged)
+)]
+pub enum RawMinifyOptions {
+  Boolean(bool),
+  Option(MinifyOptions),
+}
+
+/// Legal comments are kept at the end of each minified chunk unless `comments` is `none`.
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct MinifyOptions {
+  pub compress: Option<MinifyCompressOptions>,
+  pub mangle: Option<MinifyMangleOptions>,
+  /// Only remove whitespace. `compress` and `mangle` are ignored.
+  pub whitespace_only: Option<bool>,
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct MinifyCompressOptions {
+  /// Defaults to `false`.
+  pub drop_console: Option<bool>,
+  /// Defaults to `true`.
+  pub drop_debugger: Option<bool>,
+  /// Calls to these functions are dropped when their result is unused, e.g. `["console.info"]`.
+  /// Their arguments are dropped too.
+  pub pure_funcs: Option<Vec<String>>,
+  /// Defaults to `1`.
+  pub passes: Option<u32>,
+}
+
+#[derive(Debug, Default, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct MinifyMangleOptions {
+  /// Also mangle top-level bindings. Exported names are kept.
+  pub top_level: Option<bool>,
+  /// Keep the names of function declarations and named function expressions.
+  pub keep_fnames: Option<bool>,
+  /// Keep the names of class declarations and named class expressions.
+  pub keep_classnames: Option<bool>,
+  /// Names that are neither mangled nor used as mangled names.
+  pub reserved: Option<Vec<String>>,
+  /// Path of a JSON file, relative to `cwd`, that remembers the mangled names of top-level bindings
+  /// keyed by module id and binding name. It is read before minifying and updated when the bundle is
+  /// written, so unchanged bindings keep their names across builds. Has no effect unless `topLevel`
+  /// is enabled.
+  pub name_cache: Option<String>,
+}
@@ -1,4 +1,5 @@
 pub mod advanced_chunks_options;
+pub mod assets_inline_limit;
 pub mod checks_options;
 pub mod comments;
 pub mod es_module_flag;
@@ -9,6 +10,9 @@ pub mod inject_import;
 pub mod input_item;
 pub mod is_external;
 pub mod jsx;
+pub mod log_rule;
+pub mod mangle_props_options;
+pub mod minify_options;
 pub mod module_type;
 pub mod normalized_bundler_options;
 pub mod output_exports;
@@ -16,9 +20,11 @@ pub mod output_format;
 pub mod output_option;
 pub mod platform;
 pub mod resolve_options;
+pub mod runtime_helpers;
 pub mod source_map_type;
 pub mod sourcemap_ignore_list;
 pub mod sourcemap_path_transform;
+pub mod sourcemap_quality;
 pub mod target;
 pub mod treeshake;
 pub mod watch_option;
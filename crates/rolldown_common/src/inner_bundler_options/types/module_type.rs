@@ -61,6 +61,33 @@ impl ModuleType {
       _ => Self::Custom(s.as_ref().to_string()),
     }
   }
+
+  /// Maps the `type` of import attributes to the module type the importee is loaded as.
+  /// - `import data from './data.json' with { type: 'json' }`
+  /// - `import text from './README.md' with { type: 'text' }`
+  /// - `import bytes from './image.png' with { type: 'bytes' }`
+  /// - `import sheet from './style.css' with { type: 'css' }`
+  pub fn from_import_attribute_type(s: &str) -> Option<Self> {
+    match s {
+      "json" => Some(Self::Json),
+      "text" => Some(Self::Text),
+      "bytes" => Some(Self::Binary),
+      "css" => Some(Self::Css),
+      _ => None,
+    }
+  }
+
+  /// The reverse of `from_import_attribute_type`. Returns `None` for module types that can't be
+  /// asserted by import attributes.
+  pub fn as_import_attribute_type(&self) -> Option<&'static str> {
+    match self {
+      Self::Json => Some("json"),
+      Self::Text => Some("text"),
+      Self::Binary => Some("bytes"),
+      Self::Css => Some("css"),
+      _ => None,
+    }
+  }
 }
 
 impl Display for ModuleType {
@@ -8,19 +8,29 @@ use oxc::transformer::InjectGlobalVariablesConfig;
 use rustc_hash::{FxHashMap, FxHashSet};
 
 use super::advanced_chunks_options::AdvancedChunksOptions;
+use super::assets_inline_limit::AssetsInlineLimit;
 use super::checks_options::ChecksOptions;
 use super::comments::Comments;
 use super::experimental_options::ExperimentalOptions;
 use super::jsx::Jsx;
+use super::log_rule::LogRule;
+use super::mangle_props_options::ManglePropsOptions;
 use super::output_option::ChunkFilenamesOutputOption;
+use super::runtime_helpers::RuntimeHelpers;
 use super::target::ESTarget;
 use super::treeshake::TreeshakeOptions;
 use super::watch_option::WatchOption;
 use super::{
-  filename_template::FilenameTemplate, is_external::IsExternal, output_exports::OutputExports,
-  output_format::OutputFormat, output_option::AddonOutputOption, platform::Platform,
-  source_map_type::SourceMapType, sourcemap_ignore_list::SourceMapIgnoreList,
+  filename_template::FilenameTemplate,
+  is_external::IsExternal,
+  output_exports::OutputExports,
+  output_format::OutputFormat,
+  output_option::AddonOutputOption,
+  platform::Platform,
+  source_map_type::SourceMapType,
+  sourcemap_ignore_list::SourceMapIgnoreList,
   sourcemap_path_transform::SourceMapPathTransform,
+  sourcemap_quality::{SourceMapQuality, SourceMapQualityRule},
 };
 use crate::{
   EsModuleFlag, GlobalsOutputOption, HashCharacters, InjectImport, InputItem, ModuleType,
@@ -46,6 +56,7 @@ pub struct NormalizedBundlerOptions {
   pub entry_filenames: ChunkFilenamesOutputOption,
   pub chunk_filenames: ChunkFilenamesOutputOption,
   pub asset_filenames: FilenameTemplate,
+  pub assets_inline_limit: AssetsInlineLimit,
   // The user specified output directory config
   pub dir: Option<String>,
   // The rolldown resolved output directory from `dir` or `file`.
@@ -64,8 +75,16 @@ pub struct NormalizedBundlerOptions {
   pub sourcemap_ignore_list: Option<SourceMapIgnoreList>,
   pub sourcemap_path_transform: Option<SourceMapPathTransform>,
   pub sourcemap_debug_ids: bool,
+  pub sourcemap_debug_ids_global: Option<String>,
+  pub sourcemap_debug_ids_manifest: Option<String>,
+  pub sourcemap_index_map: bool,
+  pub sourcemap_quality: Vec<SourceMapQualityRule>,
   pub experimental: ExperimentalOptions,
-  pub minify: bool,
+  /// `None` when minification is disabled.
+  pub minify: Option<rolldown_ecmascript::MinifyOptions>,
+  /// Absolute path of the mangle name cache. Only set when top-level bindings are mangled.
+  pub mangle_name_cache: Option<PathBuf>,
+  pub mangle_props: Option<ManglePropsOptions>,
   pub extend: bool,
   pub define: Vec<(/* Target to be replaced */ String, /* Replacement */ String)>,
   pub keep_names: bool,
@@ -75,13 +94,17 @@ pub struct NormalizedBundlerOptions {
   pub inline_dynamic_imports: bool,
   pub advanced_chunks: Option<AdvancedChunksOptions>,
   pub checks: ChecksOptions,
+  pub log_rules: Vec<LogRule>,
   pub profiler_names: bool,
   pub jsx: Jsx,
   pub watch: WatchOption,
   pub comments: Comments,
+  pub legal_comments_file: Option<String>,
+  pub license_report: Option<String>,
   pub drop_labels: FxHashSet<String>,
   pub target: ESTarget,
   pub polyfill_require: bool,
+  pub runtime_helpers: RuntimeHelpers,
 }
 
 pub type SharedNormalizedBundlerOptions = Arc<NormalizedBundlerOptions>;
@@ -91,6 +114,14 @@ impl NormalizedBundlerOptions {
     self.sourcemap.is_some()
   }
 
+  pub fn sourcemap_quality_of(&self, module_id: &str) -> SourceMapQuality {
+    self
+      .sourcemap_quality
+      .iter()
+      .find(|rule| rule.test.matches(module_id))
+      .map_or(SourceMapQuality::Full, |rule| rule.quality)
+  }
+
   pub fn is_esm_format_with_node_platform(&self) -> bool {
     matches!(self.format, OutputFormat::Esm) && matches!(self.platform, Platform::Node)
   }
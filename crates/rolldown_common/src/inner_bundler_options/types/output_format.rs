@@ -13,6 +13,8 @@ use std::fmt::Display;
 pub enum OutputFormat {
   Esm,
   Cjs,
+  /// Every module is registered with `__defineModule` and keeps its own scope. The helpers are
+  /// provided by the host, e.g. a dev server.
   App,
   Iife,
   Umd,
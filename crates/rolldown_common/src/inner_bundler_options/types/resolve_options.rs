@@ -15,6 +15,10 @@ pub struct ResolveOptions {
   pub alias: Option<Vec<(String, Vec<String>)>>,
   pub alias_fields: Option<Vec<Vec<String>>>,
   pub condition_names: Option<Vec<String>>,
+  /// Packages that always resolve to the copy found from `cwd`, no matter which module imports
+  /// them. It prevents bundling several copies of a package that is installed in nested
+  /// `node_modules`.
+  pub dedupe: Option<Vec<String>>,
   pub exports_fields: Option<Vec<Vec<String>>>,
   pub extensions: Option<Vec<String>>,
   pub extension_alias: Option<Vec<(String, Vec<String>)>>,
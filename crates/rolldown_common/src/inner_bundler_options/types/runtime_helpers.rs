@@ -0,0 +1,30 @@
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::Deserialize;
+
+/// Controls where the helpers of the runtime module, such as `__toESM`, `__commonJS` and `__export`,
+/// come from.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub enum RuntimeHelpers {
+  /// Inline the used helpers into the output.
+  #[default]
+  Inline,
+  /// Import the used helpers from the given specifier. Something needs to provide it at runtime,
+  /// e.g. `External("@acme/rolldown-runtime".into())`.
+  External(String),
+  /// Emit all helpers as a separate asset with the given file name. The output imports the used
+  /// helpers from it, so independently built bundles that share the asset share one copy.
+  Shared(String),
+}
+
+impl RuntimeHelpers {
+  pub fn is_inline(&self) -> bool {
+    matches!(self, Self::Inline)
+  }
+}
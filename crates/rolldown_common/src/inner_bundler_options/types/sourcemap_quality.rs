@@ -0,0 +1,48 @@
+use rolldown_utils::js_regex::HybridRegex;
+#[cfg(feature = "deserialize_bundler_options")]
+use schemars::JsonSchema;
+#[cfg(feature = "deserialize_bundler_options")]
+use serde::{Deserialize, Deserializer};
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase")
+)]
+pub enum SourceMapQuality {
+  /// Mappings with column precision.
+  #[default]
+  Full,
+  /// One mapping per generated line, pointing at the start of the original line.
+  Cheap,
+  /// The module is left out of the source map.
+  Excluded,
+}
+
+#[derive(Debug, Clone)]
+#[cfg_attr(
+  feature = "deserialize_bundler_options",
+  derive(Deserialize, JsonSchema),
+  serde(rename_all = "camelCase", deny_unknown_fields)
+)]
+pub struct SourceMapQualityRule {
+  /// Matched against the module id, e.g. `node_modules`.
+  #[cfg_attr(
+    feature = "deserialize_bundler_options",
+    serde(deserialize_with = "deserialize_test"),
+    schemars(with = "String")
+  )]
+  pub test: HybridRegex,
+  pub quality: SourceMapQuality,
+}
+
+#[cfg(feature = "deserialize_bundler_options")]
+fn deserialize_test<'de, D>(deserializer: D) -> Result<HybridRegex, D::Error>
+where
+  D: Deserializer<'de>,
+{
+  let pattern = String::deserialize(deserializer)?;
+  HybridRegex::new(&pattern)
+    .map_err(|e| serde::de::Error::custom(format!("failed to deserialize {e:?} to HybridRegex")))
+}
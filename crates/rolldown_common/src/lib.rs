@@ -16,7 +16,8 @@ pub mod bundler_options {
   pub use crate::inner_bundler_options::{
     types::{
       advanced_chunks_options::{AdvancedChunksOptions, MatchGroup},
-      checks_options::ChecksOptions,
+      assets_inline_limit::{AssetsInlineLimit, AssetsInlineLimitFn},
+      checks_options::{BoundaryRule, ChecksOptions},
       comments::Comments,
       es_module_flag::EsModuleFlag,
       experimental_options::ExperimentalOptions,
@@ -26,6 +27,11 @@ pub mod bundler_options {
       input_item::InputItem,
       is_external::IsExternal,
       jsx::Jsx,
+      log_rule::{LogAction, LogRule},
+      mangle_props_options::ManglePropsOptions,
+      minify_options::{
+        MinifyCompressOptions, MinifyMangleOptions, MinifyOptions, RawMinifyOptions,
+      },
       module_type::ModuleType,
       normalized_bundler_options::{NormalizedBundlerOptions, SharedNormalizedBundlerOptions},
       output_exports::OutputExports,
@@ -35,9 +41,11 @@ pub mod bundler_options {
       },
       platform::Platform,
       resolve_options::ResolveOptions,
+      runtime_helpers::RuntimeHelpers,
       source_map_type::SourceMapType,
       sourcemap_ignore_list::SourceMapIgnoreList,
       sourcemap_path_transform::SourceMapPathTransform,
+      sourcemap_quality::{SourceMapQuality, SourceMapQualityRule},
       target::ESTarget,
       treeshake::{InnerOptions, ModuleSideEffects, ModuleSideEffectsRule, TreeshakeOptions},
       watch_option::{NotifyOption, WatchOption},
@@ -85,9 +93,10 @@ pub use crate::{
   types::asset_meta::InstantiationKind,
   types::ast_scopes::AstScopes,
   types::bundler_file_system::BundlerFileSystem,
-  types::cache::Cache,
+  types::cache::{Cache, CachedModuleSource},
   types::chunk_idx::ChunkIdx,
   types::chunk_kind::ChunkKind,
+  types::custom_field::CustomField,
   types::ecma_ast_idx::EcmaAstIdx,
   types::entry_point::{EntryPoint, EntryPointKind},
   types::exports_kind::ExportsKind,
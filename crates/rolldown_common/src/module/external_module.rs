@@ -1,5 +1,5 @@
 use crate::side_effects::DeterminedSideEffects;
-use crate::{ImportRecordIdx, ModuleIdx, ResolvedImportRecord, SymbolRef};
+use crate::{ImportRecordIdx, ModuleIdx, ModuleType, ResolvedImportRecord, SymbolRef};
 use arcstr::ArcStr;
 use oxc_index::IndexVec;
 
@@ -14,6 +14,8 @@ pub struct ExternalModule {
   pub name: ArcStr,
   pub import_records: IndexVec<ImportRecordIdx, ResolvedImportRecord>,
   pub side_effects: DeterminedSideEffects,
+  /// `json` in `import data from 'external' with { type: 'json' }`, kept on the import in esm output.
+  pub asserted_module_type: Option<ModuleType>,
 }
 
 impl ExternalModule {
@@ -22,6 +24,7 @@ impl ExternalModule {
     module_id: ArcStr,
     side_effects: DeterminedSideEffects,
     namespace_ref: SymbolRef,
+    asserted_module_type: Option<ModuleType>,
   ) -> Self {
     Self {
       idx,
@@ -30,6 +33,7 @@ impl ExternalModule {
       name: module_id,
       import_records: IndexVec::default(),
       side_effects,
+      asserted_module_type,
     }
   }
 }
@@ -5,9 +5,9 @@ use crate::css::css_view::CssView;
 use crate::ecmascript::ecma_view::EsmNamespaceInCjs;
 use crate::types::module_render_output::ModuleRenderOutput;
 use crate::{
-  AssetView, Comments, DebugStmtInfoForTreeShaking, ExportsKind, ImportRecordIdx, ImportRecordMeta,
-  ModuleId, ModuleIdx, ModuleInfo, NormalizedBundlerOptions, RawImportRecord, RuntimeModuleBrief,
-  StmtInfo, SymbolRef, SymbolRefDb,
+  AssetView, Comments, CustomField, DebugStmtInfoForTreeShaking, ExportsKind, ImportRecordIdx,
+  ImportRecordMeta, ModuleId, ModuleIdx, ModuleInfo, NormalizedBundlerOptions, PackageJson,
+  RawImportRecord, RuntimeModuleBrief, SourceMapQuality, StmtInfo, SymbolRef, SymbolRefDb,
 };
 use crate::{EcmaAstIdx, EcmaView, IndexModules, Interop, Module, ModuleType};
 use std::ops::{Deref, DerefMut};
@@ -38,6 +38,10 @@ pub struct NormalModule {
   pub ecma_view: EcmaView,
   pub css_view: Option<CssView>,
   pub asset_view: Option<AssetView>,
+  /// The closest `package.json` of the module, if it was resolved by the builtin resolver.
+  pub package_json: Option<Arc<PackageJson>>,
+  /// Custom data attached to the module by `load` and `transform` hooks.
+  pub custom_meta: Option<Arc<CustomField>>,
 }
 
 impl NormalModule {
@@ -115,6 +119,7 @@ impl NormalModule {
         }
         exports
       },
+      meta: self.custom_meta.clone(),
     }
   }
 
@@ -220,12 +225,16 @@ impl NormalModule {
   ) -> Option<ModuleRenderOutput> {
     match args {
       ModuleRenderArgs::Ecma { ast } => {
-        let enable_sourcemap = options.sourcemap.is_some() && !self.is_virtual();
+        let enable_sourcemap = options.sourcemap.is_some()
+          && !self.is_virtual()
+          && options.sourcemap_quality_of(&self.id) != SourceMapQuality::Excluded;
 
         let comments = match options.comments {
           Comments::None => Either::Left(false),
           Comments::Preserve => Either::Left(true),
           Comments::PreserveLegal => Either::Right(LegalComment::Inline),
+          // Legal comments are collected from the ast when the chunk is rendered.
+          Comments::External => Either::Right(LegalComment::External),
         };
 
         // Because oxc codegen sourcemap is last of sourcemap chain,
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use rolldown_sourcemap::SourceMap;
+use rolldown_sourcemap::{IndexSourceMap, SourceMap};
 
 use crate::{ChunkIdx, InstantiationKind, PreliminaryFilename, StrOrBytes};
 
@@ -10,6 +10,8 @@ pub struct Asset {
   pub origin_chunk: ChunkIdx,
   pub content: StrOrBytes,
   pub map: Option<SourceMap>,
+  /// Set instead of `map` if `sourcemap_index_map` is enabled.
+  pub index_map: Option<IndexSourceMap>,
   pub meta: InstantiationKind,
   pub augment_chunk_hash: Option<String>,
   pub file_dir: PathBuf,
@@ -1,14 +1,30 @@
+use std::sync::Arc;
+
 use arcstr::ArcStr;
 use dashmap::mapref::one::Ref;
 use rolldown_ecmascript::EcmaAst;
+use rolldown_sourcemap::SourceMap;
 use rolldown_utils::dashmap::FxDashMap;
 
-use crate::{ModuleType, StrOrBytes};
+use crate::{side_effects::HookSideEffects, CustomField, ModuleType, StrOrBytes};
+
+/// The result of running `load` and `transform` hooks on a module.
+#[derive(Debug, Clone)]
+pub struct CachedModuleSource {
+  pub source: StrOrBytes,
+  pub module_type: ModuleType,
+  pub sourcemap_chain: Vec<SourceMap>,
+  pub hook_side_effects: Option<HookSideEffects>,
+  pub meta: Option<Arc<CustomField>>,
+  /// Files added by `addWatchFile` while transforming the module. The entry is stale once any of
+  /// them changes.
+  pub watch_files: Vec<ArcStr>,
+}
 
 #[derive(Default)]
 pub struct Cache {
   ecma_ast: FxDashMap<ArcStr, EcmaAst>,
-  raw_source_and_module_type: FxDashMap<ArcStr, (StrOrBytes, ModuleType)>,
+  module_source: FxDashMap<ArcStr, CachedModuleSource>,
 }
 
 impl Cache {
@@ -16,9 +32,17 @@ impl Cache {
     self.ecma_ast.get(key)
   }
 
+  /// Drops the entries of `key` and of every module whose transform result depends on `key`.
   pub fn invalidate(&self, key: &str) {
     self.ecma_ast.remove(key);
-    self.raw_source_and_module_type.remove(key);
+    self.module_source.remove(key);
+    self.module_source.retain(|id, cached| {
+      let is_stale = cached.watch_files.iter().any(|file| file == key);
+      if is_stale {
+        self.ecma_ast.remove(id);
+      }
+      !is_stale
+    });
   }
 
   pub fn get_source(&self, key: &str) -> Option<ArcStr> {
@@ -33,14 +57,11 @@ impl Cache {
     self.ecma_ast.insert(key, value);
   }
 
-  pub fn get_raw_source_and_module_type(
-    &self,
-    key: &str,
-  ) -> Option<Ref<'_, ArcStr, (StrOrBytes, ModuleType)>> {
-    self.raw_source_and_module_type.get(key)
+  pub fn get_module_source(&self, key: &str) -> Option<Ref<'_, ArcStr, CachedModuleSource>> {
+    self.module_source.get(key)
   }
 
-  pub fn insert_raw_source_and_module_type(&self, key: ArcStr, value: (StrOrBytes, ModuleType)) {
-    self.raw_source_and_module_type.insert(key, value);
+  pub fn insert_module_source(&self, key: ArcStr, value: CachedModuleSource) {
+    self.module_source.insert(key, value);
   }
 }
@@ -21,6 +21,7 @@ pub struct ImportRecordStateInit {
 
 #[derive(Debug)]
 pub struct ImportRecordStateResolved {
+  pub span: Span,
   pub resolved_module: ModuleIdx,
 }
 
@@ -102,7 +103,7 @@ impl RawImportRecord {
 
   pub fn into_resolved(self, resolved_module: ModuleIdx) -> ResolvedImportRecord {
     ResolvedImportRecord {
-      state: ImportRecordStateResolved { resolved_module },
+      state: ImportRecordStateResolved { span: self.state.span, resolved_module },
       module_request: self.module_request,
       kind: self.kind,
       namespace_ref: self.namespace_ref,
@@ -1,6 +1,6 @@
 use std::path::PathBuf;
 
-use rolldown_sourcemap::SourceMap;
+use rolldown_sourcemap::{IndexSourceMap, SourceMap};
 
 use crate::{Asset, ChunkIdx, InstantiationKind, PreliminaryFilename, StrOrBytes};
 
@@ -11,6 +11,8 @@ pub struct InstantiatedChunk {
   pub origin_chunk: ChunkIdx,
   pub content: StrOrBytes,
   pub map: Option<SourceMap>,
+  /// Set instead of `map` if `sourcemap_index_map` is enabled.
+  pub index_map: Option<IndexSourceMap>,
   pub kind: InstantiationKind,
   pub augment_chunk_hash: Option<String>,
   pub file_dir: PathBuf,
@@ -23,6 +25,7 @@ impl InstantiatedChunk {
       origin_chunk: self.origin_chunk,
       content: self.content,
       map: self.map,
+      index_map: self.index_map,
       meta: self.kind,
       augment_chunk_hash: self.augment_chunk_hash,
       file_dir: self.file_dir,
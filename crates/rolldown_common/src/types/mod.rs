@@ -6,6 +6,7 @@ pub mod bundler_file_system;
 pub mod cache;
 pub mod chunk_idx;
 pub mod chunk_kind;
+pub mod custom_field;
 pub mod ecma_ast_idx;
 pub mod entry_point;
 pub mod exports_kind;
@@ -1,7 +1,9 @@
+use std::sync::Arc;
+
 use arcstr::ArcStr;
 use rolldown_utils::indexmap::FxIndexSet;
 
-use crate::ModuleId;
+use crate::{CustomField, ModuleId};
 
 #[derive(Debug)]
 pub struct ModuleInfo {
@@ -13,4 +15,6 @@ pub struct ModuleInfo {
   pub imported_ids: FxIndexSet<ModuleId>,
   pub dynamically_imported_ids: FxIndexSet<ModuleId>,
   pub exports: Vec<ArcStr>,
+  /// Custom data attached to the module by `load` and `transform` hooks.
+  pub meta: Option<Arc<CustomField>>,
 }
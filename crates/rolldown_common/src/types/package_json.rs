@@ -6,13 +6,37 @@ use crate::side_effects::{glob_match_with_normalized_pattern, SideEffects};
 pub struct PackageJson {
   /// Path to `package.json`. Contains the `package.json` filename.
   pub path: PathBuf,
+  pub name: Option<String>,
+  pub version: Option<String>,
+  /// The `license` field, or the `type` of a legacy `{ "type": "MIT", "url": "..." }` object.
+  pub license: Option<String>,
   pub r#type: Option<String>,
   pub side_effects: Option<SideEffects>,
 }
 
 impl PackageJson {
   pub fn new(path: PathBuf) -> Self {
-    Self { path, r#type: None, side_effects: None }
+    Self { path, name: None, version: None, license: None, r#type: None, side_effects: None }
+  }
+
+  #[must_use]
+  pub fn with_name(mut self, value: Option<&String>) -> Self {
+    self.name = value.cloned();
+    self
+  }
+
+  #[must_use]
+  pub fn with_version(mut self, value: Option<&serde_json::Value>) -> Self {
+    self.version = value.and_then(|v| v.as_str()).map(ToString::to_string);
+    self
+  }
+
+  #[must_use]
+  pub fn with_license(mut self, value: Option<&serde_json::Value>) -> Self {
+    self.license = value
+      .and_then(|v| v.as_str().or_else(|| v.get("type").and_then(|v| v.as_str())))
+      .map(ToString::to_string);
+    self
   }
 
   #[must_use]
@@ -4,18 +4,22 @@ use arcstr::ArcStr;
 use either::Either;
 use oxc::{
   allocator::Allocator,
-  ast::AstBuilder,
+  ast::{AstBuilder, VisitMut},
   codegen::{CodeGenerator, Codegen, CodegenOptions, CodegenReturn, LegalComment},
-  minifier::{Minifier, MinifierOptions},
+  minifier::{CompressOptions, Compressor},
   parser::{ParseOptions, Parser},
   span::{SourceType, SPAN},
 };
 use oxc_sourcemap::SourceMap;
 use rolldown_error::{BuildDiagnostic, BuildResult, Severity};
+use rustc_hash::FxHashMap;
 
-use crate::ecma_ast::{
-  program_cell::{ProgramCell, ProgramCellDependent, ProgramCellOwner},
-  EcmaAst,
+use crate::{
+  ecma_ast::{
+    program_cell::{ProgramCell, ProgramCellDependent, ProgramCellOwner},
+    EcmaAst,
+  },
+  minifier::{collect_legal_comments, mangle_program, MinifyOptions, PureFuncsRemover},
 };
 pub struct EcmaCompiler;
 
@@ -107,23 +111,54 @@ impl EcmaCompiler {
 
   pub fn minify(
     source_text: &str,
+    options: &MinifyOptions,
+    top_level_names: Option<&mut FxHashMap<String, String>>,
     enable_sourcemap: bool,
     filename: &str,
   ) -> (String, Option<SourceMap>) {
     let allocator = Allocator::default();
     let program = Parser::new(&allocator, source_text, SourceType::default()).parse().program;
     let program = allocator.alloc(program);
-    let options = MinifierOptions::default();
-    let ret = Minifier::new(options).build(&allocator, program);
+    let legal_comments =
+      if options.legal_comments { collect_legal_comments(program) } else { vec![] };
+    if let Some(compress) = &options.compress {
+      if !compress.pure_funcs.is_empty() {
+        PureFuncsRemover::new(&allocator, &compress.pure_funcs).visit_program(program);
+      }
+      let compress_options = CompressOptions {
+        drop_console: compress.drop_console,
+        drop_debugger: compress.drop_debugger,
+        ..CompressOptions::default()
+      };
+      for _ in 0..compress.passes.max(1) {
+        Compressor::new(&allocator, compress_options).build(program);
+      }
+    }
+    let mangler = options
+      .mangle
+      .as_ref()
+      .and_then(|mangle| mangle_program(&allocator, program, mangle, top_level_names));
     let ret = Codegen::new()
       .with_options(CodegenOptions {
         source_map_path: enable_sourcemap.then(|| PathBuf::from(filename)),
         minify: true,
         ..CodegenOptions::default()
       })
-      .with_mangler(ret.mangler)
+      .with_mangler(mangler)
       .build(program);
-    (ret.code, ret.map)
+    let mut code = ret.code;
+    // The codegen drops every comment in minify mode, so legal comments are appended by hand. Putting
+    // them at the end keeps the generated mappings valid.
+    if !legal_comments.is_empty() {
+      if !code.is_empty() && !code.ends_with('\n') {
+        code.push('\n');
+      }
+      for comment in legal_comments {
+        code.push_str(&comment);
+        code.push('\n');
+      }
+    }
+    (code, ret.map)
   }
 }
 
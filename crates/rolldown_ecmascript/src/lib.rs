@@ -1,7 +1,9 @@
 mod ecma_ast;
 mod ecma_compiler;
+mod minifier;
 
 pub use crate::{
   ecma_ast::{program_cell::WithMutFields, EcmaAst, ToSourceString},
   ecma_compiler::{EcmaCompiler, PrintOptions},
+  minifier::{collect_legal_comments, CompressOptions, MangleOptions, MinifyOptions},
 };
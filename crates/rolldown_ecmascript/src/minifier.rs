@@ -0,0 +1,348 @@
+use oxc::{
+  allocator::Allocator,
+  ast::{
+    ast::{
+      BindingIdentifier, BindingPatternKind, Expression, IdentifierReference, Program, Statement,
+      StaticMemberExpression, VariableDeclarator,
+    },
+    visit::{walk, walk_mut},
+    AstBuilder, Visit, VisitMut,
+  },
+  mangler::{MangleOptions as OxcMangleOptions, Mangler},
+  semantic::{ReferenceId, SemanticBuilder, SymbolId},
+  span::GetSpan,
+  syntax::keyword::is_reserved_keyword,
+};
+use rolldown_utils::ecmascript::short_identifier_name;
+use rustc_hash::{FxHashMap, FxHashSet};
+
+/// Normalized options for [`crate::EcmaCompiler::minify`].
+///
+/// Passing `None` for both `compress` and `mangle` only strips whitespace.
+#[derive(Debug, Clone)]
+pub struct MinifyOptions {
+  pub compress: Option<CompressOptions>,
+  pub mangle: Option<MangleOptions>,
+  /// Move legal comments (`/*! ... */`, `@license`, `@preserve`) to the end of the output.
+  pub legal_comments: bool,
+}
+
+impl Default for MinifyOptions {
+  fn default() -> Self {
+    Self {
+      compress: Some(CompressOptions::default()),
+      mangle: Some(MangleOptions::default()),
+      legal_comments: false,
+    }
+  }
+}
+
+#[derive(Debug, Clone)]
+pub struct CompressOptions {
+  pub drop_console: bool,
+  pub drop_debugger: bool,
+  /// Calls to these functions are removed when their result is unused. Dotted paths such as
+  /// `console.info` match static member calls.
+  pub pure_funcs: Vec<String>,
+  /// How many times the compressor runs over the program.
+  pub passes: u32,
+}
+
+impl Default for CompressOptions {
+  fn default() -> Self {
+    Self { drop_console: false, drop_debugger: true, pure_funcs: vec![], passes: 1 }
+  }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct MangleOptions {
+  pub top_level: bool,
+  pub keep_fnames: bool,
+  pub keep_classnames: bool,
+  /// Bindings with these names are not renamed, and no other binding is renamed to them.
+  pub reserved: Vec<String>,
+}
+
+impl MangleOptions {
+  fn keeps_any_name(&self) -> bool {
+    self.keep_fnames || self.keep_classnames || !self.reserved.is_empty()
+  }
+}
+
+/// Replaces expression statements that only call one of `pure_funcs` with an empty statement.
+/// The compressor removes the empty statements afterwards.
+pub struct PureFuncsRemover<'me, 'a> {
+  ast: AstBuilder<'a>,
+  pure_funcs: &'me [String],
+}
+
+impl<'me, 'a> PureFuncsRemover<'me, 'a> {
+  pub fn new(allocator: &'a Allocator, pure_funcs: &'me [String]) -> Self {
+    Self { ast: AstBuilder::new(allocator), pure_funcs }
+  }
+
+  fn is_pure_call(&self, stmt: &Statement<'a>) -> bool {
+    let Statement::ExpressionStatement(expr_stmt) = stmt else {
+      return false;
+    };
+    let Expression::CallExpression(call) = expr_stmt.expression.without_parentheses() else {
+      return false;
+    };
+    let mut path = String::new();
+    if !write_callee_path(&call.callee, &mut path) {
+      return false;
+    }
+    self.pure_funcs.iter().any(|name| *name == path)
+  }
+}
+
+impl<'a> VisitMut<'a> for PureFuncsRemover<'_, 'a> {
+  fn visit_statement(&mut self, it: &mut Statement<'a>) {
+    if self.is_pure_call(it) {
+      *it = self.ast.statement_empty(it.span());
+      return;
+    }
+    walk_mut::walk_statement(self, it);
+  }
+}
+
+fn write_callee_path(expr: &Expression, out: &mut String) -> bool {
+  match expr {
+    Expression::Identifier(ident) => {
+      out.push_str(&ident.name);
+      true
+    }
+    Expression::StaticMemberExpression(member) => write_member_path(member, out),
+    _ => false,
+  }
+}
+
+fn write_member_path(member: &StaticMemberExpression, out: &mut String) -> bool {
+  if !write_callee_path(&member.object, out) {
+    return false;
+  }
+  out.push('.');
+  out.push_str(&member.property.name);
+  true
+}
+
+/// Mangles `program`.
+///
+/// When no name needs to be kept, the [`Mangler`] is returned and applied by the codegen. Otherwise
+/// the final names are written into the AST directly and `None` is returned. Kept names are never
+/// handed out to other bindings: a binding that the mangler named after a kept name gets a fresh
+/// name instead.
+///
+/// `top_level_names` maps top-level names before mangling to names that should be reused, e.g. from
+/// a previous build. Unusable entries are ignored. On return it holds the final name of every
+/// top-level binding.
+pub fn mangle_program<'a>(
+  allocator: &'a Allocator,
+  program: &mut Program<'a>,
+  options: &MangleOptions,
+  top_level_names: Option<&mut FxHashMap<String, String>>,
+) -> Option<Mangler> {
+  let oxc_options = OxcMangleOptions { top_level: options.top_level, debug: false };
+  let (symbols, scopes) =
+    SemanticBuilder::new().build(program).semantic.into_symbol_table_and_scope_tree();
+
+  if !options.keeps_any_name() && top_level_names.is_none() {
+    return Some(
+      Mangler::new()
+        .with_options(oxc_options)
+        .build_with_symbols_and_scopes(symbols, &scopes, program),
+    );
+  }
+
+  let mut inferred_names = InferredNameCollector::default();
+  inferred_names.visit_program(program);
+
+  let root_scope_id = scopes.root_scope_id();
+  let root_bindings = scopes.get_bindings(root_scope_id);
+  let symbol_ids = symbols.symbol_ids().collect::<Vec<_>>();
+  let original_names =
+    symbol_ids.iter().map(|&symbol_id| symbols.get_name(symbol_id).to_string()).collect::<Vec<_>>();
+  let reserved = options.reserved.iter().map(String::as_str).collect::<FxHashSet<_>>();
+  let mut used_names =
+    scopes.root_unresolved_references().keys().map(ToString::to_string).collect::<FxHashSet<_>>();
+
+  // Maps symbols that are not renamed by the mangler to their final names.
+  let mut kept = FxHashMap::default();
+  let mut protected_names = reserved.iter().map(ToString::to_string).collect::<FxHashSet<_>>();
+  for &symbol_id in &symbol_ids {
+    let flags = symbols.get_flags(symbol_id);
+    let name = symbols.get_name(symbol_id);
+    if (options.keep_fnames
+      && (flags.is_function() || inferred_names.functions.contains(&symbol_id)))
+      || (options.keep_classnames
+        && (flags.is_class() || inferred_names.classes.contains(&symbol_id)))
+      || reserved.contains(name)
+    {
+      kept.insert(symbol_id, name.to_string());
+      protected_names.insert(name.to_string());
+    }
+  }
+  if let Some(previous_names) = top_level_names.as_deref() {
+    for &symbol_id in root_bindings.values() {
+      if kept.contains_key(&symbol_id) {
+        continue;
+      }
+      let Some(previous_name) = previous_names.get(symbols.get_name(symbol_id)) else {
+        continue;
+      };
+      let is_usable = !is_reserved_keyword(previous_name)
+        && !used_names.contains(previous_name)
+        && !protected_names.contains(previous_name)
+        && !root_bindings.contains_key(previous_name.as_str());
+      if is_usable {
+        kept.insert(symbol_id, previous_name.clone());
+        protected_names.insert(previous_name.clone());
+      }
+    }
+  }
+  let root_symbols = root_bindings.values().copied().collect::<FxHashSet<_>>();
+  let reference_symbols = symbols
+    .references
+    .iter_enumerated()
+    .filter_map(|(reference_id, reference)| Some((reference_id, reference.symbol_id()?)))
+    .collect::<FxHashMap<_, _>>();
+
+  let mangler = Mangler::new()
+    .with_options(oxc_options)
+    .build_with_symbols_and_scopes(symbols, &scopes, program);
+
+  let mut names: FxHashMap<SymbolId, String> = symbol_ids
+    .iter()
+    .map(|&symbol_id| {
+      let name = kept.get(&symbol_id).cloned();
+      (symbol_id, name.unwrap_or_else(|| mangler.get_symbol_name(symbol_id).to_string()))
+    })
+    .collect();
+  used_names.extend(names.values().cloned());
+
+  let conflicting = symbol_ids
+    .iter()
+    .zip(&original_names)
+    .filter(|(symbol_id, original_name)| {
+      let name = &names[*symbol_id];
+      // Top-level bindings the mangler left alone keep their original names, just like in the input.
+      let keeps_name = kept.contains_key(*symbol_id)
+        || (name == *original_name && root_symbols.contains(*symbol_id));
+      !keeps_name && protected_names.contains(name)
+    })
+    .map(|(symbol_id, _)| *symbol_id)
+    .collect::<Vec<_>>();
+  assign_fresh_names(&mut names, &conflicting, &used_names);
+
+  if let Some(top_level_names) = top_level_names {
+    top_level_names.clear();
+    for (symbol_id, original_name) in symbol_ids.iter().zip(original_names) {
+      if root_symbols.contains(symbol_id) {
+        top_level_names.insert(original_name, names[symbol_id].clone());
+      }
+    }
+  }
+
+  ApplyNames {
+    ast: AstBuilder::new(allocator),
+    names: &names,
+    reference_symbols: &reference_symbols,
+  }
+  .visit_program(program);
+  None
+}
+
+/// Renames `conflicting` symbols to names that are not used anywhere in the program.
+fn assign_fresh_names(
+  names: &mut FxHashMap<SymbolId, String>,
+  conflicting: &[SymbolId],
+  used_names: &FxHashSet<String>,
+) {
+  // Symbols sharing a mangled name never clash with each other, so they can share the fresh name too.
+  let mut fresh_names: FxHashMap<String, String> = FxHashMap::default();
+  let mut next_fresh = 0usize;
+  for symbol_id in conflicting {
+    let fresh = fresh_names
+      .entry(names[symbol_id].clone())
+      .or_insert_with(|| loop {
+        let candidate = short_identifier_name(next_fresh);
+        next_fresh += 1;
+        if !used_names.contains(&candidate) && !is_reserved_keyword(&candidate) {
+          break candidate;
+        }
+      })
+      .clone();
+    names.insert(*symbol_id, fresh);
+  }
+}
+
+/// Collects `const x = class {}` and `const f = () => {}` bindings, which give their name to the
+/// anonymous class or function.
+#[derive(Default)]
+struct InferredNameCollector {
+  functions: FxHashSet<SymbolId>,
+  classes: FxHashSet<SymbolId>,
+}
+
+impl<'a> Visit<'a> for InferredNameCollector {
+  fn visit_variable_declarator(&mut self, it: &VariableDeclarator<'a>) {
+    if let (BindingPatternKind::BindingIdentifier(ident), Some(init)) = (&it.id.kind, &it.init) {
+      if let Some(symbol_id) = ident.symbol_id.get() {
+        match init.without_parentheses() {
+          Expression::ClassExpression(class) if class.id.is_none() => {
+            self.classes.insert(symbol_id);
+          }
+          Expression::FunctionExpression(func) if func.id.is_none() => {
+            self.functions.insert(symbol_id);
+          }
+          Expression::ArrowFunctionExpression(_) => {
+            self.functions.insert(symbol_id);
+          }
+          _ => {}
+        }
+      }
+    }
+    walk::walk_variable_declarator(self, it);
+  }
+}
+
+struct ApplyNames<'me, 'a> {
+  ast: AstBuilder<'a>,
+  names: &'me FxHashMap<SymbolId, String>,
+  reference_symbols: &'me FxHashMap<ReferenceId, SymbolId>,
+}
+
+impl<'a> VisitMut<'a> for ApplyNames<'_, 'a> {
+  fn visit_binding_identifier(&mut self, it: &mut BindingIdentifier<'a>) {
+    if let Some(name) = it.symbol_id.get().and_then(|symbol_id| self.names.get(&symbol_id)) {
+      if it.name != name.as_str() {
+        it.name = self.ast.atom(name);
+      }
+    }
+  }
+
+  fn visit_identifier_reference(&mut self, it: &mut IdentifierReference<'a>) {
+    let symbol_id = it
+      .reference_id
+      .get()
+      .and_then(|reference_id| self.reference_symbols.get(&reference_id).copied());
+    if let Some(name) = symbol_id.and_then(|symbol_id| self.names.get(&symbol_id)) {
+      if it.name != name.as_str() {
+        it.name = self.ast.atom(name);
+      }
+    }
+  }
+}
+
+/// Collects the distinct legal comments of `program` in source order.
+pub fn collect_legal_comments(program: &Program) -> Vec<String> {
+  let mut seen = FxHashSet::default();
+  program
+    .comments
+    .iter()
+    .filter(|comment| comment.is_legal(program.source_text))
+    .map(|comment| &program.source_text[comment.span.start as usize..comment.span.end as usize])
+    .filter(|text| seen.insert(*text))
+    .map(ToString::to_string)
+    .collect()
+}
@@ -6,9 +6,11 @@ use oxc::{
       ImportOrExportKind, NumberBase, ObjectPropertyKind, PropertyKind, Statement,
       VariableDeclarationKind,
     },
-    AstBuilder, NONE,
+    AstBuilder, VisitMut, NONE,
   },
-  span::{Atom, CompactStr, Span, SPAN},
+  diagnostics::OxcDiagnostic,
+  parser::Parser,
+  span::{Atom, CompactStr, SourceType, Span, SPAN},
 };
 use rolldown_common::{EcmaModuleAstUsage, Interop};
 
@@ -486,6 +488,92 @@ impl<'ast> AstSnippet<'ast> {
     )
   }
 
+  /// ```js
+  /// __defineModule("foo.js", (exports, module, require) => { ... });
+  /// // or with `profiler_names`
+  /// __defineModule({ "foo.js"(exports, module, require) { ... } });
+  /// ```
+  pub fn app_module_wrapper_stmt(
+    &self,
+    directives: allocator::Vec<'ast, ast::Directive<'ast>>,
+    statements: allocator::Vec<'ast, Statement<'ast>>,
+    profiler_names: bool,
+    stable_id: &str,
+  ) -> ast::Statement<'ast> {
+    // (exports, module, require) => { ... }
+    let mut params = self.builder.formal_parameters(
+      SPAN,
+      ast::FormalParameterKind::Signature,
+      self.builder.vec_with_capacity(3),
+      NONE,
+    );
+    for name in ["exports", "module", "require"] {
+      params.items.push(self.builder.formal_parameter(
+        SPAN,
+        self.builder.vec(),
+        self.builder.binding_pattern(
+          self.builder.binding_pattern_kind_binding_identifier(SPAN, name),
+          NONE,
+          false,
+        ),
+        None,
+        false,
+        false,
+      ));
+    }
+    let body = self.builder.function_body(SPAN, directives, statements);
+
+    // __defineModule(...)
+    let mut define_call_expr = self.builder.call_expression(
+      SPAN,
+      self.id_ref_expr("__defineModule", SPAN),
+      NONE,
+      self.builder.vec(),
+      false,
+    );
+
+    if profiler_names {
+      let obj_expr = self.builder.alloc_object_expression(
+        SPAN,
+        self.builder.vec1(self.builder.object_property_kind_object_property(
+          SPAN,
+          PropertyKind::Init,
+          ast::PropertyKey::from(self.builder.expression_string_literal(SPAN, stable_id, None)),
+          self.builder.expression_function(
+            SPAN,
+            FunctionType::FunctionExpression,
+            None,
+            false,
+            false,
+            false,
+            NONE,
+            NONE,
+            params,
+            NONE,
+            Some(body),
+          ),
+          true,
+          false,
+          false,
+        )),
+        None,
+      );
+      define_call_expr.arguments.push(ast::Argument::ObjectExpression(obj_expr));
+    } else {
+      define_call_expr
+        .arguments
+        .push(ast::Argument::StringLiteral(self.alloc_string_literal(stable_id, SPAN)));
+      let arrow_expr =
+        self.builder.alloc_arrow_function_expression(SPAN, false, false, NONE, params, NONE, body);
+      define_call_expr.arguments.push(ast::Argument::ArrowFunctionExpression(arrow_expr));
+    };
+
+    self.builder.statement_expression(
+      SPAN,
+      ast::Expression::CallExpression(define_call_expr.into_in(self.alloc())),
+    )
+  }
+
   /// ```js
   /// (a, b)
   /// ```
@@ -934,4 +1022,21 @@ impl<'ast> AstSnippet<'ast> {
       )),
     )
   }
+
+  /// Parses code provided from outside, such as plugins, into an expression. Spans are reset to
+  /// `SPAN`, because they point to `code` rather than the source of the module being finalized.
+  pub fn parse_expr(&self, code: &str) -> Result<Expression<'ast>, Vec<OxcDiagnostic>> {
+    let code = self.alloc().alloc_str(code);
+    let mut expr = Parser::new(self.alloc(), code, SourceType::mjs()).parse_expression()?;
+    SpanResetter.visit_expression(&mut expr);
+    Ok(expr)
+  }
+}
+
+struct SpanResetter;
+
+impl VisitMut<'_> for SpanResetter {
+  fn visit_span(&mut self, span: &mut Span) {
+    *span = SPAN;
+  }
 }
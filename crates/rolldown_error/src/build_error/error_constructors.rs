@@ -8,16 +8,19 @@ use oxc::{diagnostics::LabeledSpan, span::Span};
 use oxc_resolver::ResolveError;
 
 use crate::events::assign_to_import::AssignToImport;
+use crate::events::boundary_violation::BoundaryViolation;
+use crate::events::duplicate_package::{DuplicatePackage, DuplicatePackageCopy};
 use crate::events::export_undefined_variable::ExportUndefinedVariable;
 use crate::events::illegal_identifier_as_name::IllegalIdentifierAsName;
 use crate::events::import_is_undefined::ImportIsUndefined;
 use crate::events::invalid_define_config::InvalidDefineConfig;
+use crate::events::invalid_import_attribute::InvalidImportAttribute;
 use crate::events::invalid_option::{InvalidOption, InvalidOptionType};
 use crate::events::json_parse::JsonParse;
 use crate::events::missing_global_name::MissingGlobalName;
 use crate::events::missing_name_option_for_iife_export::MissingNameOptionForIifeExport;
 use crate::events::missing_name_option_for_umd_export::MissingNameOptionForUmdExport;
-use crate::events::resolve_error::DiagnosableResolveError;
+use crate::events::resolve_error::{DiagnosableResolveError, ResolveErrorContext};
 use crate::events::unhandleable_error::UnhandleableError;
 use crate::events::unloadable_dependency::{UnloadableDependency, UnloadableDependencyContext};
 use crate::events::unsupported_feature::UnsupportedFeature;
@@ -26,7 +29,7 @@ use crate::events::DiagnosableArcstr;
 use crate::events::NapiError;
 use crate::events::{
   ambiguous_external_namespace::{AmbiguousExternalNamespace, AmbiguousExternalNamespaceModule},
-  circular_dependency::CircularDependency,
+  circular_dependency::{CircularDependency, CircularDependencyEagerAccess},
   commonjs_variable_in_esm::{CjsExportSpan, CommonJsVariableInEsm},
   eval::Eval,
   external_entry::ExternalEntry,
@@ -35,7 +38,7 @@ use crate::events::{
   missing_export::MissingExport,
   mixed_export::MixedExport,
   parse_error::ParseError,
-  sourcemap_error::SourceMapError,
+  sourcemap_error::{InputSourceMapError, SourceMapError},
   unresolved_entry::UnresolvedEntry,
   unresolved_import::UnresolvedImport,
   unresolved_import_treated_as_external::UnresolvedImportTreatedAsExternal,
@@ -82,8 +85,16 @@ impl BuildDiagnostic {
     importee: DiagnosableArcstr,
     reason: String,
     title: Option<&'static str>,
+    context: Option<ResolveErrorContext>,
   ) -> Self {
-    Self::new_inner(DiagnosableResolveError { source, importer_id, importee, reason, title })
+    Self::new_inner(DiagnosableResolveError {
+      source,
+      importer_id,
+      importee,
+      reason,
+      title,
+      context,
+    })
   }
 
   pub fn unloadable_dependency(
@@ -100,8 +111,16 @@ impl BuildDiagnostic {
     Self::new_inner(SourceMapError { error })
   }
 
-  pub fn circular_dependency(paths: Vec<String>) -> Self {
-    Self::new_inner(CircularDependency { paths })
+  pub fn input_sourcemap_error(id: String, reason: String) -> Self {
+    Self::new_inner(InputSourceMapError { id, reason })
+  }
+
+  pub fn circular_dependency(
+    paths: Vec<String>,
+    group_size: usize,
+    eager_access: Option<CircularDependencyEagerAccess>,
+  ) -> Self {
+    Self::new_inner(CircularDependency { paths, group_size, eager_access })
   }
 
   pub fn unresolved_import_treated_as_external(
@@ -117,6 +136,7 @@ impl BuildDiagnostic {
   }
 
   pub fn missing_export(
+    importer: String,
     stable_importer: String,
     stable_importee: String,
     importer_source: ArcStr,
@@ -124,6 +144,7 @@ impl BuildDiagnostic {
     imported_specifier_span: Span,
   ) -> Self {
     Self::new_inner(MissingExport {
+      importer,
       stable_importer,
       stable_importee,
       importer_source,
@@ -195,6 +216,28 @@ impl BuildDiagnostic {
 
   // --- Rolldown related
 
+  pub fn boundary_violation(
+    importer: String,
+    importer_source: ArcStr,
+    importee: String,
+    import_span: Span,
+    chain: Vec<String>,
+    rule_message: Option<String>,
+  ) -> Self {
+    Self::new_inner(BoundaryViolation {
+      importer,
+      importer_source,
+      importee,
+      import_span,
+      chain,
+      rule_message,
+    })
+  }
+
+  pub fn duplicate_package(package_name: String, copies: Vec<DuplicatePackageCopy>) -> Self {
+    Self::new_inner(DuplicatePackage { package_name, copies })
+  }
+
   pub fn oxc_parse_error(
     source: ArcStr,
     filename: String,
@@ -242,6 +285,15 @@ impl BuildDiagnostic {
     Self::new_inner(ForbidConstAssign { filename, source, name, reference_span, re_assign_span })
   }
 
+  pub fn invalid_import_attribute(
+    filename: ArcStr,
+    source: ArcStr,
+    span: Span,
+    reason: String,
+  ) -> Self {
+    Self::new_inner(InvalidImportAttribute { filename, source, span, reason })
+  }
+
   pub fn invalid_option(invalid_option_type: InvalidOptionType) -> Self {
     Self::new_inner(InvalidOption { invalid_option_type })
   }
@@ -36,6 +36,14 @@ impl BuildDiagnostic {
     self.inner.kind()
   }
 
+  pub fn id(&self) -> Option<String> {
+    self.inner.id()
+  }
+
+  pub fn severity(&self) -> Severity {
+    self.severity
+  }
+
   #[must_use]
   pub fn with_source(
     mut self,
@@ -51,6 +59,12 @@ impl BuildDiagnostic {
     self
   }
 
+  #[must_use]
+  pub fn with_severity(mut self, severity: Severity) -> Self {
+    self.severity = severity;
+    self
+  }
+
   pub fn to_diagnostic(&self) -> Diagnostic {
     self.to_diagnostic_with(&DiagnosticOptions::default())
   }
@@ -62,6 +76,15 @@ impl BuildDiagnostic {
     diagnostic
   }
 
+  /// See [`Diagnostic::to_json`] for the shape of the output.
+  pub fn to_json(&self) -> serde_json::Value {
+    self.to_diagnostic().to_json()
+  }
+
+  pub fn to_json_with(&self, opts: &DiagnosticOptions) -> serde_json::Value {
+    self.to_diagnostic_with(opts).to_json()
+  }
+
   #[cfg(feature = "napi")]
   pub fn downcast_napi_error(&self) -> Result<&napi::Error, &Self> {
     match &self.napi_error {
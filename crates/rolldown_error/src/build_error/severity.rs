@@ -3,3 +3,12 @@ pub enum Severity {
   Error,
   Warning,
 }
+
+impl Severity {
+  pub fn as_str(&self) -> &'static str {
+    match self {
+      Severity::Error => "error",
+      Severity::Warning => "warning",
+    }
+  }
+}
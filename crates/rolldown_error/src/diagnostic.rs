@@ -1,17 +1,25 @@
-use crate::build_error::severity::Severity;
+use crate::{build_error::severity::Severity, locator::byte_offset_to_line_column};
 use arcstr::ArcStr;
 use ariadne::{sources, Config, Label, Report, ReportBuilder, ReportKind};
+use serde_json::{json, Value};
 use std::{fmt::Display, ops::Range};
 
 #[derive(Debug, Clone)]
 pub struct DiagnosticFileId(ArcStr);
 
+#[derive(Debug, Clone)]
+pub(crate) struct DiagnosticLabel {
+  pub(crate) filename: ArcStr,
+  pub(crate) range: Range<usize>,
+  pub(crate) message: String,
+}
+
 #[derive(Debug, Clone)]
 pub struct Diagnostic {
   pub(crate) kind: String,
   pub(crate) title: String,
   pub(crate) files: Vec<(/* filename */ ArcStr, /* file content */ ArcStr)>,
-  pub(crate) labels: Vec<Label<(/* filename */ ArcStr, Range<usize>)>>,
+  pub(crate) labels: Vec<DiagnosticLabel>,
   pub(crate) help: Option<String>,
   pub(crate) severity: Severity,
 }
@@ -56,8 +64,7 @@ impl Diagnostic {
   ) -> &mut Self {
     let range = range.into();
     let range = range.start as usize..range.end as usize;
-    let label = Label::new((file_id.0.clone(), range)).with_message(message);
-    self.labels.push(label);
+    self.labels.push(DiagnosticLabel { filename: file_id.0.clone(), range, message });
     self
   }
 
@@ -73,7 +80,8 @@ impl Diagnostic {
     .with_message(self.title.clone());
 
     for label in self.labels.clone() {
-      builder = builder.with_label(label);
+      builder =
+        builder.with_label(Label::new((label.filename, label.range)).with_message(label.message));
     }
 
     if let Some(help) = &self.help {
@@ -97,6 +105,55 @@ impl Diagnostic {
   pub fn to_color_string(&self) -> String {
     self.convert_to_string(true)
   }
+
+  /// Serializes the diagnostic with a stable shape:
+  ///
+  /// ```json
+  /// {
+  ///   "code": "MISSING_EXPORT",
+  ///   "severity": "warning",
+  ///   "message": "...",
+  ///   "help": null,
+  ///   "labels": [{
+  ///     "file": "main.js",
+  ///     "message": "...",
+  ///     "start": { "offset": 9, "line": 1, "column": 9 },
+  ///     "end": { "offset": 12, "line": 1, "column": 12 }
+  ///   }]
+  /// }
+  /// ```
+  ///
+  /// `offset` is in bytes, `line` is 1-based and `column` is 0-based, counted in unicode code points.
+  pub fn to_json(&self) -> Value {
+    json!({
+      "code": self.kind,
+      "severity": self.severity.as_str(),
+      "message": self.title,
+      "help": self.help,
+      "labels": self.labels.iter().map(|label| {
+        let source = self.file_content(&label.filename);
+        json!({
+          "file": label.filename.as_str(),
+          "message": label.message,
+          "start": json_position(source, label.range.start),
+          "end": json_position(source, label.range.end),
+        })
+      }).collect::<Vec<_>>(),
+    })
+  }
+
+  pub(crate) fn file_content(&self, filename: &str) -> &str {
+    self
+      .files
+      .iter()
+      .find_map(|(id, content)| (id.as_str() == filename).then_some(content.as_str()))
+      .unwrap_or_default()
+  }
+}
+
+fn json_position(source: &str, offset: usize) -> Value {
+  let (line, column) = byte_offset_to_line_column(source, offset);
+  json!({ "offset": offset, "line": line + 1, "column": column })
 }
 
 impl Display for Diagnostic {
@@ -27,9 +27,12 @@ pub enum EventKind {
   UnsupportedFeature,
 
   // --- These kinds are rolldown specific
+  BoundaryViolation,
+  DuplicatePackage,
   JsonParse,
   IllegalReassignment,
   InvalidDefineConfig,
+  InvalidImportAttribute,
   ResolveError(Option<&'static str>),
   UnhandleableError,
   UnloadableDependency,
@@ -67,9 +70,12 @@ impl Display for EventKind {
       EventKind::UnsupportedFeature => write!(f, "UNSUPPORTED_FEATURE"),
 
       // --- Rolldown specific
+      EventKind::BoundaryViolation => write!(f, "BOUNDARY_VIOLATION"),
+      EventKind::DuplicatePackage => write!(f, "DUPLICATE_PACKAGE"),
       EventKind::JsonParse => write!(f, "JSON_PARSE"),
       EventKind::IllegalReassignment => write!(f, "ILLEGAL_REASSIGNMENT"),
       EventKind::InvalidDefineConfig => write!(f, "INVALID_DEFINE_CONFIG"),
+      EventKind::InvalidImportAttribute => write!(f, "INVALID_IMPORT_ATTRIBUTE"),
       EventKind::ResolveError(title) => match title {
         Some(title) => write!(f, "{title}"),
         None => write!(f, "RESOLVE_ERROR"),
@@ -25,6 +25,10 @@ impl BuildEvent for AmbiguousExternalNamespace {
     EventKind::AmbiguousExternalNamespace
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.importer.filename.clone())
+  }
+
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     let mut exporter = self.exporter.iter().map(|v| format!(r#""{0}""#, v.filename));
 
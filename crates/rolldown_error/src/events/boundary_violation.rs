@@ -0,0 +1,60 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{
+  diagnostic::Diagnostic, event_kind::EventKind, types::diagnostic_options::DiagnosticOptions,
+};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct BoundaryViolation {
+  pub importer: String,
+  pub importer_source: ArcStr,
+  pub importee: String,
+  pub import_span: Span,
+  /// Modules from an entry down to `importer`, both included.
+  pub chain: Vec<String>,
+  pub rule_message: Option<String>,
+}
+
+impl BuildEvent for BoundaryViolation {
+  fn kind(&self) -> EventKind {
+    EventKind::BoundaryViolation
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let message = format!(
+      r#""{}" is not allowed to import "{}"."#,
+      opts.stabilize_path(&self.importer),
+      opts.stabilize_path(&self.importee)
+    );
+    match &self.rule_message {
+      Some(rule_message) => format!("{message} {rule_message}"),
+      None => message,
+    }
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let importer_file =
+      diagnostic.add_file(opts.stabilize_path(&self.importer), self.importer_source.clone());
+
+    diagnostic.add_label(
+      &importer_file,
+      self.import_span.start..self.import_span.end,
+      "Forbidden import".to_string(),
+    );
+
+    let chain = self
+      .chain
+      .iter()
+      .map(|id| opts.stabilize_path(id))
+      .chain(std::iter::once(opts.stabilize_path(&self.importee)))
+      .collect::<Vec<_>>();
+    diagnostic.add_help(format!("Import chain: {}", chain.join(" -> ")));
+  }
+}
@@ -1,9 +1,22 @@
 use super::BuildEvent;
 use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
 
+/// A module reading a binding of another module in the same cycle before that module is evaluated,
+/// which throws a `ReferenceError` at runtime.
+#[derive(Debug)]
+pub struct CircularDependencyEagerAccess {
+  pub importer: String,
+  pub importee: String,
+  pub binding: String,
+}
+
 #[derive(Debug)]
 pub struct CircularDependency {
+  /// The shortest cycle through the first executed module of the group, starting and ending with it.
   pub paths: Vec<String>,
+  /// Number of modules that import each other directly or indirectly, including those on `paths`.
+  pub group_size: usize,
+  pub eager_access: Option<CircularDependencyEagerAccess>,
 }
 
 impl CircularDependency {
@@ -17,7 +30,29 @@ impl BuildEvent for CircularDependency {
     EventKind::CircularDependency
   }
 
+  fn id(&self) -> Option<String> {
+    self.paths.first().cloned()
+  }
+
   fn message(&self, opts: &DiagnosticOptions) -> String {
-    format!("Circular dependency: {}.", self.stable_paths(opts).join(" -> "))
+    let mut message = format!("Circular dependency: {}.", self.stable_paths(opts).join(" -> "));
+    // The cycle closes with the module it starts with.
+    let modules_on_cycle = self.paths.len().saturating_sub(1).max(1);
+    if self.group_size > modules_on_cycle {
+      message.push_str(&format!(
+        " It is one of the cycles between {} modules importing each other.",
+        self.group_size
+      ));
+    }
+    if let Some(access) = &self.eager_access {
+      message.push_str(&format!(
+        r#" "{}" reads "{}" from "{}" before "{}" is evaluated, which throws at runtime."#,
+        opts.stabilize_path(&access.importer),
+        access.binding,
+        opts.stabilize_path(&access.importee),
+        opts.stabilize_path(&access.importee),
+      ));
+    }
+    message
   }
 }
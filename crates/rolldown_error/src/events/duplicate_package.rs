@@ -0,0 +1,55 @@
+use crate::{event_kind::EventKind, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct DuplicatePackageCopy {
+  pub version: Option<String>,
+  /// Directory containing the `package.json` of this copy.
+  pub path: String,
+  /// Total size of the sources of the modules from this copy.
+  pub bytes: usize,
+  /// Modules outside of this copy that import it.
+  pub importers: Vec<String>,
+}
+
+#[derive(Debug)]
+pub struct DuplicatePackage {
+  pub package_name: String,
+  pub copies: Vec<DuplicatePackageCopy>,
+}
+
+impl BuildEvent for DuplicatePackage {
+  fn kind(&self) -> EventKind {
+    EventKind::DuplicatePackage
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    let copies = self
+      .copies
+      .iter()
+      .map(|copy| {
+        let importers = copy
+          .importers
+          .iter()
+          .map(|id| format!(r#""{}""#, opts.stabilize_path(id)))
+          .collect::<Vec<_>>()
+          .join(", ");
+        format!(
+          r#"- {}@{} in "{}" ({} bytes), imported by {importers}"#,
+          self.package_name,
+          copy.version.as_deref().unwrap_or("unknown"),
+          opts.stabilize_path(&copy.path),
+          copy.bytes,
+        )
+      })
+      .collect::<Vec<_>>();
+    format!(
+      "Package \"{}\" is bundled {} times from different locations:\n{}\nAdd \"{}\" to `resolve.dedupe` to bundle a single copy.",
+      self.package_name,
+      self.copies.len(),
+      copies.join("\n"),
+      self.package_name
+    )
+  }
+}
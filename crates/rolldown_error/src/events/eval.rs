@@ -17,6 +17,10 @@ impl BuildEvent for Eval {
     crate::event_kind::EventKind::Eval
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     format!("Use of eval in '{}' is strongly discouraged as it poses security risks and may cause issues with minification.", self.filename)
   }
@@ -18,6 +18,10 @@ impl BuildEvent for ExportUndefinedVariable {
     crate::event_kind::EventKind::ExportUndefinedVariable
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     format!("`{}` is not declared in this file", self.name)
   }
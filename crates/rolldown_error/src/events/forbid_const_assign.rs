@@ -19,6 +19,10 @@ impl BuildEvent for ForbidConstAssign {
     crate::event_kind::EventKind::IllegalReassignment
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     format!("Unexpected re-assignment of const variable `{0}` at {1}", self.name, self.filename)
   }
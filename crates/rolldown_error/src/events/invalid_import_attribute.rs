@@ -0,0 +1,34 @@
+use arcstr::ArcStr;
+use oxc::span::Span;
+
+use crate::{diagnostic::Diagnostic, types::diagnostic_options::DiagnosticOptions};
+
+use super::BuildEvent;
+
+#[derive(Debug)]
+pub struct InvalidImportAttribute {
+  pub filename: ArcStr,
+  pub source: ArcStr,
+  pub span: Span,
+  pub reason: String,
+}
+
+impl BuildEvent for InvalidImportAttribute {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::InvalidImportAttribute
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.filename.to_string())
+  }
+
+  fn message(&self, _opts: &DiagnosticOptions) -> String {
+    self.reason.clone()
+  }
+
+  fn on_diagnostic(&self, diagnostic: &mut Diagnostic, opts: &DiagnosticOptions) {
+    let file_id =
+      diagnostic.add_file(opts.stabilize_path(self.filename.as_str()), self.source.clone());
+    diagnostic.add_label(&file_id, self.span.start..self.span.end, String::new());
+  }
+}
@@ -6,6 +6,8 @@ pub enum InvalidOptionType {
   UnsupportedCodeSplittingFormat(String),
   InvalidOutputFile,
   InvalidOutputDirOption,
+  UnsupportedRuntimeHelpersFormat(String),
+  NameCacheWithoutTopLevel,
 }
 
 #[derive(Debug)]
@@ -24,7 +26,11 @@ impl BuildEvent for InvalidOption {
         format!("Invalid value \"{format}\" for option \"output.format\" - UMD and IIFE are not supported for code splitting. You may set `output.inlineDynamicImports` to `true` when using dynamic imports.")
       }
       InvalidOptionType::InvalidOutputFile => "Invalid value for option \"output.file\" - When building multiple chunks, the \"output.dir\" option must be used, not \"output.file\". You may set `output.inlineDynamicImports` to `true` when using dynamic imports.".to_string(),
-      InvalidOptionType::InvalidOutputDirOption => "Invalid value for option \"output.dir\" - you must set either \"output.file\" for a single-file build or \"output.dir\" when generating multiple chunks.".to_string()
+      InvalidOptionType::InvalidOutputDirOption => "Invalid value for option \"output.dir\" - you must set either \"output.file\" for a single-file build or \"output.dir\" when generating multiple chunks.".to_string(),
+      InvalidOptionType::UnsupportedRuntimeHelpersFormat(format) => {
+        format!("Invalid value for option \"output.runtimeHelpers\" - Runtime helpers can't be imported in \"{format}\" format. They will be inlined instead.")
+      }
+      InvalidOptionType::NameCacheWithoutTopLevel => "Invalid value for option \"output.minify.mangle.nameCache\" - The name cache only remembers top-level bindings, so it's ignored unless \"output.minify.mangle.topLevel\" is enabled.".to_string(),
     }
   }
 }
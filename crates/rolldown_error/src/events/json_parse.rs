@@ -18,6 +18,10 @@ impl BuildEvent for JsonParse {
     crate::event_kind::EventKind::JsonParse
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.filename.to_string())
+  }
+
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     self.message.to_string()
   }
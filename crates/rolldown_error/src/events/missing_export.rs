@@ -7,6 +7,8 @@ use super::BuildEvent;
 
 #[derive(Debug)]
 pub struct MissingExport {
+  /// The absolute id of the importer, used to match `log_rules`.
+  pub importer: String,
   pub stable_importer: String,
   pub stable_importee: String,
   pub importer_source: ArcStr,
@@ -19,6 +21,10 @@ impl BuildEvent for MissingExport {
     EventKind::MissingExport
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.importer.clone())
+  }
+
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     format!(
       r#""{}" is not exported by "{}", imported by "{}"."#,
@@ -14,6 +14,10 @@ impl BuildEvent for MixedExport {
     EventKind::MixedExport
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.module_name.to_string())
+  }
+
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     format!(
       r#"Entry module "{}" is using named (including {}) and default exports together. Consumers of your bundle will have to use `{}.default` to access the default export, which may not be what you want. Use `output.exports: "named"` to disable this warning."#,
@@ -9,8 +9,10 @@ use crate::{
 
 pub mod ambiguous_external_namespace;
 pub mod assign_to_import;
+pub mod boundary_violation;
 pub mod circular_dependency;
 pub mod commonjs_variable_in_esm;
+pub mod duplicate_package;
 pub mod eval;
 pub mod export_undefined_variable;
 pub mod external_entry;
@@ -19,6 +21,7 @@ pub mod illegal_identifier_as_name;
 pub mod import_is_undefined;
 pub mod invalid_define_config;
 pub mod invalid_export_option;
+pub mod invalid_import_attribute;
 pub mod invalid_option;
 pub mod json_parse;
 pub mod missing_export;
@@ -39,6 +42,11 @@ pub mod unsupported_feature;
 pub trait BuildEvent: Debug + Sync + Send {
   fn kind(&self) -> EventKind;
 
+  /// The id of the module this event is about, if any. It's used to match `log_rules`.
+  fn id(&self) -> Option<String> {
+    None
+  }
+
   fn message(&self, opts: &DiagnosticOptions) -> String;
 
   fn on_diagnostic(&self, _diagnostic: &mut Diagnostic, _opts: &DiagnosticOptions) {}
@@ -19,6 +19,10 @@ impl BuildEvent for ParseError {
     crate::event_kind::EventKind::ParseError
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.filename.clone())
+  }
+
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     format!("Parse failed, got: {:?}", self.error_message)
   }
@@ -3,6 +3,40 @@ use arcstr::ArcStr;
 
 use super::{BuildEvent, DiagnosableArcstr};
 
+/// Hints about how to fix a failed resolution, shown as the help of the diagnostic.
+#[derive(Debug)]
+pub enum ResolveErrorContext {
+  /// Existing files that look like what the specifier meant, e.g. the same name with different
+  /// casing or extension. They are written as specifiers relative to the importer.
+  NearMisses(Vec<String>),
+  /// The subpaths the package defines in `exports`, when the requested one isn't exported.
+  ExportedSubpaths { package_name: String, subpaths: Vec<String> },
+}
+
+impl ResolveErrorContext {
+  fn help(&self) -> Option<String> {
+    match self {
+      Self::NearMisses(near_misses) => match near_misses.as_slice() {
+        [] => None,
+        [near_miss] => Some(format!("Did you mean {near_miss:?}?")),
+        near_misses => Some(format!(
+          "Did you mean one of {}?",
+          near_misses.iter().map(|item| format!("{item:?}")).collect::<Vec<_>>().join(", ")
+        )),
+      },
+      Self::ExportedSubpaths { package_name, subpaths } => {
+        if subpaths.is_empty() {
+          return None;
+        }
+        Some(format!(
+          "Package {package_name:?} only exports these subpaths: {}",
+          subpaths.iter().map(|item| format!("{item:?}")).collect::<Vec<_>>().join(", ")
+        ))
+      }
+    }
+  }
+}
+
 #[derive(Debug)]
 pub struct DiagnosableResolveError {
   pub source: ArcStr,
@@ -10,6 +44,7 @@ pub struct DiagnosableResolveError {
   pub importee: DiagnosableArcstr,
   pub reason: String,
   pub title: Option<&'static str>,
+  pub context: Option<ResolveErrorContext>,
 }
 
 impl BuildEvent for DiagnosableResolveError {
@@ -17,6 +52,10 @@ impl BuildEvent for DiagnosableResolveError {
     crate::event_kind::EventKind::ResolveError(self.title)
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.importer_id.to_string())
+  }
+
   fn message(&self, opts: &DiagnosticOptions) -> String {
     let importee = match &self.importee {
       DiagnosableArcstr::String(str) => str.as_str(),
@@ -40,5 +79,8 @@ impl BuildEvent for DiagnosableResolveError {
       _ => {}
     };
     diagnostic.title = self.message(opts);
+    if let Some(help) = self.context.as_ref().and_then(ResolveErrorContext::help) {
+      diagnostic.add_help(help);
+    }
   }
 }
@@ -16,3 +16,28 @@ impl BuildEvent for SourceMapError {
     format!("Error when using sourcemap for reporting an error: {:?}", self.error)
   }
 }
+
+/// The source map referenced by the `sourceMappingURL` comment of a module can't be used.
+#[derive(Debug)]
+pub struct InputSourceMapError {
+  pub id: String,
+  pub reason: String,
+}
+
+impl BuildEvent for InputSourceMapError {
+  fn kind(&self) -> crate::event_kind::EventKind {
+    crate::event_kind::EventKind::SourcemapError
+  }
+
+  fn id(&self) -> Option<String> {
+    Some(self.id.clone())
+  }
+
+  fn message(&self, opts: &DiagnosticOptions) -> String {
+    format!(
+      "Ignored the source map referenced by \"{}\": {}",
+      opts.stabilize_path(&self.id),
+      self.reason
+    )
+  }
+}
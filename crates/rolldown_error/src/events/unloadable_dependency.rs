@@ -23,6 +23,10 @@ impl BuildEvent for UnloadableDependency {
     crate::event_kind::EventKind::UnloadableDependency
   }
 
+  fn id(&self) -> Option<String> {
+    self.context.as_ref().map(|context| context.importer_id.to_string())
+  }
+
   fn message(&self, _opts: &DiagnosticOptions) -> String {
     format!(
       "Could not load {}{} - {}.",
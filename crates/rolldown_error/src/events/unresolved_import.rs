@@ -14,6 +14,10 @@ impl BuildEvent for UnresolvedImport {
     crate::event_kind::EventKind::UnresolvedImport
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.importer.to_string_lossy().into_owned())
+  }
+
   fn message(&self, opts: &DiagnosticOptions) -> String {
     format!("Could not resolve {} from {}.", self.specifier, opts.stabilize_path(&self.importer))
   }
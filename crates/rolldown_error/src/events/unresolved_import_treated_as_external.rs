@@ -18,6 +18,10 @@ impl BuildEvent for UnresolvedImportTreatedAsExternal {
     EventKind::UnresolvedImport
   }
 
+  fn id(&self) -> Option<String> {
+    Some(self.importer.to_string_lossy().into_owned())
+  }
+
   fn message(&self, opts: &crate::DiagnosticOptions) -> String {
     // https://github.com/rollup/rollup/blob/fe6cb3a291df245408ef2bdc708fc64fa4ecb262/src/utils/logs.ts#L1031-L1041
     let mut message=
@@ -3,6 +3,7 @@ mod diagnostic;
 mod event_kind;
 mod events;
 mod locator;
+mod sarif;
 mod type_aliases;
 mod types;
 
@@ -12,11 +13,15 @@ pub use crate::{
   build_error::{severity::Severity, BuildDiagnostic},
   event_kind::EventKind,
   events::ambiguous_external_namespace::AmbiguousExternalNamespaceModule,
+  events::circular_dependency::CircularDependencyEagerAccess,
   events::commonjs_variable_in_esm::CjsExportSpan,
+  events::duplicate_package::DuplicatePackageCopy,
   events::invalid_option::InvalidOptionType,
+  events::resolve_error::ResolveErrorContext,
   events::unloadable_dependency::UnloadableDependencyContext,
   events::DiagnosableArcstr,
-  locator::line_column_to_byte_offset,
+  locator::{byte_offset_to_line_column, line_column_to_byte_offset},
+  sarif::diagnostics_to_sarif,
   type_aliases::{BuildResult, SingleBuildResult},
   types::diagnostic_options::DiagnosticOptions,
 };
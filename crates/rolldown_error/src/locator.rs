@@ -24,6 +24,18 @@ pub fn line_column_to_byte_offset(source: &str, line: usize, column: usize) -> u
   ByteLocator::new(source).byte_offset(line, column)
 }
 
+/// The reverse of `line_column_to_byte_offset`. Both line and column are 0-based, and the column is
+/// counted in unicode code points.
+pub fn byte_offset_to_line_column(source: &str, offset: usize) -> (usize, usize) {
+  let mut offset = offset.min(source.len());
+  while !source.is_char_boundary(offset) {
+    offset -= 1;
+  }
+  let before = &source[..offset];
+  let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+  (before[..line_start].matches('\n').count(), before[line_start..].chars().count())
+}
+
 mod test_locator {
   #[test]
   fn line_column_to_byte_offset() {
@@ -33,4 +45,14 @@ mod test_locator {
     assert_eq!(ByteLocator::new(source).byte_offset(0, 0), 0);
     assert_eq!(ByteLocator::new(source).byte_offset(1, 0), 4);
   }
+
+  #[test]
+  fn byte_offset_to_line_column() {
+    use super::byte_offset_to_line_column;
+    let source = "abc\nd€f\nghi";
+    assert_eq!(byte_offset_to_line_column(source, 0), (0, 0));
+    assert_eq!(byte_offset_to_line_column(source, 4), (1, 0));
+    assert_eq!(byte_offset_to_line_column(source, 8), (1, 2));
+    assert_eq!(byte_offset_to_line_column(source, 100), (2, 3));
+  }
 }
@@ -0,0 +1,118 @@
+use serde_json::{json, Value};
+
+use crate::{
+  diagnostic::Diagnostic, locator::byte_offset_to_line_column, BuildDiagnostic, DiagnosticOptions,
+};
+
+/// Exports diagnostics as a [SARIF 2.1.0](https://docs.oasis-open.org/sarif/sarif/v2.1.0/sarif-v2.1.0.html) log,
+/// which code scanning tools are able to annotate source files with.
+pub fn diagnostics_to_sarif(diagnostics: &[BuildDiagnostic], opts: &DiagnosticOptions) -> Value {
+  let diagnostics =
+    diagnostics.iter().map(|diagnostic| diagnostic.to_diagnostic_with(opts)).collect::<Vec<_>>();
+
+  let mut rule_ids =
+    diagnostics.iter().map(|diagnostic| diagnostic.kind.as_str()).collect::<Vec<_>>();
+  rule_ids.sort_unstable();
+  rule_ids.dedup();
+
+  json!({
+    "$schema": "https://json.schemastore.org/sarif-2.1.0.json",
+    "version": "2.1.0",
+    "runs": [{
+      "tool": {
+        "driver": {
+          "name": "rolldown",
+          "informationUri": "https://rolldown.rs",
+          "rules": rule_ids.iter().map(|id| json!({ "id": id })).collect::<Vec<_>>(),
+        }
+      },
+      "columnKind": "unicodeCodePoints",
+      "results": diagnostics.iter().map(sarif_result).collect::<Vec<_>>(),
+    }]
+  })
+}
+
+fn sarif_result(diagnostic: &Diagnostic) -> Value {
+  let text = match &diagnostic.help {
+    Some(help) => format!("{}\nHelp: {help}", diagnostic.title),
+    None => diagnostic.title.clone(),
+  };
+
+  let locations = diagnostic
+    .labels
+    .iter()
+    .map(|label| {
+      let source = diagnostic.file_content(&label.filename);
+      let (start_line, start_column) = byte_offset_to_line_column(source, label.range.start);
+      let (end_line, end_column) = byte_offset_to_line_column(source, label.range.end);
+      // SARIF lines and columns are 1-based.
+      json!({
+        "physicalLocation": {
+          "artifactLocation": { "uri": label.filename.replace('\\', "/") },
+          "region": {
+            "startLine": start_line + 1,
+            "startColumn": start_column + 1,
+            "endLine": end_line + 1,
+            "endColumn": end_column + 1,
+          }
+        },
+        "message": { "text": label.message },
+      })
+    })
+    .collect::<Vec<_>>();
+
+  json!({
+    "ruleId": diagnostic.kind,
+    // Both severities are valid SARIF levels as they are.
+    "level": diagnostic.severity.as_str(),
+    "message": { "text": text },
+    "locations": locations,
+  })
+}
+
+#[cfg(test)]
+mod tests {
+  use oxc::span::Span;
+  use serde_json::json;
+
+  use super::diagnostics_to_sarif;
+  use crate::{BuildDiagnostic, DiagnosticOptions};
+
+  fn missing_export() -> BuildDiagnostic {
+    BuildDiagnostic::missing_export(
+      "/root/main.js".to_string(),
+      "main.js".to_string(),
+      "foo.js".to_string(),
+      "const a = 1;\nimport { bar } from './foo.js'".into(),
+      "bar".to_string(),
+      Span::new(22, 25),
+    )
+    .with_severity_warning()
+  }
+
+  #[test]
+  fn to_json() {
+    let value = missing_export().to_json();
+    assert_eq!(value["code"], "MISSING_EXPORT");
+    assert_eq!(value["severity"], "warning");
+    assert_eq!(value["labels"][0]["file"], "main.js");
+    assert_eq!(value["labels"][0]["start"], json!({ "offset": 22, "line": 2, "column": 9 }));
+    assert_eq!(value["labels"][0]["end"], json!({ "offset": 25, "line": 2, "column": 12 }));
+  }
+
+  #[test]
+  fn to_sarif() {
+    let value = diagnostics_to_sarif(&[missing_export()], &DiagnosticOptions::default());
+    let run = &value["runs"][0];
+    assert_eq!(value["version"], "2.1.0");
+    assert_eq!(run["tool"]["driver"]["rules"], json!([{ "id": "MISSING_EXPORT" }]));
+    assert_eq!(run["results"][0]["ruleId"], "MISSING_EXPORT");
+    assert_eq!(run["results"][0]["level"], "warning");
+    let location = &run["results"][0]["locations"][0]["physicalLocation"];
+    assert_eq!(location["artifactLocation"]["uri"], "main.js");
+    assert_eq!(
+      location["region"],
+      json!({ "startLine": 2, "startColumn": 10, "endLine": 2, "endColumn": 13 })
+    );
+  }
+}
@@ -85,6 +85,21 @@ impl FileSystem for MemoryFileSystem {
       .read_to_end(&mut buf)?;
     Ok(buf)
   }
+
+  fn read_dir(&self, path: &Path) -> io::Result<Vec<PathBuf>> {
+    let names = self
+      .fs
+      .read_dir(&path.to_string_lossy())
+      .map_err(|err| io::Error::new(io::ErrorKind::NotFound, err))?;
+    Ok(names.map(|name| path.join(name)).collect())
+  }
+
+  fn is_dir(&self, path: &Path) -> bool {
+    self
+      .fs
+      .metadata(&path.to_string_lossy())
+      .is_ok_and(|metadata| metadata.file_type == vfs::VfsFileType::Directory)
+  }
 }
 
 impl OxcResolverFileSystem for MemoryFileSystem {
@@ -114,7 +129,7 @@ impl OxcResolverFileSystem for MemoryFileSystem {
     })
   }
 
-  fn canonicalize(&self, _path: &Path) -> io::Result<PathBuf> {
+  fn read_link(&self, _path: &Path) -> io::Result<PathBuf> {
     Err(io::Error::new(io::ErrorKind::NotFound, "not a symlink"))
   }
 }
@@ -20,26 +20,31 @@ pub mod __inner {
 pub use crate::{
   plugin::{
     HookAugmentChunkHashReturn, HookInjectionOutputReturn, HookLoadReturn, HookNoopReturn,
-    HookRenderChunkReturn, HookResolveIdReturn, HookTransformAstReturn, HookTransformReturn,
-    Plugin,
+    HookOptionsReturn, HookRenderChunkReturn, HookRenderDynamicImportReturn, HookResolveIdReturn,
+    HookShouldTransformCachedModuleReturn, HookTransformAstReturn, HookTransformReturn, Plugin,
   },
   plugin_context::PluginContext,
   plugin_driver::{PluginDriver, SharedPluginDriver},
   plugin_hook_meta::{PluginHookMeta, PluginOrder},
   transform_plugin_context::{SharedTransformPluginContext, TransformPluginContext},
-  types::custom_field::CustomField,
   types::hook_addon_args::HookAddonArgs,
   types::hook_build_end_args::HookBuildEndArgs,
   types::hook_build_start_args::HookBuildStartArgs,
+  types::hook_filter::{GeneralHookFilter, HookFilter},
   types::hook_generate_bundle_args::HookGenerateBundleArgs,
   types::hook_load_args::HookLoadArgs,
   types::hook_load_output::HookLoadOutput,
   types::hook_render_chunk_args::HookRenderChunkArgs,
   types::hook_render_chunk_output::HookRenderChunkOutput,
+  types::hook_render_dynamic_import_args::HookRenderDynamicImportArgs,
+  types::hook_render_dynamic_import_output::HookRenderDynamicImportOutput,
   types::hook_render_error::HookRenderErrorArgs,
   types::hook_render_start_args::HookRenderStartArgs,
+  types::hook_resolve_file_url_args::HookResolveFileUrlArgs,
   types::hook_resolve_id_args::HookResolveIdArgs,
   types::hook_resolve_id_output::HookResolveIdOutput,
+  types::hook_resolve_import_meta_args::HookResolveImportMetaArgs,
+  types::hook_should_transform_cached_module_args::HookShouldTransformCachedModuleArgs,
   types::hook_transform_args::HookTransformArgs,
   types::hook_transform_ast_args::HookTransformAstArgs,
   types::hook_transform_output::HookTransformOutput,
@@ -47,4 +52,5 @@ pub use crate::{
   types::plugin_context_resolve_options::PluginContextResolveOptions,
 };
 
+pub use rolldown_common::CustomField;
 pub use typedmap;
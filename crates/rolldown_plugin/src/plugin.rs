@@ -9,11 +9,13 @@ use crate::{
     hook_transform_output::HookTransformOutput,
   },
   HookAddonArgs, HookBuildEndArgs, HookGenerateBundleArgs, HookLoadArgs, HookLoadOutput,
-  HookRenderChunkArgs, HookRenderChunkOutput, HookResolveIdArgs, HookResolveIdOutput,
-  HookTransformArgs, HookWriteBundleArgs, SharedTransformPluginContext,
+  HookRenderChunkArgs, HookRenderChunkOutput, HookRenderDynamicImportArgs,
+  HookRenderDynamicImportOutput, HookResolveFileUrlArgs, HookResolveIdArgs, HookResolveIdOutput,
+  HookResolveImportMetaArgs, HookShouldTransformCachedModuleArgs, HookTransformArgs,
+  HookWriteBundleArgs, SharedTransformPluginContext,
 };
 use anyhow::Result;
-use rolldown_common::{ModuleInfo, RollupRenderedChunk, WatcherChangeKind};
+use rolldown_common::{BundlerOptions, ModuleInfo, RollupRenderedChunk, WatcherChangeKind};
 use rolldown_ecmascript::EcmaAst;
 
 pub type HookResolveIdReturn = Result<Option<HookResolveIdOutput>>;
@@ -24,11 +26,42 @@ pub type HookNoopReturn = Result<()>;
 pub type HookRenderChunkReturn = Result<Option<HookRenderChunkOutput>>;
 pub type HookAugmentChunkHashReturn = Result<Option<String>>;
 pub type HookInjectionOutputReturn = Result<Option<String>>;
+pub type HookOptionsReturn = Result<Option<BundlerOptions>>;
+pub type HookRenderDynamicImportReturn = Result<Option<HookRenderDynamicImportOutput>>;
+pub type HookShouldTransformCachedModuleReturn = Result<bool>;
 
 pub trait Plugin: Any + Debug + Send + Sync + 'static {
   fn name(&self) -> Cow<'static, str>;
 
-  // The `option` hook consider call at node side.
+  // --- Options hooks ---
+  // These hooks run before the plugin driver is created, so there is no `PluginContext` for them.
+  // Hooks of js plugins are called at node side.
+
+  /// Called with the raw input options before they are normalized. Returning `Some` replaces the
+  /// options passed to the next plugin and finally to the bundler.
+  fn options(
+    &self,
+    _options: &BundlerOptions,
+  ) -> impl std::future::Future<Output = HookOptionsReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn options_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
+  /// Same as [Plugin::options], but called after all `options` hooks and meant to adjust output
+  /// related options.
+  fn output_options(
+    &self,
+    _options: &BundlerOptions,
+  ) -> impl std::future::Future<Output = HookOptionsReturn> + Send {
+    async { Ok(None) }
+  }
+
+  fn output_options_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
 
   // --- Build hooks ---
 
@@ -95,6 +128,21 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  /// Called for modules whose `load` and `transform` results are reused from the cache of
+  /// incremental builds. Returning `true` discards the cached result and runs `load` and
+  /// `transform` again.
+  fn should_transform_cached_module(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookShouldTransformCachedModuleArgs<'_>,
+  ) -> impl std::future::Future<Output = HookShouldTransformCachedModuleReturn> + Send {
+    async { Ok(false) }
+  }
+
+  fn should_transform_cached_module_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   fn module_parsed(
     &self,
     _ctx: &PluginContext,
@@ -181,6 +229,51 @@ pub trait Plugin: Any + Debug + Send + Sync + 'static {
     None
   }
 
+  // `resolve_import_meta`, `resolve_file_url` and `render_dynamic_import` are synchronous, because
+  // they are called while finalizing modules in parallel. The returned code is parsed as an
+  // expression and replaces the original one.
+
+  /// Called for `import.meta.<property>`. Returning `Some` overrides how rolldown renders it.
+  fn resolve_import_meta(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveImportMetaArgs<'_>,
+  ) -> HookInjectionOutputReturn {
+    Ok(None)
+  }
+
+  fn resolve_import_meta_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
+  /// Called for `new URL('./foo.png', import.meta.url)` that references an asset and for
+  /// `import.meta.ROLLUP_FILE_URL_<referenceId>`. Returning `Some` replaces the whole expression.
+  fn resolve_file_url(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveFileUrlArgs<'_>,
+  ) -> HookInjectionOutputReturn {
+    Ok(None)
+  }
+
+  fn resolve_file_url_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
+  /// Called for `import('...')` that isn't inlined. Returning `Some` replaces the whole `import(...)`
+  /// expression with `${left}'${specifier}'${right}`.
+  fn render_dynamic_import(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderDynamicImportArgs<'_>,
+  ) -> HookRenderDynamicImportReturn {
+    Ok(None)
+  }
+
+  fn render_dynamic_import_meta(&self) -> Option<PluginHookMeta> {
+    None
+  }
+
   fn render_chunk(
     &self,
     _ctx: &PluginContext,
@@ -193,6 +193,10 @@ impl PluginContextImpl {
     self.modules.iter().map(|v| v.key().to_string()).collect()
   }
 
+  pub fn options(&self) -> &SharedNormalizedBundlerOptions {
+    &self.options
+  }
+
   pub fn cwd(&self) -> &PathBuf {
     self.resolver.cwd()
   }
@@ -7,15 +7,19 @@ use crate::{
     plugin_idx::PluginIdx,
   },
   HookBuildEndArgs, HookLoadArgs, HookLoadReturn, HookNoopReturn, HookResolveIdArgs,
-  HookResolveIdReturn, HookTransformArgs, PluginContext, PluginDriver, TransformPluginContext,
+  HookResolveIdReturn, HookShouldTransformCachedModuleArgs, HookShouldTransformCachedModuleReturn,
+  HookTransformArgs, PluginContext, PluginDriver, TransformPluginContext,
 };
 use anyhow::Result;
+use arcstr::ArcStr;
 use rolldown_common::{
-  side_effects::HookSideEffects, ModuleInfo, ModuleType, SharedNormalizedBundlerOptions,
+  side_effects::HookSideEffects, CustomField, ModuleInfo, ModuleType,
+  SharedNormalizedBundlerOptions, SourceMapQuality,
 };
 use rolldown_sourcemap::SourceMap;
-use rolldown_utils::unique_arc::UniqueArc;
+use rolldown_utils::{dashmap::FxDashSet, unique_arc::UniqueArc};
 use string_wizard::{MagicString, SourceMapOptions};
+use tracing::Instrument;
 
 impl PluginDriver {
   #[tracing::instrument(level = "trace", skip_all)]
@@ -90,6 +94,15 @@ impl PluginDriver {
       if skipped_plugins.iter().any(|p| *p == plugin_idx) {
         continue;
       }
+      if !Self::matches_hook_filter(
+        self.hook_filters.resolve_id[plugin_idx].as_ref(),
+        ctx,
+        args.specifier,
+        None,
+        None,
+      ) {
+        continue;
+      }
       if let Some(r) = plugin
         .call_resolve_id(
           &skipped_resolve_calls.map_or_else(
@@ -103,6 +116,7 @@ impl PluginDriver {
           ),
           args,
         )
+        .instrument(Self::hook_span(plugin, "resolve_id", args.specifier))
         .await?
       {
         return Ok(Some(r));
@@ -148,16 +162,28 @@ impl PluginDriver {
   }
 
   pub async fn load(&self, args: &HookLoadArgs<'_>) -> HookLoadReturn {
-    for (_plugin_idx, plugin, ctx) in
+    for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_load_meta)
     {
-      if let Some(r) = plugin.call_load(ctx, args).await? {
+      if !Self::matches_hook_filter(
+        self.hook_filters.load[plugin_idx].as_ref(),
+        ctx,
+        args.id,
+        None,
+        None,
+      ) {
+        continue;
+      }
+      if let Some(r) =
+        plugin.call_load(ctx, args).instrument(Self::hook_span(plugin, "load", args.id)).await?
+      {
         return Ok(Some(r));
       }
     }
     Ok(None)
   }
 
+  #[allow(clippy::too_many_arguments)]
   pub async fn transform(
     &self,
     id: &str,
@@ -165,13 +191,26 @@ impl PluginDriver {
     sourcemap_chain: &mut Vec<SourceMap>,
     side_effects: &mut Option<HookSideEffects>,
     module_type: &mut ModuleType,
+    meta: &mut Option<Arc<CustomField>>,
+    watch_files: &mut Vec<ArcStr>,
+    sourcemap_quality: SourceMapQuality,
   ) -> Result<String> {
+    let transform_watch_files = Arc::new(FxDashSet::default());
     let mut code = original_code;
     let mut original_sourcemap_chain = std::mem::take(sourcemap_chain);
     let mut plugin_sourcemap_chain = UniqueArc::new(original_sourcemap_chain);
-    for (_plugin_idx, plugin, ctx) in
+    for (plugin_idx, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_transform_meta)
     {
+      if !Self::matches_hook_filter(
+        self.hook_filters.transform[plugin_idx].as_ref(),
+        ctx,
+        id,
+        Some(&*module_type),
+        Some(&code),
+      ) {
+        continue;
+      }
       if let Some(r) = plugin
         .call_transform(
           Arc::new(TransformPluginContext::new(
@@ -179,28 +218,50 @@ impl PluginDriver {
             plugin_sourcemap_chain.weak_ref(),
             code.as_str().into(),
             id.into(),
+            Arc::clone(&transform_watch_files),
           )),
           &HookTransformArgs { id, code: &code, module_type: &*module_type },
         )
+        .instrument(Self::hook_span(plugin, "transform", id))
         .await?
       {
         original_sourcemap_chain = plugin_sourcemap_chain.into_inner();
-        if let Some(map) = Self::normalize_transform_sourcemap(r.map, id, &code, r.code.as_ref()) {
-          original_sourcemap_chain.push(map);
+        // Maps of excluded modules are never used, so they are neither normalized nor kept.
+        if let Some(hires) = match sourcemap_quality {
+          SourceMapQuality::Full => Some(string_wizard::Hires::True),
+          SourceMapQuality::Cheap => Some(string_wizard::Hires::False),
+          SourceMapQuality::Excluded => None,
+        } {
+          if let Some(map) =
+            Self::normalize_transform_sourcemap(r.map, id, &code, r.code.as_ref(), hires)
+          {
+            original_sourcemap_chain.push(map);
+          }
         }
         plugin_sourcemap_chain = UniqueArc::new(original_sourcemap_chain);
         if let Some(v) = r.side_effects {
           *side_effects = Some(v);
         }
         if let Some(v) = r.code {
+          tracing::trace!(
+            plugin = %plugin.call_name(),
+            module_id = id,
+            before = code.as_str(),
+            after = v.as_str(),
+            "transformed"
+          );
           code = v;
         }
         if let Some(ty) = r.module_type {
           *module_type = ty;
         }
+        if let Some(v) = r.meta {
+          *meta = Some(v);
+        }
       }
     }
     *sourcemap_chain = plugin_sourcemap_chain.into_inner();
+    watch_files.extend(transform_watch_files.iter().map(|file| file.clone()));
     Ok(code)
   }
 
@@ -210,6 +271,7 @@ impl PluginDriver {
     id: &str,
     original_code: &str,
     code: Option<&String>,
+    hires: string_wizard::Hires,
   ) -> Option<SourceMap> {
     if let Some(mut map) = map {
       // If sourcemap  hasn't `sources`, using original id to fill it.
@@ -229,11 +291,11 @@ impl PluginDriver {
         None
       } else {
         // If sourcemap is empty and code has changed, need to create one remapping original code.
-        // Here using `hires: true` to get more accurate column information, but it has more overhead.
-        // TODO: maybe it should be add a option to control hires.
+        // `hires` follows the `sourcemapQuality` of the module, since `Hires::True` is more accurate
+        // but has more overhead.
         let magic_string = MagicString::new(original_code);
         Some(magic_string.source_map(SourceMapOptions {
-          hires: string_wizard::Hires::True,
+          hires,
           include_content: true,
           source: id.into(),
         }))
@@ -260,6 +322,21 @@ impl PluginDriver {
     Ok(args.ast)
   }
 
+  /// Returns `true` if any plugin wants to discard the cached `load` and `transform` results.
+  pub async fn should_transform_cached_module(
+    &self,
+    args: &HookShouldTransformCachedModuleArgs<'_>,
+  ) -> HookShouldTransformCachedModuleReturn {
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_should_transform_cached_module_meta)
+    {
+      if plugin.call_should_transform_cached_module(ctx, args).await? {
+        return Ok(true);
+      }
+    }
+    Ok(false)
+  }
+
   pub async fn module_parsed(&self, module_info: Arc<ModuleInfo>) -> HookNoopReturn {
     for (_, plugin, ctx) in
       self.iter_plugin_with_context_by_order(&self.order_by_module_parsed_meta)
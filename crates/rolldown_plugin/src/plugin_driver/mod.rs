@@ -6,8 +6,10 @@ use std::{
 
 use arcstr::ArcStr;
 use dashmap::{DashMap, DashSet};
+use oxc_index::IndexVec;
 use rolldown_common::{
-  ModuleId, ModuleInfo, ModuleLoaderMsg, SharedFileEmitter, SharedNormalizedBundlerOptions,
+  ModuleId, ModuleInfo, ModuleLoaderMsg, ModuleType, SharedFileEmitter,
+  SharedNormalizedBundlerOptions,
 };
 use rolldown_resolver::Resolver;
 use rolldown_utils::dashmap::{FxDashMap, FxDashSet};
@@ -18,10 +20,11 @@ use crate::{
   plugin_context::{LoadCallback, PluginContextImpl},
   type_aliases::{IndexPluginContext, IndexPluginable},
   types::plugin_idx::PluginIdx,
-  PluginContext, PluginHookMeta, PluginOrder,
+  HookFilter, PluginContext, PluginHookMeta, PluginOrder,
 };
 
 mod build_hooks;
+mod options_hooks;
 mod output_hooks;
 mod watch_hooks;
 
@@ -31,6 +34,7 @@ pub struct PluginDriver {
   plugins: IndexPluginable,
   contexts: IndexPluginContext,
   order_indicates: HookOrderIndicates,
+  hook_filters: HookFilterIndicates,
   pub file_emitter: SharedFileEmitter,
   pub watch_files: Arc<FxDashSet<ArcStr>>,
   pub modules: Arc<FxDashMap<ArcStr, Arc<ModuleInfo>>>,
@@ -75,6 +79,7 @@ impl PluginDriver {
 
       Self {
         order_indicates: HookOrderIndicates::new(&index_plugins),
+        hook_filters: HookFilterIndicates::new(&index_plugins),
         plugins: index_plugins,
         contexts: index_contexts,
         file_emitter: Arc::clone(file_emitter),
@@ -121,6 +126,25 @@ impl PluginDriver {
       (idx, plugin, context)
     })
   }
+
+  /// Returns `false` if the plugin declared a filter for the hook and the module doesn't match it,
+  /// which means the hook shouldn't be called.
+  #[inline]
+  fn matches_hook_filter(
+    filter: Option<&HookFilter>,
+    ctx: &PluginContext,
+    id: &str,
+    module_type: Option<&ModuleType>,
+    code: Option<&str>,
+  ) -> bool {
+    filter.map_or(true, |filter| filter.matches(id, ctx.cwd(), module_type, code))
+  }
+
+  /// Span wrapping a single call of a plugin hook, used to inspect time spent in plugins.
+  #[inline]
+  fn hook_span(plugin: &SharedPluginable, hook: &'static str, module_id: &str) -> tracing::Span {
+    tracing::trace_span!("plugin_hook", plugin = %plugin.call_name(), hook, module_id)
+  }
 }
 
 impl Deref for PluginDriver {
@@ -138,6 +162,7 @@ pub struct HookOrderIndicates {
   pub order_by_resolve_dynamic_import_meta: Vec<PluginIdx>,
   pub order_by_load_meta: Vec<PluginIdx>,
   pub order_by_transform_meta: Vec<PluginIdx>,
+  pub order_by_should_transform_cached_module_meta: Vec<PluginIdx>,
   pub order_by_module_parsed_meta: Vec<PluginIdx>,
   pub order_by_build_end_meta: Vec<PluginIdx>,
   pub order_by_render_start_meta: Vec<PluginIdx>,
@@ -145,6 +170,9 @@ pub struct HookOrderIndicates {
   pub order_by_footer_meta: Vec<PluginIdx>,
   pub order_by_intro_meta: Vec<PluginIdx>,
   pub order_by_outro_meta: Vec<PluginIdx>,
+  pub order_by_resolve_import_meta_meta: Vec<PluginIdx>,
+  pub order_by_resolve_file_url_meta: Vec<PluginIdx>,
+  pub order_by_render_dynamic_import_meta: Vec<PluginIdx>,
   pub order_by_render_chunk_meta: Vec<PluginIdx>,
   pub order_by_augment_chunk_hash_meta: Vec<PluginIdx>,
   pub order_by_render_error_meta: Vec<PluginIdx>,
@@ -172,6 +200,10 @@ impl HookOrderIndicates {
       order_by_transform_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_transform_meta()
       }),
+      order_by_should_transform_cached_module_meta: Self::sort_plugins_by_hook_meta(
+        index_plugins,
+        |p| p.call_should_transform_cached_module_meta(),
+      ),
       order_by_module_parsed_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_module_parsed_meta()
       }),
@@ -189,6 +221,15 @@ impl HookOrderIndicates {
       }),
       order_by_intro_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| p.call_intro_meta()),
       order_by_outro_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| p.call_outro_meta()),
+      order_by_resolve_import_meta_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_resolve_import_meta_meta()
+      }),
+      order_by_resolve_file_url_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_resolve_file_url_meta()
+      }),
+      order_by_render_dynamic_import_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
+        p.call_render_dynamic_import_meta()
+      }),
       order_by_render_chunk_meta: Self::sort_plugins_by_hook_meta(index_plugins, |p| {
         p.call_render_chunk_meta()
       }),
@@ -219,7 +260,7 @@ impl HookOrderIndicates {
     }
   }
 
-  fn sort_plugins_by_hook_meta(
+  pub(crate) fn sort_plugins_by_hook_meta(
     index_plugins: &IndexPluginable,
     get_hook_meta: impl Fn(&SharedPluginable) -> Option<PluginHookMeta>,
   ) -> Vec<PluginIdx> {
@@ -244,3 +285,28 @@ impl HookOrderIndicates {
     pre_plugins
   }
 }
+
+/// Filters declared through [PluginHookMeta::filter], collected once so they don't need to be
+/// recomputed for every module.
+pub struct HookFilterIndicates {
+  pub resolve_id: IndexVec<PluginIdx, Option<HookFilter>>,
+  pub load: IndexVec<PluginIdx, Option<HookFilter>>,
+  pub transform: IndexVec<PluginIdx, Option<HookFilter>>,
+}
+
+impl HookFilterIndicates {
+  pub fn new(index_plugins: &IndexPluginable) -> Self {
+    Self {
+      resolve_id: Self::collect_hook_filters(index_plugins, |p| p.call_resolve_id_meta()),
+      load: Self::collect_hook_filters(index_plugins, |p| p.call_load_meta()),
+      transform: Self::collect_hook_filters(index_plugins, |p| p.call_transform_meta()),
+    }
+  }
+
+  fn collect_hook_filters(
+    index_plugins: &IndexPluginable,
+    get_hook_meta: impl Fn(&SharedPluginable) -> Option<PluginHookMeta>,
+  ) -> IndexVec<PluginIdx, Option<HookFilter>> {
+    index_plugins.iter().map(|plugin| get_hook_meta(plugin).and_then(|meta| meta.filter)).collect()
+  }
+}
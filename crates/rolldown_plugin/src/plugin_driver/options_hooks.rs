@@ -0,0 +1,41 @@
+use rolldown_common::BundlerOptions;
+
+use crate::{__inner::SharedPluginable, type_aliases::IndexPluginable, PluginDriver};
+
+use super::HookOrderIndicates;
+
+impl PluginDriver {
+  /// Runs `options` and then `output_options` hooks of `plugins` against the raw options.
+  ///
+  /// This is an associated function rather than a method, because the options need to be settled
+  /// before the plugin driver, which holds the normalized options, could be created. If a hook
+  /// fails, `options` keeps the value returned by the last succeeded hook. Returns whether any hook
+  /// replaced the options.
+  pub async fn options(
+    plugins: &[SharedPluginable],
+    options: &mut BundlerOptions,
+  ) -> anyhow::Result<bool> {
+    let index_plugins = IndexPluginable::from_vec(plugins.to_vec());
+    let mut is_replaced = false;
+
+    for plugin_idx in
+      HookOrderIndicates::sort_plugins_by_hook_meta(&index_plugins, |p| p.call_options_meta())
+    {
+      if let Some(replaced) = index_plugins[plugin_idx].call_options(options).await? {
+        *options = replaced;
+        is_replaced = true;
+      }
+    }
+
+    for plugin_idx in HookOrderIndicates::sort_plugins_by_hook_meta(&index_plugins, |p| {
+      p.call_output_options_meta()
+    }) {
+      if let Some(replaced) = index_plugins[plugin_idx].call_output_options(options).await? {
+        *options = replaced;
+        is_replaced = true;
+      }
+    }
+
+    Ok(is_replaced)
+  }
+}
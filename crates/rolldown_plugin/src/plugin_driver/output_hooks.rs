@@ -1,6 +1,10 @@
 use crate::types::hook_render_error::HookRenderErrorArgs;
 use crate::{HookAddonArgs, PluginDriver};
-use crate::{HookAugmentChunkHashReturn, HookNoopReturn, HookRenderChunkArgs};
+use crate::{
+  HookAugmentChunkHashReturn, HookInjectionOutputReturn, HookNoopReturn, HookRenderChunkArgs,
+  HookRenderDynamicImportArgs, HookRenderDynamicImportReturn, HookResolveFileUrlArgs,
+  HookResolveImportMetaArgs,
+};
 use anyhow::{Ok, Result};
 use rolldown_common::{Output, RollupRenderedChunk, SharedNormalizedBundlerOptions};
 use rolldown_sourcemap::SourceMap;
@@ -74,6 +78,45 @@ impl PluginDriver {
     Ok(Some(outro))
   }
 
+  pub fn resolve_import_meta(
+    &self,
+    args: &HookResolveImportMetaArgs<'_>,
+  ) -> HookInjectionOutputReturn {
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_resolve_import_meta_meta)
+    {
+      if let Some(r) = plugin.call_resolve_import_meta(ctx, args)? {
+        return Ok(Some(r));
+      }
+    }
+    Ok(None)
+  }
+
+  pub fn resolve_file_url(&self, args: &HookResolveFileUrlArgs<'_>) -> HookInjectionOutputReturn {
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_resolve_file_url_meta)
+    {
+      if let Some(r) = plugin.call_resolve_file_url(ctx, args)? {
+        return Ok(Some(r));
+      }
+    }
+    Ok(None)
+  }
+
+  pub fn render_dynamic_import(
+    &self,
+    args: &HookRenderDynamicImportArgs<'_>,
+  ) -> HookRenderDynamicImportReturn {
+    for (_, plugin, ctx) in
+      self.iter_plugin_with_context_by_order(&self.order_by_render_dynamic_import_meta)
+    {
+      if let Some(r) = plugin.call_render_dynamic_import(ctx, args)? {
+        return Ok(Some(r));
+      }
+    }
+    Ok(None)
+  }
+
   pub async fn render_chunk(
     &self,
     mut args: HookRenderChunkArgs<'_>,
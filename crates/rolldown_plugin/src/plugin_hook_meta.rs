@@ -1,10 +1,14 @@
+use crate::HookFilter;
+
 #[derive(Debug, PartialEq, Eq)]
 pub enum PluginOrder {
   Pre,
   Post,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct PluginHookMeta {
   pub order: Option<PluginOrder>,
+  /// Only respected by `resolve_id`, `load` and `transform`. See [HookFilter].
+  pub filter: Option<HookFilter>,
 }
@@ -8,17 +8,21 @@ use crate::{
     hook_write_bundle_args::HookWriteBundleArgs,
   },
   HookAddonArgs, HookBuildEndArgs, HookBuildStartArgs, HookGenerateBundleArgs,
-  HookInjectionOutputReturn, HookLoadArgs, HookRenderChunkArgs, HookRenderStartArgs,
-  HookResolveIdArgs, HookTransformArgs, Plugin, SharedTransformPluginContext,
+  HookInjectionOutputReturn, HookLoadArgs, HookRenderChunkArgs, HookRenderDynamicImportArgs,
+  HookRenderStartArgs, HookResolveFileUrlArgs, HookResolveIdArgs, HookResolveImportMetaArgs,
+  HookShouldTransformCachedModuleArgs, HookTransformArgs, Plugin, SharedTransformPluginContext,
 };
 use anyhow::Ok;
-use rolldown_common::{ModuleInfo, RollupRenderedChunk, WatcherChangeKind};
+use rolldown_common::{BundlerOptions, ModuleInfo, RollupRenderedChunk, WatcherChangeKind};
 
 pub use crate::plugin::HookAugmentChunkHashReturn;
 pub use crate::plugin::HookLoadReturn;
 pub use crate::plugin::HookNoopReturn;
+pub use crate::plugin::HookOptionsReturn;
 pub use crate::plugin::HookRenderChunkReturn;
+pub use crate::plugin::HookRenderDynamicImportReturn;
 pub use crate::plugin::HookResolveIdReturn;
+pub use crate::plugin::HookShouldTransformCachedModuleReturn;
 pub use crate::plugin::HookTransformAstReturn;
 pub use crate::plugin::HookTransformReturn;
 
@@ -34,7 +38,15 @@ pub type SharedPluginable = Arc<dyn Pluginable>;
 pub trait Pluginable: Any + Debug + Send + Sync + 'static {
   fn call_name(&self) -> Cow<'static, str>;
 
-  // The `option` hook consider call at node side.
+  // --- Options hooks ---
+
+  async fn call_options(&self, _options: &BundlerOptions) -> HookOptionsReturn;
+
+  fn call_options_meta(&self) -> Option<PluginHookMeta>;
+
+  async fn call_output_options(&self, _options: &BundlerOptions) -> HookOptionsReturn;
+
+  fn call_output_options_meta(&self) -> Option<PluginHookMeta>;
 
   // --- Build hooks ---
 
@@ -77,6 +89,14 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_transform_meta(&self) -> Option<PluginHookMeta>;
 
+  async fn call_should_transform_cached_module(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookShouldTransformCachedModuleArgs,
+  ) -> HookShouldTransformCachedModuleReturn;
+
+  fn call_should_transform_cached_module_meta(&self) -> Option<PluginHookMeta>;
+
   fn call_transform_ast(
     &self,
     _ctx: &PluginContext,
@@ -143,6 +163,30 @@ pub trait Pluginable: Any + Debug + Send + Sync + 'static {
 
   fn call_outro_meta(&self) -> Option<PluginHookMeta>;
 
+  fn call_resolve_import_meta(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveImportMetaArgs,
+  ) -> HookInjectionOutputReturn;
+
+  fn call_resolve_import_meta_meta(&self) -> Option<PluginHookMeta>;
+
+  fn call_resolve_file_url(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookResolveFileUrlArgs,
+  ) -> HookInjectionOutputReturn;
+
+  fn call_resolve_file_url_meta(&self) -> Option<PluginHookMeta>;
+
+  fn call_render_dynamic_import(
+    &self,
+    _ctx: &PluginContext,
+    _args: &HookRenderDynamicImportArgs,
+  ) -> HookRenderDynamicImportReturn;
+
+  fn call_render_dynamic_import_meta(&self) -> Option<PluginHookMeta>;
+
   async fn call_render_chunk(
     &self,
     _ctx: &PluginContext,
@@ -215,6 +259,22 @@ impl<T: Plugin> Pluginable for T {
     Plugin::name(self)
   }
 
+  async fn call_options(&self, options: &BundlerOptions) -> HookOptionsReturn {
+    Plugin::options(self, options).await
+  }
+
+  fn call_options_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::options_meta(self)
+  }
+
+  async fn call_output_options(&self, options: &BundlerOptions) -> HookOptionsReturn {
+    Plugin::output_options(self, options).await
+  }
+
+  fn call_output_options_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::output_options_meta(self)
+  }
+
   async fn call_build_start(
     &self,
     ctx: &PluginContext,
@@ -272,6 +332,18 @@ impl<T: Plugin> Pluginable for T {
     Plugin::transform_meta(self)
   }
 
+  async fn call_should_transform_cached_module(
+    &self,
+    ctx: &PluginContext,
+    args: &HookShouldTransformCachedModuleArgs,
+  ) -> HookShouldTransformCachedModuleReturn {
+    Plugin::should_transform_cached_module(self, ctx, args).await
+  }
+
+  fn call_should_transform_cached_module_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::should_transform_cached_module_meta(self)
+  }
+
   async fn call_module_parsed(
     &self,
     ctx: &PluginContext,
@@ -356,6 +428,42 @@ impl<T: Plugin> Pluginable for T {
     Plugin::outro_meta(self)
   }
 
+  fn call_resolve_import_meta(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveImportMetaArgs,
+  ) -> HookInjectionOutputReturn {
+    Plugin::resolve_import_meta(self, ctx, args)
+  }
+
+  fn call_resolve_import_meta_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::resolve_import_meta_meta(self)
+  }
+
+  fn call_resolve_file_url(
+    &self,
+    ctx: &PluginContext,
+    args: &HookResolveFileUrlArgs,
+  ) -> HookInjectionOutputReturn {
+    Plugin::resolve_file_url(self, ctx, args)
+  }
+
+  fn call_resolve_file_url_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::resolve_file_url_meta(self)
+  }
+
+  fn call_render_dynamic_import(
+    &self,
+    ctx: &PluginContext,
+    args: &HookRenderDynamicImportArgs,
+  ) -> HookRenderDynamicImportReturn {
+    Plugin::render_dynamic_import(self, ctx, args)
+  }
+
+  fn call_render_dynamic_import_meta(&self) -> Option<PluginHookMeta> {
+    Plugin::render_dynamic_import_meta(self)
+  }
+
   async fn call_render_chunk(
     &self,
     ctx: &PluginContext,
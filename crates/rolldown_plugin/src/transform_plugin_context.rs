@@ -3,7 +3,7 @@ use std::sync::Arc;
 use crate::PluginContext;
 use arcstr::ArcStr;
 use rolldown_sourcemap::{collapse_sourcemaps, SourceMap};
-use rolldown_utils::unique_arc::WeakRef;
+use rolldown_utils::{dashmap::FxDashSet, unique_arc::WeakRef};
 use string_wizard::{MagicString, SourceMapOptions};
 
 #[allow(unused)]
@@ -13,6 +13,8 @@ pub struct TransformPluginContext {
   sourcemap_chain: WeakRef<Vec<SourceMap>>,
   original_code: ArcStr,
   id: ArcStr,
+  /// Watch files added while transforming the module, the transform result depends on them.
+  watch_files: Arc<FxDashSet<ArcStr>>,
 }
 
 impl TransformPluginContext {
@@ -21,8 +23,16 @@ impl TransformPluginContext {
     sourcemap_chain: WeakRef<Vec<SourceMap>>,
     original_code: ArcStr,
     id: ArcStr,
+    watch_files: Arc<FxDashSet<ArcStr>>,
   ) -> Self {
-    Self { inner, sourcemap_chain, original_code, id }
+    Self { inner, sourcemap_chain, original_code, id, watch_files }
+  }
+
+  /// Like `PluginContext::add_watch_file`, but also records the file as a dependency of the
+  /// transformed module, so a change of it invalidates the cached transform result.
+  pub fn add_watch_file(&self, file: &str) {
+    self.inner.add_watch_file(file);
+    self.watch_files.insert(file.into());
   }
 
   pub fn get_combined_sourcemap(&self) -> SourceMap {
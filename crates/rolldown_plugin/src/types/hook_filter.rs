@@ -0,0 +1,85 @@
+use std::path::Path;
+
+use rolldown_common::ModuleType;
+use rolldown_utils::pattern_filter::{self, FilterResult, StringOrRegex};
+use sugar_path::SugarPath;
+
+#[derive(Debug, Default, Clone)]
+pub struct GeneralHookFilter {
+  pub include: Option<Vec<StringOrRegex>>,
+  pub exclude: Option<Vec<StringOrRegex>>,
+}
+
+/// Declares which modules a hook is interested in. [crate::PluginDriver] evaluates it before
+/// dispatching, so plugins whose filter doesn't match are not called at all.
+///
+/// - `id` is tested against the module id (or the specifier for `resolve_id`). String patterns are
+///   globs tested against the id relative to `cwd`, regex patterns are tested against the raw id.
+/// - `module_type` only applies to `transform`.
+/// - `code` only applies to `transform`. String patterns are tested as substrings.
+#[derive(Debug, Default, Clone)]
+pub struct HookFilter {
+  pub id: Option<GeneralHookFilter>,
+  pub module_type: Option<Vec<ModuleType>>,
+  pub code: Option<GeneralHookFilter>,
+}
+
+impl HookFilter {
+  /// Returns `true` if the hook should be called for the given module.
+  ///
+  /// `module_type` and `code` are `None` for hooks that don't know them yet, e.g. `resolve_id`
+  /// and `load`. The related filters are ignored in that case.
+  pub fn matches(
+    &self,
+    id: &str,
+    cwd: &Path,
+    module_type: Option<&ModuleType>,
+    code: Option<&str>,
+  ) -> bool {
+    let mut fallback_ret = match (&self.module_type, module_type) {
+      (Some(module_type_filter), Some(module_type)) => {
+        if module_type_filter.contains(module_type) {
+          return true;
+        }
+        false
+      }
+      _ => true,
+    };
+
+    if let Some(id_filter) = &self.id {
+      let stabilized_path = Path::new(id).relative(cwd);
+      let normalized_id = stabilized_path.to_string_lossy();
+
+      let id_res = pattern_filter::filter(
+        id_filter.exclude.as_deref(),
+        id_filter.include.as_deref(),
+        id,
+        &normalized_id,
+      );
+
+      // It's matched by `exclude` or `include`, early return
+      if let FilterResult::Match(id_res) = id_res {
+        return id_res;
+      }
+
+      fallback_ret = fallback_ret && id_res.inner();
+    }
+
+    if let (Some(code_filter), Some(code)) = (&self.code, code) {
+      let code_res = pattern_filter::filter_code(
+        code_filter.exclude.as_deref(),
+        code_filter.include.as_deref(),
+        code,
+      );
+
+      // It's matched by `exclude` or `include`, early return
+      if let FilterResult::Match(code_res) = code_res {
+        return code_res;
+      }
+
+      fallback_ret = fallback_ret && code_res.inner();
+    }
+
+    fallback_ret
+  }
+}
@@ -1,4 +1,6 @@
-use rolldown_common::{side_effects::HookSideEffects, ModuleType};
+use std::sync::Arc;
+
+use rolldown_common::{side_effects::HookSideEffects, CustomField, ModuleType};
 use rolldown_sourcemap::SourceMap;
 
 #[derive(Debug, Default)]
@@ -7,4 +9,6 @@ pub struct HookLoadOutput {
   pub map: Option<SourceMap>,
   pub side_effects: Option<HookSideEffects>,
   pub module_type: Option<ModuleType>,
+  /// Custom data attached to the module, readable through `ModuleInfo::meta`.
+  pub meta: Option<Arc<CustomField>>,
 }
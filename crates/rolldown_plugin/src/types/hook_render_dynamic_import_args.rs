@@ -0,0 +1,13 @@
+use rolldown_common::OutputFormat;
+
+#[derive(Debug)]
+pub struct HookRenderDynamicImportArgs<'a> {
+  /// The preliminary filename of the chunk that the module belongs to. It may contain hash
+  /// placeholders, which are replaced with the final hashes if they are part of the returned code.
+  pub chunk_id: &'a str,
+  pub format: OutputFormat,
+  pub module_id: &'a str,
+  pub target_module_id: &'a str,
+  /// The rendered specifier, e.g. the relative path of the imported chunk.
+  pub specifier: &'a str,
+}
@@ -0,0 +1,6 @@
+/// The rendered `import(...)` is replaced with `${left}'${specifier}'${right}`.
+#[derive(Debug)]
+pub struct HookRenderDynamicImportOutput {
+  pub left: String,
+  pub right: String,
+}
@@ -0,0 +1,17 @@
+use rolldown_common::OutputFormat;
+
+/// Used for `new URL('./foo.png', import.meta.url)` and `import.meta.ROLLUP_FILE_URL_<referenceId>`.
+#[derive(Debug)]
+pub struct HookResolveFileUrlArgs<'a> {
+  /// The preliminary filename of the chunk that the module belongs to. It may contain hash
+  /// placeholders, which are replaced with the final hashes if they are part of the returned code.
+  pub chunk_id: &'a str,
+  /// The preliminary filename of the referenced file.
+  pub file_name: &'a str,
+  /// The path of the referenced file relative to the chunk. It's what rolldown renders by default.
+  pub relative_path: &'a str,
+  /// The reference id of the emitted file for `import.meta.ROLLUP_FILE_URL_<referenceId>`.
+  pub reference_id: Option<&'a str>,
+  pub format: OutputFormat,
+  pub module_id: &'a str,
+}
@@ -1,8 +1,6 @@
 use std::sync::Arc;
 
-use rolldown_common::ImportKind;
-
-use super::custom_field::CustomField;
+use rolldown_common::{CustomField, ImportKind};
 
 #[derive(Debug)]
 pub struct HookResolveIdArgs<'a> {
@@ -0,0 +1,12 @@
+use rolldown_common::OutputFormat;
+
+#[derive(Debug)]
+pub struct HookResolveImportMetaArgs<'a> {
+  /// The accessed property, e.g. `url` for `import.meta.url`. `None` for a bare `import.meta`.
+  pub property: Option<&'a str>,
+  /// The preliminary filename of the chunk that the module belongs to. It may contain hash
+  /// placeholders, which are replaced with the final hashes if they are part of the returned code.
+  pub chunk_id: &'a str,
+  pub format: OutputFormat,
+  pub module_id: &'a str,
+}
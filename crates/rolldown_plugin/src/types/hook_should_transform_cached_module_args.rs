@@ -0,0 +1,9 @@
+use rolldown_common::ModuleType;
+
+#[derive(Debug)]
+pub struct HookShouldTransformCachedModuleArgs<'a> {
+  pub id: &'a str,
+  /// The cached code after `load` and `transform`. `None` for binary modules, e.g. assets.
+  pub code: Option<&'a str>,
+  pub module_type: &'a ModuleType,
+}
@@ -1,5 +1,7 @@
+use std::sync::Arc;
+
 use rolldown_common::side_effects::HookSideEffects;
-use rolldown_common::ModuleType;
+use rolldown_common::{CustomField, ModuleType};
 use rolldown_sourcemap::SourceMap;
 
 #[derive(Debug, Default)]
@@ -8,4 +10,6 @@ pub struct HookTransformOutput {
   pub map: Option<SourceMap>,
   pub side_effects: Option<HookSideEffects>,
   pub module_type: Option<ModuleType>,
+  /// Replaces the custom data attached to the module by previous `load` or `transform` hooks.
+  pub meta: Option<Arc<CustomField>>,
 }
@@ -1,17 +1,22 @@
-pub mod custom_field;
 pub mod hook_addon_args;
 pub mod hook_build_end_args;
 pub mod hook_build_start_args;
+pub mod hook_filter;
 pub mod hook_generate_bundle_args;
 pub mod hook_load_args;
 pub mod hook_load_output;
 pub mod hook_render_chunk_args;
 pub mod hook_render_chunk_output;
+pub mod hook_render_dynamic_import_args;
+pub mod hook_render_dynamic_import_output;
 pub mod hook_render_error;
 pub mod hook_render_start_args;
+pub mod hook_resolve_file_url_args;
 pub mod hook_resolve_id_args;
 pub mod hook_resolve_id_output;
 pub mod hook_resolve_id_skipped;
+pub mod hook_resolve_import_meta_args;
+pub mod hook_should_transform_cached_module_args;
 pub mod hook_transform_args;
 pub mod hook_transform_ast_args;
 pub mod hook_transform_output;
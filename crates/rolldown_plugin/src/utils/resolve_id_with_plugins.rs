@@ -1,9 +1,8 @@
 use crate::{
-  types::{custom_field::CustomField, hook_resolve_id_skipped::HookResolveIdSkipped},
-  HookResolveIdArgs, PluginDriver,
+  types::hook_resolve_id_skipped::HookResolveIdSkipped, HookResolveIdArgs, PluginDriver,
 };
 use rolldown_common::{
-  is_existing_node_builtin_modules, ImportKind, ModuleDefFormat, ResolvedId,
+  is_existing_node_builtin_modules, CustomField, ImportKind, ModuleDefFormat, ResolvedId,
   SharedNormalizedBundlerOptions,
 };
 use rolldown_resolver::{ResolveError, Resolver};
@@ -8,7 +8,7 @@ use rolldown_common::ModuleType;
 use rolldown_ecmascript::EcmaCompiler;
 
 use oxc::transformer::EnvOptions;
-use rolldown_plugin::Plugin;
+use rolldown_plugin::{HookFilter, Plugin, PluginHookMeta};
 use rolldown_utils::clean_url::clean_url;
 use rolldown_utils::pattern_filter::{self, StringOrRegex};
 use std::borrow::Cow;
@@ -38,7 +38,7 @@ impl Plugin for TransformPlugin {
     ctx: rolldown_plugin::SharedTransformPluginContext,
     args: &rolldown_plugin::HookTransformArgs<'_>,
   ) -> rolldown_plugin::HookTransformReturn {
-    if !self.filter(&ctx, args.id, args.module_type) {
+    if !self.filter(&ctx, args.id) {
       return Ok(None);
     }
     let source_type = {
@@ -118,17 +118,28 @@ impl Plugin for TransformPlugin {
       ..Default::default()
     }))
   }
+
+  fn transform_meta(&self) -> Option<PluginHookMeta> {
+    // Without `include`/`exclude`, only ts/jsx like modules need to be transformed. Let the plugin
+    // driver skip other modules before calling into this plugin.
+    if self.include.is_empty() && self.exclude.is_empty() {
+      return Some(PluginHookMeta {
+        order: None,
+        filter: Some(HookFilter {
+          module_type: Some(vec![ModuleType::Jsx, ModuleType::Tsx, ModuleType::Ts]),
+          ..Default::default()
+        }),
+      });
+    }
+    None
+  }
 }
 
 impl TransformPlugin {
-  fn filter(
-    &self,
-    ctx: &rolldown_plugin::SharedTransformPluginContext,
-    id: &str,
-    module_type: &ModuleType,
-  ) -> bool {
+  fn filter(&self, ctx: &rolldown_plugin::SharedTransformPluginContext, id: &str) -> bool {
     if self.include.is_empty() && self.exclude.is_empty() {
-      return matches!(module_type, ModuleType::Jsx | ModuleType::Tsx | ModuleType::Ts);
+      // Already filtered by module type in `transform_meta`.
+      return true;
     }
     let normalized_path = Path::new(id).relative(ctx.inner.cwd());
     let normalized_id = normalized_path.to_string_lossy();
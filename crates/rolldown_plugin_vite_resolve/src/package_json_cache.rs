@@ -21,6 +21,9 @@ impl PackageJsonCache {
     } else {
       let pkg_json = Arc::new(
         PackageJson::new(oxc_pkg_json.path.clone())
+          .with_name(oxc_pkg_json.name.as_ref())
+          .with_version(oxc_pkg_json.raw_json().get("version"))
+          .with_license(oxc_pkg_json.raw_json().get("license"))
           .with_side_effects(oxc_pkg_json.side_effects.as_ref()),
       );
       self.side_effects_cache.insert(oxc_pkg_json.realpath.clone(), Arc::clone(&pkg_json));
@@ -1,27 +1,156 @@
-use std::borrow::Cow;
+mod wasm_module;
 
-use rolldown_plugin::{HookLoadArgs, HookLoadReturn, Plugin, PluginContext};
+use std::{borrow::Cow, fmt::Write as _, path::Path};
 
+use anyhow::{bail, Context};
+use rolldown_common::{EmittedAsset, ModuleType, OutputFormat, StrOrBytes};
+use rolldown_fs::{FileSystem, OsFileSystem};
+use rolldown_plugin::{HookLoadArgs, HookLoadOutput, HookLoadReturn, Plugin, PluginContext};
+use rolldown_utils::{
+  base64::to_standard_base64, concat_string, ecmascript::is_validate_identifier_name,
+  indexmap::FxIndexMap,
+};
+use wasm_module::WasmModuleInfo;
+
+const DEFAULT_INLINE_LIMIT: usize = 4096;
+
+/// Supports `import { fn } from './lib.wasm'` following the ESM integration proposal for Wasm.
+///
+/// A `.wasm` module is loaded as ESM glue that instantiates it. Its imports become regular imports
+/// of the glue and its exports become real exports, so they're linked like any other module.
+/// Modules smaller than `inline_limit` are inlined as base64, larger ones are emitted as assets and
+/// fetched at runtime. Fetching relies on top-level await, so non-ESM output formats only support
+/// inlined modules, which are instantiated synchronously.
 #[derive(Debug)]
-pub struct WasmFallbackPlugin {}
+pub struct WasmFallbackPlugin<Fs: FileSystem = OsFileSystem> {
+  /// Defaults to 4096 bytes.
+  pub inline_limit: Option<usize>,
+  /// The file system `.wasm` modules are read from.
+  pub fs: Fs,
+}
+
+impl Default for WasmFallbackPlugin {
+  fn default() -> Self {
+    Self { inline_limit: None, fs: OsFileSystem }
+  }
+}
 
-impl Plugin for WasmFallbackPlugin {
+impl<Fs: FileSystem + std::fmt::Debug + 'static> Plugin for WasmFallbackPlugin<Fs> {
   fn name(&self) -> Cow<'static, str> {
     Cow::Borrowed("builtin:wasm-fallback-plugin")
   }
 
   #[allow(clippy::case_sensitive_file_extension_comparisons)]
-  async fn load(&self, _ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
-    if args.id.ends_with(".wasm") {
-      // TODO: Replace the link here after rolldown's document is ready
-      Err(anyhow::anyhow!(
-        "\"ESM integration proposal for Wasm\" is not supported currently.
-        Use plugin-wasm or other community plugins to handle this.
-        Alternatively, you can use `.wasm?init` or `.wasm?url`.
-        See https://vitejs.dev/guide/features.html#webassembly for more details."
-      ))
+  async fn load(&self, ctx: &PluginContext, args: &HookLoadArgs<'_>) -> HookLoadReturn {
+    if !args.id.ends_with(".wasm") {
+      return Ok(None);
+    }
+    let file_path = Path::new(args.id);
+    let bytes = self.fs.read(file_path)?;
+    let info = WasmModuleInfo::parse(&bytes)
+      .with_context(|| format!("Failed to parse WebAssembly module {:?}", args.id))?;
+
+    // Top-level await is only available in ESM output, other formats instantiate synchronously.
+    let is_esm = matches!(ctx.options().format, OutputFormat::Esm);
+    let is_inlined = bytes.len() < self.inline_limit.unwrap_or(DEFAULT_INLINE_LIMIT);
+    let instance_expr = if is_inlined {
+      let bytes_expr = concat_string!(
+        "Uint8Array.from(atob(",
+        quote(&to_standard_base64(&bytes)),
+        "), (c) => c.charCodeAt(0))"
+      );
+      if is_esm {
+        concat_string!(
+          "(await WebAssembly.instantiate(",
+          bytes_expr,
+          ", __rolldown_wasm_imports)).instance"
+        )
+      } else {
+        concat_string!(
+          "new WebAssembly.Instance(new WebAssembly.Module(",
+          bytes_expr,
+          "), __rolldown_wasm_imports)"
+        )
+      }
     } else {
-      Ok(None)
+      if !is_esm {
+        bail!(
+          "WebAssembly modules over the inline limit are fetched with top-level await, which isn't supported with the '{}' output format. Raise `inline_limit` to inline them.",
+          ctx.options().format
+        );
+      }
+      let reference_id = ctx.emit_file(EmittedAsset {
+        name: file_path.file_name().map(|x| x.to_string_lossy().to_string()),
+        original_file_name: None,
+        source: StrOrBytes::Bytes(bytes),
+        file_name: None,
+      });
+      concat_string!(
+        "(await WebAssembly.instantiate(await fetch(import.meta.ROLLUP_FILE_URL_",
+        reference_id,
+        ").then((res) => res.arrayBuffer()), __rolldown_wasm_imports)).instance"
+      )
+    };
+
+    Ok(Some(HookLoadOutput {
+      code: render_glue(&info, &instance_expr),
+      module_type: Some(ModuleType::Js),
+      ..Default::default()
+    }))
+  }
+}
+
+fn render_glue(info: &WasmModuleInfo, instance_expr: &str) -> String {
+  let mut code = String::new();
+
+  let mut import_object: FxIndexMap<&str, Vec<(&str, String)>> = FxIndexMap::default();
+  for (idx, import) in info.imports.iter().enumerate() {
+    let local = format!("__rolldown_wasm_import_{idx}");
+    let _ = writeln!(
+      code,
+      "import {{ {} as {local} }} from {};",
+      module_export_name(&import.name),
+      quote(&import.module)
+    );
+    import_object.entry(import.module.as_str()).or_default().push((import.name.as_str(), local));
+  }
+
+  code.push_str("const __rolldown_wasm_imports = {\n");
+  for (module, names) in &import_object {
+    let _ = writeln!(code, "  {}: {{", quote(module));
+    for (name, local) in names {
+      let _ = writeln!(code, "    {}: {local},", quote(name));
     }
+    code.push_str("  },\n");
+  }
+  code.push_str("};\n");
+
+  let _ = writeln!(code, "const __rolldown_wasm_instance = {instance_expr};");
+
+  for (idx, export) in info.exports.iter().enumerate() {
+    let local = format!("__rolldown_wasm_export_{idx}");
+    // Mutable globals are read once, their later changes aren't reflected in the binding.
+    let value = if export.is_global { ".value" } else { "" };
+    let _ = writeln!(
+      code,
+      "const {local} = __rolldown_wasm_instance.exports[{}]{value};\nexport {{ {local} as {} }};",
+      quote(&export.name),
+      module_export_name(&export.name)
+    );
+  }
+
+  code
+}
+
+fn quote(s: &str) -> String {
+  serde_json::to_string(s).expect("Serializing a string should not fail")
+}
+
+/// Export names of wasm modules aren't necessarily valid identifiers, use string literals for them.
+fn module_export_name(name: &str) -> Cow<'_, str> {
+  if is_validate_identifier_name(name) {
+    Cow::Borrowed(name)
+  } else {
+    Cow::Owned(quote(name))
   }
 }
@@ -0,0 +1,158 @@
+use anyhow::{bail, Context, Result};
+
+const WASM_MAGIC: &[u8] = b"\0asm";
+const IMPORT_SECTION_ID: u8 = 2;
+const EXPORT_SECTION_ID: u8 = 7;
+const GLOBAL_EXTERNAL_KIND: u8 = 0x03;
+
+#[derive(Debug)]
+pub struct WasmImport {
+  /// The module specifier, resolved like a regular import of the `.wasm` file.
+  pub module: String,
+  pub name: String,
+}
+
+#[derive(Debug)]
+pub struct WasmExport {
+  pub name: String,
+  /// Exported globals are unwrapped to their values, following the ESM integration proposal.
+  pub is_global: bool,
+}
+
+/// Imports and exports of a wasm module, which are all that's needed to generate the ESM glue.
+#[derive(Debug, Default)]
+pub struct WasmModuleInfo {
+  pub imports: Vec<WasmImport>,
+  pub exports: Vec<WasmExport>,
+}
+
+impl WasmModuleInfo {
+  /// Reads the import and export sections of a wasm binary. Other sections are skipped without
+  /// validation, `WebAssembly.instantiate` will validate them at runtime.
+  pub fn parse(bytes: &[u8]) -> Result<Self> {
+    let mut reader = Reader { bytes, pos: 0 };
+    if reader.read_bytes(4)? != WASM_MAGIC {
+      bail!("Not a WebAssembly binary");
+    }
+    // version
+    reader.read_bytes(4)?;
+
+    let mut info = Self::default();
+    while !reader.is_eof() {
+      let section_id = reader.read_byte()?;
+      let section_size = reader.read_u32()? as usize;
+      let section = reader.read_bytes(section_size)?;
+      let mut section_reader = Reader { bytes: section, pos: 0 };
+      match section_id {
+        IMPORT_SECTION_ID => {
+          for _ in 0..section_reader.read_u32()? {
+            let module = section_reader.read_name()?;
+            let name = section_reader.read_name()?;
+            section_reader.skip_import_desc()?;
+            info.imports.push(WasmImport { module, name });
+          }
+        }
+        EXPORT_SECTION_ID => {
+          for _ in 0..section_reader.read_u32()? {
+            let name = section_reader.read_name()?;
+            let kind = section_reader.read_byte()?;
+            // index
+            section_reader.read_u32()?;
+            info.exports.push(WasmExport { name, is_global: kind == GLOBAL_EXTERNAL_KIND });
+          }
+        }
+        _ => {}
+      }
+    }
+    Ok(info)
+  }
+}
+
+struct Reader<'a> {
+  bytes: &'a [u8],
+  pos: usize,
+}
+
+impl<'a> Reader<'a> {
+  fn is_eof(&self) -> bool {
+    self.pos >= self.bytes.len()
+  }
+
+  fn read_byte(&mut self) -> Result<u8> {
+    let byte = *self.bytes.get(self.pos).context("Unexpected end of WebAssembly binary")?;
+    self.pos += 1;
+    Ok(byte)
+  }
+
+  fn read_bytes(&mut self, len: usize) -> Result<&'a [u8]> {
+    let end = self.pos.checked_add(len).filter(|end| *end <= self.bytes.len());
+    let end = end.context("Unexpected end of WebAssembly binary")?;
+    let bytes = &self.bytes[self.pos..end];
+    self.pos = end;
+    Ok(bytes)
+  }
+
+  /// Reads an unsigned LEB128 integer.
+  fn read_u64(&mut self) -> Result<u64> {
+    let mut result = 0u64;
+    let mut shift = 0;
+    loop {
+      let byte = self.read_byte()?;
+      if shift >= 64 {
+        bail!("Invalid LEB128 integer in WebAssembly binary");
+      }
+      result |= u64::from(byte & 0x7f) << shift;
+      if byte & 0x80 == 0 {
+        return Ok(result);
+      }
+      shift += 7;
+    }
+  }
+
+  fn read_u32(&mut self) -> Result<u32> {
+    u32::try_from(self.read_u64()?).context("Invalid u32 in WebAssembly binary")
+  }
+
+  fn read_name(&mut self) -> Result<String> {
+    let len = self.read_u32()? as usize;
+    let bytes = self.read_bytes(len)?;
+    String::from_utf8(bytes.to_vec()).context("Invalid UTF-8 name in WebAssembly binary")
+  }
+
+  fn skip_limits(&mut self) -> Result<()> {
+    let flags = self.read_byte()?;
+    self.read_u64()?;
+    if flags & 0x01 != 0 {
+      self.read_u64()?;
+    }
+    Ok(())
+  }
+
+  fn skip_import_desc(&mut self) -> Result<()> {
+    match self.read_byte()? {
+      // func: type index
+      0x00 => {
+        self.read_u32()?;
+      }
+      // table: ref type and limits
+      0x01 => {
+        self.read_byte()?;
+        self.skip_limits()?;
+      }
+      // memory: limits
+      0x02 => self.skip_limits()?,
+      // global: value type and mutability
+      0x03 => {
+        self.read_byte()?;
+        self.read_byte()?;
+      }
+      // tag: attribute and type index
+      0x04 => {
+        self.read_byte()?;
+        self.read_u32()?;
+      }
+      kind => bail!("Unknown import kind {kind:#x} in WebAssembly binary"),
+    }
+    Ok(())
+  }
+}
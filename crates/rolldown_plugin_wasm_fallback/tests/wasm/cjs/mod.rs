@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem, OutputFormat};
+
+use rolldown_plugin_wasm_fallback::WasmFallbackPlugin;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+// inlined wasm modules are instantiated synchronously in formats without top-level await
+#[tokio::test(flavor = "multi_thread")]
+async fn cjs() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        format: Some(OutputFormat::Cjs),
+        ..Default::default()
+      },
+      vec![Arc::new(WasmFallbackPlugin::default())],
+    )
+    .await;
+}
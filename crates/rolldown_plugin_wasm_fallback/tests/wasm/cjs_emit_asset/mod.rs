@@ -0,0 +1,31 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem, OutputFormat};
+
+use rolldown_plugin_wasm_fallback::WasmFallbackPlugin;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+// fetching wasm modules needs top-level await, which is only available in esm
+#[tokio::test(flavor = "multi_thread")]
+async fn cjs_emit_asset() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta {
+    expect_error: true,
+    expect_executed: false,
+    ..Default::default()
+  })
+  .run_with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd),
+      format: Some(OutputFormat::Cjs),
+      ..Default::default()
+    },
+    vec![Arc::new(WasmFallbackPlugin { inline_limit: Some(0), ..Default::default() })],
+  )
+  .await;
+}
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem};
+
+use rolldown_plugin_wasm_fallback::WasmFallbackPlugin;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+// wasm modules over the inline limit are emitted as assets
+#[tokio::test(flavor = "multi_thread")]
+async fn emit_asset() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(WasmFallbackPlugin { inline_limit: Some(0), ..Default::default() })],
+    )
+    .await;
+}
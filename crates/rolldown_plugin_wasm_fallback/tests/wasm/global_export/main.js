@@ -0,0 +1,4 @@
+import assert from 'node:assert';
+import { answer } from './answer.wasm';
+
+assert.strictEqual(answer, 42);
@@ -0,0 +1,4 @@
+import assert from 'node:assert';
+import { add } from './add.wasm';
+
+assert.strictEqual(add(1, 2), 13);
@@ -0,0 +1,26 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem};
+
+use rolldown_plugin_wasm_fallback::WasmFallbackPlugin;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+// small wasm modules are inlined and linked with their imports
+#[tokio::test(flavor = "multi_thread")]
+async fn inline() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta::default())
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        ..Default::default()
+      },
+      vec![Arc::new(WasmFallbackPlugin::default())],
+    )
+    .await;
+}
@@ -0,0 +1,3 @@
+import { sub } from './add.wasm';
+
+console.log(sub(1, 2));
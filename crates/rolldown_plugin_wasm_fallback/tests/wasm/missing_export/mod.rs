@@ -0,0 +1,30 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem};
+
+use rolldown_plugin_wasm_fallback::WasmFallbackPlugin;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+// exports of wasm modules are checked by the linker
+#[tokio::test(flavor = "multi_thread")]
+async fn missing_export() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta {
+    expect_error: true,
+    expect_executed: false,
+    ..Default::default()
+  })
+  .run_with_plugins(
+    BundlerOptions {
+      input: Some(vec![InputItem {
+        name: Some("main".to_string()),
+        import: "./main.js".to_string(),
+      }]),
+      cwd: Some(cwd),
+      ..Default::default()
+    },
+    vec![Arc::new(WasmFallbackPlugin::default())],
+  )
+  .await;
+}
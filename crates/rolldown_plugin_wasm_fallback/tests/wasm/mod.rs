@@ -0,0 +1,7 @@
+mod cjs;
+mod cjs_emit_asset;
+mod emit_asset;
+mod global_export;
+mod inline;
+mod missing_export;
+mod nested_chunk;
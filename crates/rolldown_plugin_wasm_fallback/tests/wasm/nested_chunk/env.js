@@ -0,0 +1,3 @@
+export function offset() {
+  return 10;
+}
@@ -0,0 +1,5 @@
+import { add } from './add.wasm';
+
+export function run() {
+  console.log(add(1, 2));
+}
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+
+use rolldown::{BundlerOptions, InputItem};
+
+use rolldown_plugin_wasm_fallback::WasmFallbackPlugin;
+use rolldown_testing::{abs_file_dir, integration_test::IntegrationTest, test_config::TestMeta};
+
+// urls of emitted wasm modules are relative to the chunk importing them
+#[tokio::test(flavor = "multi_thread")]
+async fn nested_chunk() {
+  let cwd = abs_file_dir!();
+
+  IntegrationTest::new(TestMeta { expect_executed: false, ..Default::default() })
+    .run_with_plugins(
+      BundlerOptions {
+        input: Some(vec![InputItem {
+          name: Some("main".to_string()),
+          import: "./main.js".to_string(),
+        }]),
+        cwd: Some(cwd),
+        chunk_filenames: Some("chunks/[name].js".to_string().into()),
+        ..Default::default()
+      },
+      vec![Arc::new(WasmFallbackPlugin { inline_limit: Some(0), ..Default::default() })],
+    )
+    .await;
+}
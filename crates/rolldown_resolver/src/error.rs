@@ -1,4 +1,11 @@
+use std::path::Path;
+
 use oxc_resolver::ResolveError;
+use rolldown_error::ResolveErrorContext;
+use rolldown_fs::FileSystem;
+use sugar_path::SugarPath;
+
+const MAX_NEAR_MISSES: usize = 3;
 
 /// rewrite error reason and ignore path param
 /// Note this is just the error message fallback, for better dx,
@@ -37,6 +44,140 @@ pub fn oxc_resolve_error_to_reason(e: &ResolveError) -> String {
     ResolveError::Unimplemented(_) => "Unimplemented".to_string(),
     ResolveError::Recursion => "Recursion in resolving".to_string(),
     ResolveError::MatchedAliasNotFound(_, _) => "Matched alias not found".to_string(),
-    _ => todo!(),
+    // `ResolveError` is non-exhaustive, fall back to its own message for new variants.
+    _ => e.to_string(),
+  }
+}
+
+/// Collects hints that help users fix the failed resolution of `specifier` imported by `importer`.
+pub fn resolve_error_context(
+  fs: &dyn FileSystem,
+  e: &ResolveError,
+  importer: &Path,
+  specifier: &str,
+) -> Option<ResolveErrorContext> {
+  match e {
+    ResolveError::NotFound(_) => {
+      let near_misses = find_near_misses(fs, importer, specifier);
+      (!near_misses.is_empty()).then_some(ResolveErrorContext::NearMisses(near_misses))
+    }
+    ResolveError::PackagePathNotExported(_, package_json_path) => {
+      let content = fs.read_to_string(package_json_path).ok()?;
+      let package_json: serde_json::Value = serde_json::from_str(&content).ok()?;
+      let package_name = package_json.get("name")?.as_str()?.to_string();
+      let subpaths = match package_json.get("exports")? {
+        serde_json::Value::Object(exports) if exports.keys().any(|key| key.starts_with('.')) => {
+          exports.keys().filter(|key| key.starts_with('.')).cloned().collect()
+        }
+        // `"exports": "./index.js"`, or an object of conditions, only exports the main entry
+        _ => vec![".".to_string()],
+      };
+      Some(ResolveErrorContext::ExportedSubpaths { package_name, subpaths })
+    }
+    _ => None,
+  }
+}
+
+/// Finds files next to the requested one that have the same name in a different casing, or with a
+/// missing or different extension, e.g. `./Foo.js`, `./foo.mjs` or `./foo.ts` for `./foo.js`. If
+/// the requested path is a directory, its index files are suggested, e.g. `./foo/index.ts`.
+fn find_near_misses(fs: &dyn FileSystem, importer: &Path, specifier: &str) -> Vec<String> {
+  let is_relative = specifier.starts_with("./") || specifier.starts_with("../");
+  if !is_relative && !Path::new(specifier).is_absolute() {
+    return vec![];
+  }
+  let (dir_part, requested) = specifier.rsplit_once('/').unwrap_or(("", specifier));
+  if requested.is_empty() {
+    return vec![];
+  }
+  let dir = if is_relative {
+    importer.parent().map(|importer_dir| importer_dir.join(dir_part).normalize())
+  } else {
+    Some(Path::new(dir_part).to_path_buf())
+  };
+  let Some(dir) = dir else {
+    return vec![];
+  };
+  let file_names = |dir: &Path| {
+    fs.read_dir(dir)
+      .unwrap_or_default()
+      .into_iter()
+      .filter(|path| !fs.is_dir(path))
+      .filter_map(|path| path.file_name().and_then(|name| name.to_str()).map(ToString::to_string))
+  };
+
+  let requested_stem = Path::new(requested).file_stem().and_then(|stem| stem.to_str());
+  let mut near_misses = file_names(&dir)
+    .filter(|name| {
+      if name == requested {
+        return false;
+      }
+      let Some(stem) = Path::new(name).file_stem().and_then(|stem| stem.to_str()) else {
+        return false;
+      };
+      stem.eq_ignore_ascii_case(requested)
+        || requested_stem.is_some_and(|requested_stem| stem.eq_ignore_ascii_case(requested_stem))
+    })
+    .map(|name| format!("{dir_part}/{name}"))
+    .collect::<Vec<_>>();
+  let requested_dir = dir.join(requested);
+  if fs.is_dir(&requested_dir) {
+    near_misses.extend(
+      file_names(&requested_dir)
+        .filter(|name| Path::new(name).file_stem().is_some_and(|stem| stem == "index"))
+        .map(|name| format!("{specifier}/{name}")),
+    );
+  }
+  near_misses.sort_unstable();
+  near_misses.truncate(MAX_NEAR_MISSES);
+  near_misses
+}
+
+#[cfg(test)]
+mod tests {
+  use std::path::Path;
+
+  use rolldown_fs::MemoryFileSystem;
+
+  use super::find_near_misses;
+
+  fn memory_fs(files: &[&str]) -> MemoryFileSystem {
+    let mut fs = MemoryFileSystem::default();
+    for file in files {
+      fs.add_file(Path::new(file), "");
+    }
+    fs
+  }
+
+  #[test]
+  fn different_extension() {
+    let fs = memory_fs(&["/src/main.js", "/src/foo.ts", "/src/foo.mjs", "/src/bar.ts"]);
+    let importer = Path::new("/src/main.js");
+    assert_eq!(find_near_misses(&fs, importer, "./foo.js"), ["./foo.mjs", "./foo.ts"]);
+    assert_eq!(find_near_misses(&fs, importer, "./foo"), ["./foo.mjs", "./foo.ts"]);
+    assert!(find_near_misses(&fs, importer, "./baz.js").is_empty());
+  }
+
+  #[test]
+  fn different_case() {
+    let fs = memory_fs(&["/src/main.js", "/src/components/Button.js"]);
+    let importer = Path::new("/src/main.js");
+    assert_eq!(
+      find_near_misses(&fs, importer, "./components/button.js"),
+      ["./components/Button.js"]
+    );
+    assert_eq!(
+      find_near_misses(&fs, importer, "/src/components/BUTTON"),
+      ["/src/components/Button.js"]
+    );
+  }
+
+  #[test]
+  fn index_file() {
+    let fs = memory_fs(&["/src/main.js", "/src/utils/index.ts", "/src/utils/helper.ts"]);
+    let importer = Path::new("/src/main.js");
+    assert_eq!(find_near_misses(&fs, importer, "./utils"), ["./utils/index.ts"]);
+    // Bare specifiers are resolved from `node_modules`, near misses don't apply.
+    assert!(find_near_misses(&fs, importer, "utils").is_empty());
   }
 }
@@ -29,6 +29,7 @@ pub struct Resolver<T: FileSystem + Default = OsFileSystem> {
   // Resolver for `new URL(..., import.meta.url)`
   new_url_resolver: ResolverGeneric<T>,
   package_json_cache: FxDashMap<PathBuf, Arc<PackageJson>>,
+  dedupe: Vec<String>,
 }
 
 impl<F: FileSystem + Default> Resolver<F> {
@@ -72,6 +73,8 @@ impl<F: FileSystem + Default> Resolver<F> {
       Platform::Browser | Platform::Neutral => false,
     };
 
+    let dedupe = raw_resolve.dedupe.clone().unwrap_or_default();
+
     let mut extension_alias = raw_resolve.extension_alias.clone().unwrap_or_default();
     impl_rewritten_file_extensions_via_extension_alias(&mut extension_alias);
 
@@ -155,6 +158,7 @@ impl<F: FileSystem + Default> Resolver<F> {
       css_resolver,
       new_url_resolver,
       package_json_cache: DashMap::default(),
+      dedupe,
     }
   }
 
@@ -194,7 +198,13 @@ impl<F: FileSystem + Default> Resolver<F> {
       }
     });
 
-    let context_dir = importer_dir.unwrap_or(self.cwd.as_path());
+    // Deduped packages are resolved as if they were imported from `cwd`, so every importer gets the
+    // same copy.
+    let context_dir = if self.dedupe.iter().any(|name| name == package_name_of(specifier)) {
+      self.cwd.as_path()
+    } else {
+      importer_dir.unwrap_or(self.cwd.as_path())
+    };
 
     let mut resolution = selected_resolver.resolve(context_dir, specifier);
 
@@ -234,6 +244,9 @@ impl<F: FileSystem + Default> Resolver<F> {
     } else {
       let pkg_json = Arc::new(
         PackageJson::new(oxc_pkg_json.path.clone())
+          .with_name(oxc_pkg_json.name.as_ref())
+          .with_version(oxc_pkg_json.raw_json().get("version"))
+          .with_license(oxc_pkg_json.raw_json().get("license"))
           .with_type(oxc_pkg_json.r#type.as_ref())
           .with_side_effects(oxc_pkg_json.side_effects.as_ref()),
       );
@@ -243,6 +256,13 @@ impl<F: FileSystem + Default> Resolver<F> {
   }
 }
 
+/// Returns `@scope/pkg` for `@scope/pkg/sub/path` and `pkg` for `pkg/sub/path`.
+fn package_name_of(specifier: &str) -> &str {
+  let name_segments = if specifier.starts_with('@') { 2 } else { 1 };
+  let end = specifier.match_indices('/').nth(name_segments - 1).map_or(specifier.len(), |(i, _)| i);
+  &specifier[..end]
+}
+
 /// https://github.com/evanw/esbuild/blob/d34e79e2a998c21bb71d57b92b0017ca11756912/internal/bundler/bundler.go#L1446-L1460
 fn infer_module_def_format(info: &Resolution) -> ModuleDefFormat {
   let fmt = ModuleDefFormat::from_path(info.path());
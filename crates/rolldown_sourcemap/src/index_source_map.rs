@@ -0,0 +1,196 @@
+use std::sync::Arc;
+
+use oxc_sourcemap::{SourceMap, Token};
+use rolldown_utils::base64::to_standard_base64;
+
+/// A source map v3 index map. Each section is a regular source map that applies to the generated
+/// code from its offset on, so the maps of the joined sources can be reused as they are.
+///
+/// See https://tc39.es/source-map/#index-map.
+#[derive(Debug, Clone, Default)]
+pub struct IndexSourceMap {
+  file: Option<String>,
+  debug_id: Option<String>,
+  sections: Vec<IndexSourceMapSection>,
+}
+
+#[derive(Debug, Clone)]
+pub struct IndexSourceMapSection {
+  /// Zero-based line of the generated code where the section starts.
+  pub line: u32,
+  /// Zero-based column of the generated code where the section starts.
+  pub column: u32,
+  pub map: SourceMap,
+}
+
+impl IndexSourceMap {
+  /// `sections` must be sorted by their offsets and must not overlap.
+  pub fn new(sections: Vec<IndexSourceMapSection>) -> Self {
+    Self { file: None, debug_id: None, sections }
+  }
+
+  pub fn get_file(&self) -> Option<&str> {
+    self.file.as_deref()
+  }
+
+  pub fn set_file(&mut self, file: &str) {
+    self.file = Some(file.to_string());
+  }
+
+  pub fn get_debug_id(&self) -> Option<&str> {
+    self.debug_id.as_deref()
+  }
+
+  pub fn set_debug_id(&mut self, debug_id: &str) {
+    self.debug_id = Some(debug_id.to_string());
+  }
+
+  pub fn sections(&self) -> &[IndexSourceMapSection] {
+    &self.sections
+  }
+
+  pub fn sections_mut(&mut self) -> &mut [IndexSourceMapSection] {
+    &mut self.sections
+  }
+
+  pub fn to_json_string(&self) -> String {
+    let mut json = String::from("{\"version\":3,");
+    if let Some(file) = &self.file {
+      json.push_str("\"file\":");
+      json.push_str(&escape_json_string(file));
+      json.push(',');
+    }
+    if let Some(debug_id) = &self.debug_id {
+      json.push_str("\"debugId\":");
+      json.push_str(&escape_json_string(debug_id));
+      json.push(',');
+    }
+    json.push_str("\"sections\":[");
+    for (index, section) in self.sections.iter().enumerate() {
+      if index > 0 {
+        json.push(',');
+      }
+      json.push_str("{\"offset\":{\"line\":");
+      json.push_str(&section.line.to_string());
+      json.push_str(",\"column\":");
+      json.push_str(&section.column.to_string());
+      json.push_str("},\"map\":");
+      json.push_str(&section.map.to_json_string());
+      json.push('}');
+    }
+    json.push_str("]}");
+    json
+  }
+
+  pub fn to_data_url(&self) -> String {
+    let base_64_str = to_standard_base64(self.to_json_string());
+    format!("data:application/json;charset=utf-8;base64,{base_64_str}")
+  }
+}
+
+/// Converts an index map to a regular source map, for consumers that don't support sections.
+/// `x_google_ignoreList` of the sections is not carried over.
+#[allow(clippy::cast_possible_truncation)]
+pub fn flatten_index_sourcemap(index_map: &IndexSourceMap) -> SourceMap {
+  let has_source_contents =
+    index_map.sections.iter().any(|section| section.map.get_source_contents().is_some());
+  let mut names: Vec<Arc<str>> = vec![];
+  let mut sources: Vec<Arc<str>> = vec![];
+  let mut source_contents: Vec<Arc<str>> = vec![];
+  let mut tokens = vec![];
+
+  for section in &index_map.sections {
+    let map = &section.map;
+    let names_offset = names.len() as u32;
+    let sources_offset = sources.len() as u32;
+    names.extend(map.get_names().map(Into::into));
+    sources.extend(map.get_sources().map(Into::into));
+    if has_source_contents {
+      let contents_len = source_contents.len();
+      source_contents.extend(map.get_source_contents().into_iter().flatten().map(Into::into));
+      source_contents.resize(contents_len + map.get_sources().count(), "".into());
+    }
+    tokens.extend(map.get_tokens().map(|token| {
+      let dst_line = token.get_dst_line();
+      let dst_col = token.get_dst_col();
+      Token::new(
+        section.line + dst_line,
+        if dst_line == 0 { section.column + dst_col } else { dst_col },
+        token.get_src_line(),
+        token.get_src_col(),
+        token.get_source_id().map(|id| id + sources_offset),
+        token.get_name_id().map(|id| id + names_offset),
+      )
+    }));
+  }
+
+  let mut map = SourceMap::new(
+    None,
+    names,
+    None,
+    sources,
+    has_source_contents.then_some(source_contents),
+    tokens,
+    None,
+  );
+  if let Some(file) = &index_map.file {
+    map.set_file(file);
+  }
+  if let Some(debug_id) = &index_map.debug_id {
+    map.set_debug_id(debug_id);
+  }
+  map
+}
+
+fn escape_json_string(s: &str) -> String {
+  serde_json::to_string(s).expect("strings are always serializable")
+}
+
+#[test]
+fn test_flatten_index_sourcemap() {
+  use crate::{SourceJoiner, SourceMapSource};
+  use oxc::{
+    allocator::Allocator,
+    codegen::{CodeGenerator, CodegenOptions, CodegenReturn},
+    parser::Parser,
+    span::SourceType,
+  };
+  use oxc_sourcemap::SourcemapVisualizer;
+
+  let allocator = Allocator::default();
+  let mut source_joiner = SourceJoiner::default();
+  source_joiner.prepend_source(Box::new("// banner".to_string()));
+  for (filename, source_text) in
+    [("foo.js", "const foo = 1; console.log(foo);\n"), ("bar.js", "const bar = 2;\n")]
+  {
+    let source_type = SourceType::from_path(filename).unwrap();
+    let ret = Parser::new(&allocator, source_text, source_type).parse();
+    let CodegenReturn { map, code, .. } = CodeGenerator::new()
+      .with_options(CodegenOptions {
+        source_map_path: Some(filename.into()),
+        ..CodegenOptions::default()
+      })
+      .build(&ret.program);
+    source_joiner.append_source(SourceMapSource::new(code, map.unwrap()));
+  }
+
+  let (content, index_map) = source_joiner.join_index_map();
+  let mut index_map = index_map.unwrap();
+  index_map.set_file("chunk.js");
+  assert_eq!(
+    index_map.sections().iter().map(|section| (section.line, section.column)).collect::<Vec<_>>(),
+    [(1, 0), (4, 0)]
+  );
+  assert!(index_map.to_json_string().starts_with(
+    r#"{"version":3,"file":"chunk.js","sections":[{"offset":{"line":1,"column":0},"map":{"#
+  ));
+
+  let (joined_content, joined_map) = source_joiner.join();
+  assert_eq!(content, joined_content);
+  let flattened = flatten_index_sourcemap(&index_map);
+  assert_eq!(flattened.get_file(), Some("chunk.js"));
+  assert_eq!(
+    SourcemapVisualizer::new(&content, &flattened).into_visualizer_text(),
+    SourcemapVisualizer::new(&content, &joined_map.unwrap()).into_visualizer_text()
+  );
+}
@@ -1,3 +1,4 @@
+mod index_source_map;
 mod source;
 mod source_joiner;
 
@@ -5,6 +6,7 @@ use oxc_sourcemap::Token;
 use rolldown_utils::rayon::{IntoParallelRefIterator, ParallelIterator};
 use rustc_hash::FxHashMap;
 
+pub use index_source_map::{flatten_index_sourcemap, IndexSourceMap, IndexSourceMapSection};
 pub use oxc_sourcemap::SourceMapBuilder;
 pub use oxc_sourcemap::{JSONSourceMap, SourceMap, SourcemapVisualizer};
 pub use source_joiner::SourceJoiner;
@@ -73,6 +75,30 @@ pub fn collapse_sourcemaps(mut sourcemap_chain: Vec<&SourceMap>) -> SourceMap {
   )
 }
 
+/// Keeps one mapping per generated line, from its start to the start of the original line of its
+/// first mapping. Names are dropped.
+pub fn lines_only_sourcemap(sourcemap: &SourceMap) -> SourceMap {
+  let mut last_dst_line = None;
+  let tokens = sourcemap
+    .get_tokens()
+    .filter(|token| token.get_source_id().is_some())
+    .filter(|token| last_dst_line.replace(token.get_dst_line()) != Some(token.get_dst_line()))
+    .map(|token| {
+      Token::new(token.get_dst_line(), 0, token.get_src_line(), 0, token.get_source_id(), None)
+    })
+    .collect::<Vec<_>>();
+
+  SourceMap::new(
+    None,
+    vec![],
+    None,
+    sourcemap.get_sources().map(Into::into).collect::<Vec<_>>(),
+    sourcemap.get_source_contents().map(|x| x.map(Into::into).collect::<Vec<_>>()),
+    tokens,
+    None,
+  )
+}
+
 #[test]
 fn test_collapse_sourcemaps() {
   use crate::{collapse_sourcemaps, SourceJoiner, SourceMapSource};
@@ -149,3 +175,35 @@ fn test_collapse_sourcemaps() {
 "#
   );
 }
+
+#[test]
+fn test_lines_only_sourcemap() {
+  use oxc::{
+    allocator::Allocator,
+    codegen::{CodeGenerator, CodegenOptions, CodegenReturn},
+    parser::Parser,
+    span::SourceType,
+  };
+  use oxc_sourcemap::SourcemapVisualizer;
+
+  let allocator = Allocator::default();
+  let source_text = "const foo = 1; console.log(foo);\nfoo += 1;\n";
+  let ret = Parser::new(&allocator, source_text, SourceType::mjs()).parse();
+  let CodegenReturn { map, code, .. } = CodeGenerator::new()
+    .with_options(CodegenOptions {
+      source_map_path: Some("foo.js".into()),
+      ..CodegenOptions::default()
+    })
+    .build(&ret.program);
+
+  let map = lines_only_sourcemap(&map.unwrap());
+  assert_eq!(map.get_names().count(), 0);
+  assert_eq!(
+    SourcemapVisualizer::new(&code, &map).into_visualizer_text(),
+    r#"- foo.js
+(0:0) "const foo = 1; console.log(foo);\n" --> (0:0) "const foo = 1;\n"
+(0:0) "const foo = 1; console.log(foo);\n" --> (1:0) "console.log(foo);\n"
+(1:0) "foo += 1;\n" --> (2:0) "foo += 1;\n"
+"#
+  );
+}
@@ -1,6 +1,9 @@
 use oxc_sourcemap::{ConcatSourceMapBuilder, SourceMap};
 
-use crate::source::Source;
+use crate::{
+  index_source_map::{IndexSourceMap, IndexSourceMapSection},
+  source::Source,
+};
 
 #[derive(Default)]
 pub struct SourceJoiner<'source> {
@@ -61,6 +64,31 @@ impl<'source> SourceJoiner<'source> {
     (ret_source, sourcemap_builder.map(ConcatSourceMapBuilder::into_sourcemap))
   }
 
+  /// Like [`Self::join`], but emits an index map with one section per source that has a map. The
+  /// maps are reused as they are instead of being merged into one token table.
+  pub fn join_index_map(&self) -> (String, Option<IndexSourceMap>) {
+    let sources_len = self.prepend_source.len() + self.inner.len();
+    let sources_iter = self.prepend_source.iter().chain(self.inner.iter()).enumerate();
+
+    let size_hint_of_ret_source =
+      sources_iter.clone().map(|(_idx, source)| source.content().len() + 1).sum::<usize>();
+    let mut ret_source = String::with_capacity(size_hint_of_ret_source);
+
+    let mut line_offset = 0;
+    let mut sections = self.enable_sourcemap.then(|| Vec::with_capacity(self.token_chunks_len));
+    for (index, source) in sources_iter {
+      if let (Some(sections), Some(map)) = (&mut sections, source.sourcemap()) {
+        sections.push(IndexSourceMapSection { line: line_offset, column: 0, map: map.clone() });
+      }
+      ret_source.push_str(source.content());
+      if index < sources_len - 1 {
+        ret_source.push('\n');
+        line_offset += source.lines_count() + 1; // +1 for the newline
+      }
+    }
+    (ret_source, sections.map(IndexSourceMap::new))
+  }
+
   fn accumulate_sourcemap_data_size(&mut self, hint: &SourceMap) {
     self.enable_sourcemap = true;
     self.names_len += hint.get_names().count();
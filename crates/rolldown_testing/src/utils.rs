@@ -83,6 +83,33 @@ pub fn stringify_bundle_output(output: BundleOutput, cwd: &Path) -> String {
   ret
 }
 
+/// Where name cache tests keep their cache, relative to `cwd`. `write` updates the cache, so the
+/// tests use a copy in `dist` rather than the checked in `name-cache.json`.
+pub const NAME_CACHE_COPY: &str = "dist/name-cache.json";
+
+/// Bundles with a name cache at [`NAME_CACHE_COPY`], seeded from `name-cache.json` in `cwd`. Asserts
+/// that `generate` leaves the cache untouched, then returns the code of the first asset and the
+/// cache updated by `write`.
+pub async fn bundle_with_name_cache(
+  cwd: &Path,
+  options: BundlerOptions,
+) -> (String, serde_json::Value) {
+  let cache_path = cwd.join(NAME_CACHE_COPY);
+  std::fs::create_dir_all(cache_path.parent().unwrap()).unwrap();
+  std::fs::copy(cwd.join("name-cache.json"), &cache_path).unwrap();
+  let original_cache = std::fs::read_to_string(&cache_path).unwrap();
+
+  let mut bundler = rolldown::Bundler::new(options);
+
+  bundler.generate().await.unwrap();
+  assert_eq!(std::fs::read_to_string(&cache_path).unwrap(), original_cache);
+
+  let output = bundler.write().await.unwrap();
+  let code = String::from_utf8(output.assets[0].content_as_bytes().to_vec()).unwrap();
+  let cache = serde_json::from_str(&std::fs::read_to_string(&cache_path).unwrap()).unwrap();
+  (code, cache)
+}
+
 pub(crate) static RUNTIME_MODULE_OUTPUT_RE: LazyLock<Regex> = LazyLock::new(|| {
   Regex::new(r"(//#region rolldown:runtime[\s\S]*?//#endregion)")
     .expect("invalid runtime module output regex")
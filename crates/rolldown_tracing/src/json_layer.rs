@@ -0,0 +1,193 @@
+use std::{
+  fmt,
+  io::Write,
+  sync::Mutex,
+  time::{Instant, SystemTime, UNIX_EPOCH},
+};
+
+use serde_json::{json, Map, Value};
+use tracing::{
+  field::{Field, Visit},
+  span, Event, Subscriber,
+};
+use tracing_subscriber::{layer::Context, registry::LookupSpan, Layer};
+
+/// Writes spans and events as newline-delimited json. Every line is a standalone json object:
+///
+/// - `{"type":"span","name":..,"target":..,"fields":{..},"time_us":..,"duration_us":..}` is written
+///   when a span is closed. `time_us` is the time the span was created.
+/// - `{"type":"event","level":..,"target":..,"span":..,"fields":{..},"time_us":..}` is written for
+///   each event. `span` is the name of the span the event happened in.
+///
+/// `time_us` is microseconds since the unix epoch.
+pub struct JsonLayer<W: Write> {
+  writer: Mutex<W>,
+}
+
+impl<W: Write> JsonLayer<W> {
+  pub fn new(writer: W) -> Self {
+    Self { writer: Mutex::new(writer) }
+  }
+
+  fn write_line(&self, value: &Value) {
+    let Ok(mut writer) = self.writer.lock() else {
+      return;
+    };
+    // Failing to write traces shouldn't affect the build.
+    let _ = serde_json::to_writer(&mut *writer, value);
+    let _ = writer.write_all(b"\n");
+  }
+}
+
+struct SpanData {
+  fields: Map<String, Value>,
+  time_us: u64,
+  start: Instant,
+}
+
+struct JsonVisitor<'a>(&'a mut Map<String, Value>);
+
+impl Visit for JsonVisitor<'_> {
+  fn record_f64(&mut self, field: &Field, value: f64) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_i64(&mut self, field: &Field, value: i64) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_u64(&mut self, field: &Field, value: u64) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_bool(&mut self, field: &Field, value: bool) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_str(&mut self, field: &Field, value: &str) {
+    self.0.insert(field.name().to_string(), json!(value));
+  }
+
+  fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+    self.0.insert(field.name().to_string(), json!(format!("{value:?}")));
+  }
+}
+
+fn now_us() -> u64 {
+  SystemTime::now()
+    .duration_since(UNIX_EPOCH)
+    .map_or(0, |d| u64::try_from(d.as_micros()).unwrap_or(u64::MAX))
+}
+
+impl<S, W> Layer<S> for JsonLayer<W>
+where
+  S: Subscriber + for<'a> LookupSpan<'a>,
+  W: Write + 'static,
+{
+  fn on_new_span(&self, attrs: &span::Attributes<'_>, id: &span::Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else {
+      return;
+    };
+    let mut fields = Map::new();
+    attrs.record(&mut JsonVisitor(&mut fields));
+    span.extensions_mut().insert(SpanData { fields, time_us: now_us(), start: Instant::now() });
+  }
+
+  fn on_record(&self, id: &span::Id, values: &span::Record<'_>, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(id) else {
+      return;
+    };
+    let mut extensions = span.extensions_mut();
+    if let Some(data) = extensions.get_mut::<SpanData>() {
+      values.record(&mut JsonVisitor(&mut data.fields));
+    }
+  }
+
+  fn on_event(&self, event: &Event<'_>, ctx: Context<'_, S>) {
+    let mut fields = Map::new();
+    event.record(&mut JsonVisitor(&mut fields));
+    let metadata = event.metadata();
+    self.write_line(&json!({
+      "type": "event",
+      "level": metadata.level().as_str(),
+      "target": metadata.target(),
+      "span": ctx.event_span(event).map(|span| span.name()),
+      "fields": fields,
+      "time_us": now_us(),
+    }));
+  }
+
+  fn on_close(&self, id: span::Id, ctx: Context<'_, S>) {
+    let Some(span) = ctx.span(&id) else {
+      return;
+    };
+    let Some(data) = span.extensions_mut().remove::<SpanData>() else {
+      return;
+    };
+    self.write_line(&json!({
+      "type": "span",
+      "name": span.name(),
+      "target": span.metadata().target(),
+      "fields": data.fields,
+      "time_us": data.time_us,
+      "duration_us": u64::try_from(data.start.elapsed().as_micros()).unwrap_or(u64::MAX),
+    }));
+  }
+}
+
+#[cfg(test)]
+mod tests {
+  use std::{
+    io::{self, Write},
+    sync::{Arc, Mutex},
+  };
+
+  use serde_json::Value;
+  use tracing_subscriber::prelude::*;
+
+  use super::JsonLayer;
+
+  #[derive(Clone, Default)]
+  struct SharedBuffer(Arc<Mutex<Vec<u8>>>);
+
+  impl Write for SharedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+      self.0.lock().unwrap().write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+      Ok(())
+    }
+  }
+
+  #[test]
+  fn writes_spans_and_events_as_ndjson() {
+    let buffer = SharedBuffer::default();
+    let subscriber = tracing_subscriber::registry().with(JsonLayer::new(buffer.clone()));
+    tracing::subscriber::with_default(subscriber, || {
+      let span = tracing::info_span!("plugin_hook", plugin = "test", hook = "load");
+      span.in_scope(|| tracing::info!(module_id = "a.js", "loaded"));
+    });
+
+    let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+    let lines =
+      output.lines().map(|line| serde_json::from_str::<Value>(line).unwrap()).collect::<Vec<_>>();
+    assert_eq!(lines.len(), 2);
+
+    let event = &lines[0];
+    assert_eq!(event["type"], "event");
+    assert_eq!(event["level"], "INFO");
+    assert_eq!(event["span"], "plugin_hook");
+    assert_eq!(event["fields"]["module_id"], "a.js");
+    assert_eq!(event["fields"]["message"], "loaded");
+    assert!(event["time_us"].is_u64());
+
+    let span = &lines[1];
+    assert_eq!(span["type"], "span");
+    assert_eq!(span["name"], "plugin_hook");
+    assert_eq!(span["fields"]["plugin"], "test");
+    assert_eq!(span["fields"]["hook"], "load");
+    assert!(span["time_us"].is_u64());
+    assert!(span["duration_us"].is_u64());
+  }
+}
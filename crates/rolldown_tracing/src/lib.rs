@@ -4,7 +4,17 @@
 ///   - https://docs.rs/tracing-subscriber/latest/tracing_subscriber/filter/struct.EnvFilter.html#directives
 /// - Using `RD_LOG=trace RD_LOG_OUTPUT=chrome-json` to collect tracing events into a json file.
 ///   - Using `RD_LOG_OUTPUT_STYLE=async` to record traces as a group of asynchronous operations.
+/// - Using `RD_LOG=trace RD_LOG_OUTPUT=json` to write newline-delimited json into `./trace-<timestamp>.ndjson`.
+///   - Spans of plugin hooks are named `plugin_hook` and carry `plugin`, `hook` and `module_id`.
+///   - `transform` hooks that change the code emit a `transformed` event with `before` and `after`.
+mod json_layer;
+
+use std::fs::File;
+use std::io::{LineWriter, Write};
 use std::sync::atomic::AtomicBool;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use json_layer::JsonLayer;
 
 use tracing_chrome::ChromeLayerBuilder;
 use tracing_chrome::FlushGuard;
@@ -41,8 +51,19 @@ pub fn try_init_tracing() -> Option<FlushGuard> {
       Some(guard)
     }
     "json" => {
-      // We gonna use this feature to implement something like https://github.com/antfu-collective/vite-plugin-inspect
-      unimplemented!()
+      // Used to inspect builds, something like https://github.com/antfu-collective/vite-plugin-inspect
+      let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_micros());
+      let path = format!("./trace-{timestamp}.ndjson");
+      // Tracing shouldn't break the build, e.g. when the cwd is read-only.
+      let (writer, create_error): (Box<dyn Write + Send>, _) = match File::create(&path) {
+        Ok(file) => (Box::new(LineWriter::new(file)), None),
+        Err(err) => (Box::new(std::io::stderr()), Some(err)),
+      };
+      tracing_subscriber::registry().with(env_filter).with(JsonLayer::new(writer)).init();
+      if let Some(err) = create_error {
+        tracing::warn!("Failed to create {path:?} ({err}), writing traces to stderr");
+      }
+      None
     }
     _ => {
       tracing_subscriber::registry()
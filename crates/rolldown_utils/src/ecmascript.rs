@@ -56,6 +56,31 @@ pub fn property_access_str(obj: &str, prop: &str) -> String {
   }
 }
 
+/// Returns the `n`-th name of the sequence `a`, `b`, ..., `$`, `aa`, `ab`, ..., used for mangled names.
+/// Callers skip names that are taken or reserved.
+pub fn short_identifier_name(mut n: usize) -> String {
+  const FIRST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_$";
+  const REST: &[u8] = b"abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ_$0123456789";
+  let mut name = String::new();
+  name.push(FIRST[n % FIRST.len()] as char);
+  n /= FIRST.len();
+  while n > 0 {
+    n -= 1;
+    name.push(REST[n % REST.len()] as char);
+    n /= REST.len();
+  }
+  name
+}
+
+#[test]
+fn test_short_identifier_name() {
+  assert_eq!(short_identifier_name(0), "a");
+  assert_eq!(short_identifier_name(53), "$");
+  assert_eq!(short_identifier_name(54), "aa");
+  assert_eq!(short_identifier_name(55), "ba");
+  assert_eq!(short_identifier_name(54 + 54 * 64), "aaa");
+}
+
 #[test]
 fn test_is_validate_identifier_name() {
   assert!(is_validate_identifier_name("foo"));
@@ -107,6 +107,14 @@ impl<'str> Chunk<'str> {
     self.edited_content = Some(content);
   }
 
+  /// Drops the edits and the inserted content.
+  pub fn reset(&mut self) {
+    self.intro.clear();
+    self.outro.clear();
+    self.edited_content = None;
+    self.keep_in_mappings = false;
+  }
+
   pub fn is_edited(&self) -> bool {
     self.edited_content.is_some()
   }
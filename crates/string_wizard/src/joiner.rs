@@ -1,3 +1,5 @@
+#[cfg(feature = "sourcemap")]
+use crate::{source_map::sourcemap_builder::SourcemapBuilder, SourceMapOptions};
 use crate::{CowStr, MagicString};
 
 pub struct JoinerOptions {
@@ -46,6 +48,24 @@ impl<'s> Joiner<'s> {
     ret
   }
 
+  /// Generates the combined source map of the joined content. Each appended [MagicString] is
+  /// mapped to its `filename`, and the ones without a filename, like the ones added by
+  /// `append_raw`, are not mapped. `opts.source` is not used.
+  #[cfg(feature = "sourcemap")]
+  pub fn source_map(&self, opts: SourceMapOptions) -> oxc_sourcemap::SourceMap {
+    let mut source_builder = SourcemapBuilder::new(opts.hires);
+    for (index, source) in self.sources.iter().enumerate() {
+      if let Some(separator) = self.separator.as_deref().filter(|_| index > 0) {
+        source_builder.advance(separator);
+      }
+      match source.filename() {
+        Some(filename) => source.add_to_source_map(&mut source_builder, filename),
+        None => source.fragments().for_each(|frag| source_builder.advance(frag)),
+      }
+    }
+    source_builder.into_source_map()
+  }
+
   // --- private
 
   fn fragments(&'s self) -> impl Iterator<Item = &'s str> {
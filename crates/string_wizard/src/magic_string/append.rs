@@ -26,6 +26,11 @@ impl<'text> MagicString<'text> {
     self
   }
 
+  /// Alias of [`MagicString::append_left`], the `insertLeft` of magic-string.
+  pub fn insert_left(&mut self, text_index: usize, content: impl Into<CowStr<'text>>) -> &mut Self {
+    self.append_left(text_index, content)
+  }
+
   /// # Example
   /// ```rust
   /// use string_wizard::MagicString;
@@ -3,8 +3,10 @@ pub mod indent;
 pub mod movement;
 pub mod prepend;
 pub mod replace;
+pub mod slice;
 #[cfg(feature = "sourcemap")]
 pub mod source_map;
+pub mod trim;
 pub mod update;
 
 use std::{collections::VecDeque, sync::OnceLock};
@@ -83,6 +85,18 @@ impl<'text> MagicString<'text> {
     self.len() == 0
   }
 
+  /// Whether the content differs from the original source.
+  pub fn has_changed(&self) -> bool {
+    let mut rest = self.source.as_ref();
+    for frag in self.fragments() {
+      match rest.strip_prefix(frag) {
+        Some(stripped) => rest = stripped,
+        None => return true,
+      }
+    }
+    !rest.is_empty()
+  }
+
   fn prepend_intro(&mut self, content: impl Into<CowStr<'text>>) {
     self.intro.push_front(content.into());
   }
@@ -33,4 +33,13 @@ impl<'text> MagicString<'text> {
     }
     self
   }
+
+  /// Alias of [`MagicString::prepend_right`], the `insertRight` of magic-string.
+  pub fn insert_right(
+    &mut self,
+    text_index: usize,
+    content: impl Into<CowStr<'text>>,
+  ) -> &mut Self {
+    self.prepend_right(text_index, content)
+  }
 }
@@ -0,0 +1,82 @@
+use crate::MagicString;
+
+impl<'text> MagicString<'text> {
+  /// Returns the content between the original `start` and `end` indices, including the edits made
+  /// in between.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `start` or `end` is inside an updated range.
+  ///
+  /// # Example
+  /// ```rust
+  /// use string_wizard::MagicString;
+  /// let mut s = MagicString::new("abcdefghijkl");
+  /// s.update(4, 6, "EF").append_left(8, "!");
+  /// assert_eq!(s.slice(2, 9), "cdEFgh!i");
+  /// ```
+  pub fn slice(&self, start: usize, end: usize) -> String {
+    assert!(start <= end && end <= self.source.len(), "Cannot slice {start}..{end} out of bounds");
+    let mut ret = String::new();
+    if start == end {
+      return ret;
+    }
+
+    let mut chunks = self.iter_chunks();
+    let mut chunk = loop {
+      let Some(chunk) = chunks.next() else {
+        return ret;
+      };
+      if chunk.start() <= start && start < chunk.end() {
+        break chunk;
+      }
+      // The chunk containing `end` is moved before the one containing `start`.
+      if chunk.start() < end && end <= chunk.end() {
+        return ret;
+      }
+    };
+    if chunk.is_edited() && chunk.start() != start {
+      panic!("Cannot use replaced character {start} as slice start anchor.");
+    }
+
+    let mut is_start_chunk = true;
+    loop {
+      if !is_start_chunk || chunk.start() == start {
+        chunk.intro.iter().for_each(|frag| ret.push_str(frag));
+      }
+      let contains_end = chunk.start() < end && end <= chunk.end();
+      if contains_end && chunk.is_edited() && chunk.end() != end {
+        panic!("Cannot use replaced character {end} as slice end anchor.");
+      }
+      let content =
+        chunk.edited_content.as_deref().unwrap_or_else(|| chunk.span.text(&self.source));
+      let slice_start = if is_start_chunk { start - chunk.start() } else { 0 };
+      let slice_end = if contains_end { content.len() + end - chunk.end() } else { content.len() };
+      ret.push_str(&content[slice_start..slice_end]);
+      if !contains_end || chunk.end() == end {
+        chunk.outro.iter().for_each(|frag| ret.push_str(frag));
+      }
+      if contains_end {
+        break;
+      }
+      is_start_chunk = false;
+      match chunks.next() {
+        Some(next) => chunk = next,
+        None => break,
+      }
+    }
+    ret
+  }
+
+  /// Returns a clone with the content outside of the original `start` and `end` indices removed.
+  pub fn snip(&self, start: usize, end: usize) -> MagicString<'text> {
+    let mut snipped = self.clone();
+    if start > 0 {
+      snipped.remove(0, start);
+    }
+    if end < snipped.source.len() {
+      snipped.remove(end, snipped.source.len());
+    }
+    snipped
+  }
+}
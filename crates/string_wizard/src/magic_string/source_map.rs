@@ -26,8 +26,14 @@ impl Default for SourceMapOptions {
 impl MagicString<'_> {
   pub fn source_map(&self, opts: SourceMapOptions) -> oxc_sourcemap::SourceMap {
     let mut source_builder = SourcemapBuilder::new(opts.hires);
+    self.add_to_source_map(&mut source_builder, &opts.source);
+    source_builder.into_source_map()
+  }
 
-    source_builder.set_source_and_content(&opts.source, &self.source);
+  /// Adds the mappings of this string to `source_builder`, as if its content was appended to the
+  /// content added before.
+  pub(crate) fn add_to_source_map(&self, source_builder: &mut SourcemapBuilder, source: &str) {
+    source_builder.set_source_and_content(source, &self.source);
 
     let locator = Locator::new(&self.source);
 
@@ -62,7 +68,9 @@ impl MagicString<'_> {
       });
     });
 
-    source_builder.into_source_map()
+    self.outro.iter().for_each(|frag| {
+      source_builder.advance(frag);
+    });
   }
 }
 
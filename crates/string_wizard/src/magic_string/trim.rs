@@ -0,0 +1,163 @@
+use std::{borrow::Cow, collections::VecDeque};
+
+use crate::{chunk::EditOptions, CowStr, MagicString};
+
+impl MagicString<'_> {
+  /// Removes leading and trailing whitespace. Original characters are removed by editing their
+  /// chunks, so the remaining content keeps its mappings.
+  pub fn trim(&mut self) -> &mut Self {
+    self.trim_with(char::is_whitespace)
+  }
+
+  pub fn trim_start(&mut self) -> &mut Self {
+    self.trim_start_with(char::is_whitespace)
+  }
+
+  pub fn trim_end(&mut self) -> &mut Self {
+    self.trim_end_with(char::is_whitespace)
+  }
+
+  /// Removes leading and trailing line breaks.
+  pub fn trim_lines(&mut self) -> &mut Self {
+    self.trim_with(|c| c == '\r' || c == '\n')
+  }
+
+  /// Removes the leading and trailing characters matching `pat`.
+  pub fn trim_with(&mut self, pat: impl Fn(char) -> bool) -> &mut Self {
+    self.inner_trim_start(&pat);
+    self.inner_trim_end(&pat);
+    self
+  }
+
+  pub fn trim_start_with(&mut self, pat: impl Fn(char) -> bool) -> &mut Self {
+    self.inner_trim_start(&pat);
+    self
+  }
+
+  pub fn trim_end_with(&mut self, pat: impl Fn(char) -> bool) -> &mut Self {
+    self.inner_trim_end(&pat);
+    self
+  }
+
+  // --- private
+
+  fn inner_trim_start(&mut self, pat: &dyn Fn(char) -> bool) {
+    if trim_frags_start(&mut self.intro, pat) {
+      return;
+    }
+    let mut next_chunk_idx = Some(self.first_chunk_idx);
+    while let Some(chunk_idx) = next_chunk_idx {
+      let chunk = &mut self.chunks[chunk_idx];
+      if trim_frags_start(&mut chunk.intro, pat) {
+        return;
+      }
+      if let Some(edited_content) = chunk.edited_content.as_mut() {
+        trim_cow_start(edited_content, pat);
+        if !edited_content.is_empty() {
+          return;
+        }
+      } else {
+        let text = chunk.span.text(&self.source);
+        let kept_len = text.trim_start_matches(pat).len();
+        if kept_len == text.len() {
+          return;
+        }
+        if kept_len > 0 {
+          let at = chunk.end() - kept_len;
+          self.split_at(at);
+          self.chunks[chunk_idx]
+            .edit("".into(), EditOptions { overwrite: false, store_name: false });
+          return;
+        }
+        chunk.edit("".into(), EditOptions { overwrite: false, store_name: false });
+      }
+      let chunk = &mut self.chunks[chunk_idx];
+      if trim_frags_start(&mut chunk.outro, pat) {
+        return;
+      }
+      next_chunk_idx = chunk.next;
+    }
+    trim_frags_start(&mut self.outro, pat);
+  }
+
+  fn inner_trim_end(&mut self, pat: &dyn Fn(char) -> bool) {
+    if trim_frags_end(&mut self.outro, pat) {
+      return;
+    }
+    let mut prev_chunk_idx = Some(self.last_chunk_idx);
+    while let Some(chunk_idx) = prev_chunk_idx {
+      let chunk = &mut self.chunks[chunk_idx];
+      if trim_frags_end(&mut chunk.outro, pat) {
+        return;
+      }
+      if let Some(edited_content) = chunk.edited_content.as_mut() {
+        trim_cow_end(edited_content, pat);
+        if !edited_content.is_empty() {
+          return;
+        }
+      } else {
+        let text = chunk.span.text(&self.source);
+        let kept_len = text.trim_end_matches(pat).len();
+        if kept_len == text.len() {
+          return;
+        }
+        if kept_len > 0 {
+          let at = chunk.start() + kept_len;
+          self.split_at(at);
+          let trimmed_idx = self.chunk_by_start[&at];
+          self.chunks[trimmed_idx]
+            .edit("".into(), EditOptions { overwrite: false, store_name: false });
+          return;
+        }
+        chunk.edit("".into(), EditOptions { overwrite: false, store_name: false });
+      }
+      let chunk = &mut self.chunks[chunk_idx];
+      if trim_frags_end(&mut chunk.intro, pat) {
+        return;
+      }
+      prev_chunk_idx = chunk.prev;
+    }
+    trim_frags_end(&mut self.intro, pat);
+  }
+}
+
+/// Returns `true` if some content is left after trimming.
+fn trim_frags_start(frags: &mut VecDeque<CowStr>, pat: &dyn Fn(char) -> bool) -> bool {
+  while let Some(first) = frags.front_mut() {
+    trim_cow_start(first, pat);
+    if !first.is_empty() {
+      return true;
+    }
+    frags.pop_front();
+  }
+  false
+}
+
+/// Returns `true` if some content is left after trimming.
+fn trim_frags_end(frags: &mut VecDeque<CowStr>, pat: &dyn Fn(char) -> bool) -> bool {
+  while let Some(last) = frags.back_mut() {
+    trim_cow_end(last, pat);
+    if !last.is_empty() {
+      return true;
+    }
+    frags.pop_back();
+  }
+  false
+}
+
+fn trim_cow_start(s: &mut CowStr, pat: &dyn Fn(char) -> bool) {
+  match s {
+    Cow::Borrowed(borrowed) => *borrowed = borrowed.trim_start_matches(pat),
+    Cow::Owned(owned) => {
+      let trimmed_len = owned.len() - owned.trim_start_matches(pat).len();
+      owned.drain(..trimmed_len);
+    }
+  }
+}
+
+fn trim_cow_end(s: &mut CowStr, pat: &dyn Fn(char) -> bool) {
+  match s {
+    Cow::Borrowed(borrowed) => *borrowed = borrowed.trim_end_matches(pat),
+    Cow::Owned(owned) => owned.truncate(owned.trim_end_matches(pat).len()),
+  }
+}
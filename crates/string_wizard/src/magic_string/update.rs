@@ -31,6 +31,41 @@ impl<'text> MagicString<'text> {
     self
   }
 
+  /// Reverts the content between the original `start` and `end` indices, including the content
+  /// inserted at the chunks in between, to the original source.
+  ///
+  /// # Panics
+  ///
+  /// Panics if `start` or `end` is inside an updated range.
+  ///
+  /// # Example
+  /// ```rust
+  /// use string_wizard::MagicString;
+  /// let mut s = MagicString::new("abcdefghijkl");
+  /// s.update(1, 3, "BC").update(5, 7, "FG");
+  /// s.reset(0, 4);
+  /// assert_eq!(s.to_string(), "abcdeFGhijkl");
+  /// ```
+  pub fn reset(&mut self, start: usize, end: usize) -> &mut Self {
+    if start == end {
+      return self;
+    }
+    assert!(start < end && end <= self.source.len(), "Cannot reset {start}..{end} out of bounds");
+    self.split_at(start);
+    self.split_at(end);
+
+    let mut chunk_idx = self.chunk_by_start[&start];
+    loop {
+      let chunk = &mut self.chunks[chunk_idx];
+      chunk.reset();
+      if end <= chunk.end() {
+        break;
+      }
+      chunk_idx = self.chunk_by_start[&chunk.end()];
+    }
+    self
+  }
+
   // --- private
 
   pub(super) fn inner_update_with(
@@ -61,9 +96,12 @@ impl<'text> MagicString<'text> {
       return self;
     };
 
-    while rest_chunk_idx != end_idx {
+    loop {
       let rest_chunk = &mut self.chunks[rest_chunk_idx];
       rest_chunk.edit("".into(), Default::default());
+      if rest_chunk_idx == end_idx {
+        break;
+      }
       rest_chunk_idx = rest_chunk.next.unwrap();
     }
     self
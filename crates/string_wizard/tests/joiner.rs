@@ -21,3 +21,28 @@ fn separator() {
   j.append_raw("123");
   assert_eq!(j.join(), "123,123,123");
 }
+
+#[test]
+fn source_map() {
+  use oxc_sourcemap::SourcemapVisualizer;
+  use string_wizard::{Hires, MagicStringOptions, SourceMapOptions};
+
+  let mut foo = MagicString::with_options(
+    "const foo = 1;",
+    MagicStringOptions { filename: Some("foo.js".to_string()) },
+  );
+  foo.update(6, 9, "bar");
+  let mut main = MagicString::with_options(
+    "  console.log(foo);\n",
+    MagicStringOptions { filename: Some("main.js".to_string()) },
+  );
+  main.trim();
+
+  let mut j = Joiner::with_options(JoinerOptions { separator: Some("\n".to_string()) });
+  j.append_raw("// banner").append(foo).append(main);
+  let code = j.join();
+  assert_eq!(code, "// banner\nconst bar = 1;\nconsole.log(foo);");
+
+  let map = j.source_map(SourceMapOptions { hires: Hires::Boundary, ..Default::default() });
+  insta::assert_snapshot!(SourcemapVisualizer::new(&code, &map).into_visualizer_text());
+}
@@ -135,6 +135,15 @@ mod overwrite {
     assert_eq!(s.to_string(), "abcdeFGHijkl");
   }
 
+  #[test]
+  fn should_replace_every_chunk_of_the_range() {
+    let mut s = MagicString::new("abcdefghijkl");
+    s.overwrite(6, 8, "GH").overwrite(2, 4, "CD");
+    // The range ends with the chunk of the first overwrite, which has to be cleared too.
+    s.overwrite(1, 8, "X");
+    assert_eq!(s.to_string(), "aXijkl");
+  }
+
   // #[test]
   // fn should_throw_an_error_if_overlapping_replacements_are_attempted() {
   //     let mut s = MagicString::new("abcdefghijkl");
@@ -406,3 +415,212 @@ mod misc {
     assert_eq!(s.to_string(), "xyzxyz");
   }
 }
+
+mod insert {
+  use super::*;
+
+  #[test]
+  fn should_alias_append_left_and_prepend_right() {
+    let mut s = MagicString::new("0123456789");
+    s.insert_left(3, "a").insert_right(3, "b").insert_left(3, "c").insert_right(3, "d");
+    assert_eq!(s.to_string(), "012acdb3456789");
+  }
+}
+
+mod has_changed {
+  use super::*;
+
+  #[test]
+  fn should_indicate_if_a_string_has_changed() {
+    let mut s = MagicString::new("abcdefghijkl");
+    assert!(!s.has_changed());
+    assert!(!s.clone().has_changed());
+
+    s.prepend("abcd");
+    assert!(s.has_changed());
+    let mut s = MagicString::new("abcdefghijkl");
+    s.update(0, 1, "a");
+    assert!(!s.has_changed());
+    s.remove(0, 1);
+    assert!(s.has_changed());
+  }
+}
+
+mod reset {
+  use super::*;
+
+  #[test]
+  fn should_reset_moved_characters_in_the_original_string() {
+    let mut s = MagicString::new("abcdefghijkl");
+    s.overwrite(1, 3, "_").overwrite(7, 9, "+");
+    assert_eq!(s.to_string(), "a_defg+jkl");
+    s.reset(1, 3);
+    assert_eq!(s.to_string(), "abcdefg+jkl");
+    s.reset(7, 9);
+    assert_eq!(s.to_string(), "abcdefghijkl");
+  }
+
+  #[test]
+  fn should_reset_removed_and_inserted_content() {
+    let mut s = MagicString::new("abcdefghijkl");
+    s.remove(1, 3).append_left(5, "!").prepend_right(5, "?");
+    assert_eq!(s.to_string(), "ade!?fghijkl");
+    s.reset(0, 6);
+    assert_eq!(s.to_string(), "abcdefghijkl");
+    assert!(!s.has_changed());
+  }
+
+  #[test]
+  fn should_ignore_empty_ranges() {
+    let mut s = MagicString::new("abcdefghijkl");
+    s.remove(1, 3);
+    s.reset(2, 2);
+    assert_eq!(s.to_string(), "adefghijkl");
+  }
+
+  #[test]
+  #[should_panic]
+  fn should_refuse_to_reset_inside_an_updated_range() {
+    let mut s = MagicString::new("abcdefghijkl");
+    s.update(1, 5, "_");
+    s.reset(2, 3);
+  }
+}
+
+mod slice {
+  use super::*;
+
+  #[test]
+  fn should_return_the_generated_content_between_the_specified_original_characters() {
+    let mut s = MagicString::new("abcdefghijkl");
+    assert_eq!(s.slice(3, 9), "defghi");
+    s.overwrite(4, 8, "XX");
+    assert_eq!(s.slice(3, 9), "dXXi");
+    s.overwrite(2, 10, "ZZ");
+    assert_eq!(s.slice(1, 11), "bZZk");
+    assert_eq!(s.slice(2, 10), "ZZ");
+  }
+
+  #[test]
+  fn should_include_inserted_characters_respecting_insertion_direction() {
+    let mut s = MagicString::new("abefij");
+    s.prepend_right(2, "cd").append_left(4, "gh");
+    assert_eq!(s.slice(0, 6), "abcdefghij");
+    assert_eq!(s.slice(1, 5), "bcdefghi");
+    assert_eq!(s.slice(2, 4), "cdefgh");
+    assert_eq!(s.slice(3, 4), "fgh");
+    assert_eq!(s.slice(0, 2), "ab");
+    assert_eq!(s.slice(4, 6), "ij");
+  }
+
+  #[test]
+  fn supports_characters_moved_outward() {
+    let mut s = MagicString::new("abcdEFghIJklmn");
+    s.relocate(4, 6, 2);
+    s.relocate(8, 10, 12);
+    assert_eq!(s.to_string(), "abEFcdghklIJmn");
+    assert_eq!(s.slice(1, 13), "bEFcdghklIJm");
+    assert_eq!(s.slice(2, 12), "cdghkl");
+  }
+
+  #[test]
+  fn supports_characters_moved_inward() {
+    let mut s = MagicString::new("abCDefghijKLmn");
+    s.relocate(2, 4, 6);
+    s.relocate(10, 12, 8);
+    assert_eq!(s.to_string(), "abefCDghKLijmn");
+    assert_eq!(s.slice(1, 13), "befCDghKLijm");
+    assert_eq!(s.slice(4, 10), "efCDghKLij");
+  }
+
+  #[test]
+  #[should_panic]
+  fn should_refuse_to_slice_from_inside_an_updated_range() {
+    let mut s = MagicString::new("abcdefghijkl");
+    s.overwrite(2, 5, "xx");
+    s.slice(3, 8);
+  }
+}
+
+mod snip {
+  use super::*;
+
+  #[test]
+  fn should_return_a_clone_with_content_outside_start_and_end_removed() {
+    let mut s = MagicString::with_options(
+      "abcdefghijkl",
+      MagicStringOptions { filename: Some("foo.js".to_string()) },
+    );
+    s.overwrite(6, 9, "GHI");
+    let snippet = s.snip(3, 9);
+    assert_eq!(snippet.to_string(), "defGHI");
+    assert_eq!(snippet.filename(), Some("foo.js"));
+    assert_eq!(s.to_string(), "abcdefGHIjkl");
+  }
+
+  #[test]
+  fn should_snip_the_whole_string() {
+    let s = MagicString::new("abcdefghijkl");
+    assert_eq!(s.snip(0, 12).to_string(), "abcdefghijkl");
+  }
+}
+
+mod trim {
+  use super::*;
+
+  #[test]
+  fn should_trim_original_content() {
+    let mut s = MagicString::new("   abcdefghijkl   ");
+    assert_eq!(s.trim().to_string(), "abcdefghijkl");
+    let mut s = MagicString::new("   abcdefghijkl   ");
+    assert_eq!(s.trim_start().to_string(), "abcdefghijkl   ");
+    let mut s = MagicString::new("   abcdefghijkl   ");
+    assert_eq!(s.trim_end().to_string(), "   abcdefghijkl");
+  }
+
+  #[test]
+  fn should_trim_replaced_content() {
+    let mut s = MagicString::new("abcdefghijkl");
+    s.overwrite(0, 3, "   ").overwrite(9, 12, "   ");
+    assert_eq!(s.trim().to_string(), "defghi");
+  }
+
+  #[test]
+  fn should_trim_original_content_before_replaced_content() {
+    let mut s = MagicString::new("abcdefghi   ");
+    s.overwrite(6, 9, "   ");
+    assert_eq!(s.trim().to_string(), "abcdef");
+  }
+
+  #[test]
+  fn should_trim_appended_and_prepended_content() {
+    let mut s = MagicString::new(" abcdefghijkl ");
+    s.append("  ").prepend("  ");
+    assert_eq!(s.trim().to_string(), "abcdefghijkl");
+    let mut s = MagicString::new(" abcdefghijkl ");
+    s.append("  xyz  ").prepend("  xyz  ");
+    assert_eq!(s.trim().to_string(), "xyz   abcdefghijkl   xyz");
+  }
+
+  #[test]
+  fn should_trim_empty_strings() {
+    let mut s = MagicString::new("   ");
+    assert_eq!(s.trim().to_string(), "");
+    let mut s = MagicString::new("");
+    s.append("  ");
+    assert_eq!(s.trim().to_string(), "");
+  }
+
+  #[test]
+  fn should_trim_lines() {
+    let mut s = MagicString::new("\n\n   abcdefghijkl   \n\n");
+    assert_eq!(s.trim_lines().to_string(), "   abcdefghijkl   ");
+  }
+
+  #[test]
+  fn should_trim_with_a_custom_pattern() {
+    let mut s = MagicString::new("--abc--");
+    s.append_left(7, "-");
+    assert_eq!(s.trim_with(|c| c == '-').to_string(), "abc");
+  }
+}
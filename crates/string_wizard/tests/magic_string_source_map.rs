@@ -59,3 +59,27 @@ function test() {
   insta::assert_snapshot!("hires_true", visualize(&s, Hires::True, &output));
   insta::assert_snapshot!("hires_boundary", visualize(&s, Hires::Boundary, &output));
 }
+
+#[test]
+fn trim_and_snip() {
+  let code = "\n  const foo = 1;\n  console.log(foo);\n";
+  let mut s = MagicString::new(code);
+  s.trim();
+  let output = s.to_string();
+  assert_eq!(output, "const foo = 1;\n  console.log(foo);");
+  let sourcemap = s.source_map(SourceMapOptions { hires: Hires::Boundary, ..Default::default() });
+  insta::assert_snapshot!(
+    "trim",
+    SourcemapVisualizer::new(&output, &sourcemap).into_visualizer_text()
+  );
+
+  let snipped = s.snip(20, 37);
+  let output = snipped.to_string();
+  assert_eq!(output, "console.log(foo);");
+  let sourcemap =
+    snipped.source_map(SourceMapOptions { hires: Hires::Boundary, ..Default::default() });
+  insta::assert_snapshot!(
+    "snip",
+    SourcemapVisualizer::new(&output, &sourcemap).into_visualizer_text()
+  );
+}
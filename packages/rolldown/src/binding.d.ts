@@ -117,6 +117,7 @@ export declare class BindingRenderedModule {
 
 export declare class BindingTransformPluginContext {
   getCombinedSourcemap(): string
+  addWatchFile(file: string): void
   inner(): BindingPluginContext
 }
 
@@ -231,6 +232,13 @@ export interface BindingAssetSource {
   inner: string | Uint8Array
 }
 
+export interface BindingBoundaryRule {
+  from?: BindingStringOrRegex
+  disallow: BindingStringOrRegex
+  platform?: 'node' | 'browser' | 'neutral'
+  message?: string
+}
+
 export interface BindingBuildImportAnalysisPluginConfig {
   preloadCode: string
   insertPreload: boolean
@@ -267,6 +275,9 @@ export interface BindingBundlerOptions {
 
 export interface BindingChecksOptions {
   circularDependency?: boolean
+  circularDependencyIgnore?: Array<BindingStringOrRegex>
+  duplicatePackage?: boolean
+  boundaries?: Array<BindingBoundaryRule>
 }
 
 export interface BindingEmittedAsset {
@@ -328,6 +339,28 @@ export interface BindingHookRenderChunkOutput {
   map?: BindingSourcemap
 }
 
+export interface BindingHookRenderDynamicImportArgs {
+  chunkId: string
+  format: 'es' | 'cjs' | 'app' | 'iife' | 'umd'
+  moduleId: string
+  specifier: string
+  targetModuleId: string
+}
+
+export interface BindingHookRenderDynamicImportOutput {
+  left: string
+  right: string
+}
+
+export interface BindingHookResolveFileUrlArgs {
+  chunkId: string
+  fileName: string
+  format: 'es' | 'cjs' | 'app' | 'iife' | 'umd'
+  moduleId: string
+  referenceId?: string
+  relativePath: string
+}
+
 export interface BindingHookResolveIdExtraArgs {
   custom?: number
   isEntry: boolean
@@ -340,6 +373,12 @@ export interface BindingHookResolveIdOutput {
   sideEffects?: BindingHookSideEffects
 }
 
+export interface BindingHookResolveImportMetaArgs {
+  chunkId: string
+  format: 'es' | 'cjs' | 'app' | 'iife' | 'umd'
+  moduleId: string
+}
+
 export declare enum BindingHookSideEffects {
   True = 0,
   False = 1,
@@ -392,6 +431,8 @@ export interface BindingInputOptions {
   watch?: BindingWatchOption
   keepNames?: boolean
   checks?: BindingChecksOptions
+  logRules?: Array<BindingLogRule>
+  assetsInlineLimit?: number | ((id: string, content: Uint8Array) => VoidNullable<boolean>)
 }
 
 export interface BindingJsonPluginConfig {
@@ -428,6 +469,18 @@ export declare enum BindingLogLevel {
   Debug = 3
 }
 
+export interface BindingLogRule {
+  code?: string
+  id?: BindingStringOrRegex
+  action: 'ignore' | 'warn' | 'error'
+}
+
+export interface BindingManglePropsOptions {
+  regex: BindingStringOrRegex
+  reserved?: Array<string>
+  nameCache?: string
+}
+
 export interface BindingManifestPluginConfig {
   root: string
   outPath: string
@@ -441,6 +494,28 @@ export interface BindingMatchGroup {
   minShareCount?: number
 }
 
+export interface BindingMinifyCompressOptions {
+  dropConsole?: boolean
+  dropDebugger?: boolean
+  pureFuncs?: Array<string>
+  passes?: number
+}
+
+export interface BindingMinifyMangleOptions {
+  topLevel?: boolean
+  keepFnames?: boolean
+  keepClassnames?: boolean
+  reserved?: Array<string>
+  nameCache?: string
+}
+
+export interface BindingMinifyOptions {
+  compress?: BindingMinifyCompressOptions
+  mangle?: BindingMinifyMangleOptions
+  /** Only remove whitespace. `compress` and `mangle` are ignored. */
+  whitespaceOnly?: boolean
+}
+
 export interface BindingModuleFederationPluginOption {
   name: string
   filename?: string
@@ -494,12 +569,20 @@ export interface BindingOutputOptions {
   sourcemap?: 'file' | 'inline' | 'hidden'
   sourcemapIgnoreList?: (source: string, sourcemapPath: string) => boolean
   sourcemapDebugIds?: boolean
+  sourcemapDebugIdsGlobal?: string
+  sourcemapDebugIdsManifest?: string
   sourcemapPathTransform?: (source: string, sourcemapPath: string) => string
-  minify?: boolean
+  sourcemapIndexMap?: boolean
+  sourcemapQuality?: Array<BindingSourcemapQualityRule>
+  minify?: boolean | BindingMinifyOptions
+  mangleProps?: BindingManglePropsOptions
   advancedChunks?: BindingAdvancedChunksOptions
-  comments?: 'none' | 'preserve-legal'
+  comments?: 'none' | 'preserve-legal' | 'external'
+  legalCommentsFile?: string
+  licenseReport?: string
   polyfillRequire?: boolean
   target?: string
+  runtimeHelpers?: 'inline' | BindingRuntimeHelpers
 }
 
 export interface BindingPluginContextResolvedId {
@@ -562,6 +645,12 @@ export interface BindingPluginOptions {
   introMeta?: BindingPluginHookMeta
   outro?: (ctx: BindingPluginContext, chunk: RenderedChunk) => void
   outroMeta?: BindingPluginHookMeta
+  resolveImportMeta?: (ctx: BindingPluginContext, property: Nullable<string>, args: BindingHookResolveImportMetaArgs) => MaybePromise<VoidNullable<string>>
+  resolveImportMetaMeta?: BindingPluginHookMeta
+  resolveFileUrl?: (ctx: BindingPluginContext, args: BindingHookResolveFileUrlArgs) => MaybePromise<VoidNullable<string>>
+  resolveFileUrlMeta?: BindingPluginHookMeta
+  renderDynamicImport?: (ctx: BindingPluginContext, args: BindingHookRenderDynamicImportArgs) => MaybePromise<VoidNullable<BindingHookRenderDynamicImportOutput>>
+  renderDynamicImportMeta?: BindingPluginHookMeta
 }
 
 export declare enum BindingPluginOrder {
@@ -594,6 +683,7 @@ export interface BindingResolveOptions {
   alias?: Array<AliasItem>
   aliasFields?: Array<Array<string>>
   conditionNames?: Array<string>
+  dedupe?: Array<string>
   exportsFields?: Array<Array<string>>
   extensions?: Array<string>
   extensionAlias?: Array<ExtensionAliasItem>
@@ -604,6 +694,13 @@ export interface BindingResolveOptions {
   tsconfigFilename?: string
 }
 
+export interface BindingRuntimeHelpers {
+  /** Import the used helpers from this specifier. */
+  external?: string
+  /** Emit all helpers as a separate asset with this file name. */
+  shared?: string
+}
+
 export interface BindingShared {
   version?: string
   shareScope?: string
@@ -616,6 +713,11 @@ export interface BindingSourcemap {
   inner: string | BindingJsonSourcemap
 }
 
+export interface BindingSourcemapQualityRule {
+  test: BindingStringOrRegex
+  quality: 'full' | 'cheap' | 'excluded'
+}
+
 export interface BindingTransformHookExtraArgs {
   moduleType: string
 }
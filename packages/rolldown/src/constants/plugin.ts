@@ -23,6 +23,9 @@ export const ENUMERATED_OUTPUT_PLUGIN_HOOK_NAMES = [
   'renderError',
   'writeBundle',
   'generateBundle',
+  'resolveImportMeta',
+  'resolveFileUrl',
+  'renderDynamicImport',
 ] as const
 
 export const ENUMERATED_PLUGIN_HOOK_NAMES: [
@@ -103,4 +106,7 @@ export const DEFINED_HOOK_NAMES: DefinedHookNames = {
   [ENUMERATED_PLUGIN_HOOK_NAMES[20]]: ENUMERATED_PLUGIN_HOOK_NAMES[20],
   [ENUMERATED_PLUGIN_HOOK_NAMES[21]]: ENUMERATED_PLUGIN_HOOK_NAMES[21],
   [ENUMERATED_PLUGIN_HOOK_NAMES[22]]: ENUMERATED_PLUGIN_HOOK_NAMES[22],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[23]]: ENUMERATED_PLUGIN_HOOK_NAMES[23],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[24]]: ENUMERATED_PLUGIN_HOOK_NAMES[24],
+  [ENUMERATED_PLUGIN_HOOK_NAMES[25]]: ENUMERATED_PLUGIN_HOOK_NAMES[25],
 } as const
@@ -11,7 +11,11 @@ import type {
   ExternalOption,
   JsxOptions,
 } from './options/input-options'
-import type { ModuleFormat, OutputOptions } from './options/output-options'
+import type {
+  MinifyOptions,
+  ModuleFormat,
+  OutputOptions,
+} from './options/output-options'
 import type { RolldownOptions } from './types/rolldown-options'
 import type {
   AsyncPluginHooks,
@@ -73,6 +77,7 @@ export type {
   NormalizedInputOptions,
   OutputOptions,
   NormalizedOutputOptions,
+  MinifyOptions,
   Plugin,
   RolldownPlugin,
   RolldownPluginOption,
@@ -62,6 +62,42 @@ export interface ChecksOptions {
    * @default false
    */
   circularDependency?: boolean
+  /**
+   * Cycles passing through modules matching these patterns are not reported.
+   * Glob patterns are matched against module ids relative to `cwd`, and regexes against module ids.
+   */
+  circularDependencyIgnore?: StringOrRegExp[]
+  /**
+   * Whether to emit warnings when a package is bundled from several locations, e.g. from nested `node_modules`.
+   * @default false
+   */
+  duplicatePackage?: boolean
+  /**
+   * Import edges that are not allowed. Violations are reported as `BOUNDARY_VIOLATION` errors.
+   *
+   * Patterns are glob patterns matched against module ids relative to `cwd`, or regexes matched against module ids.
+   * External modules are matched by their import specifier, e.g. `node:*`.
+   */
+  boundaries?: BoundaryRule[]
+}
+
+export interface BoundaryRule {
+  /**
+   * The importers the rule applies to. Applies to all modules if omitted.
+   */
+  from?: StringOrRegExp
+  /**
+   * The importees that are forbidden.
+   */
+  disallow: StringOrRegExp
+  /**
+   * Only applies the rule when building for this platform.
+   */
+  platform?: 'node' | 'browser' | 'neutral'
+  /**
+   * Appended to the diagnostic, e.g. to explain why the import is forbidden.
+   */
+  message?: string
 }
 
 export interface InputOptions {
@@ -72,6 +108,11 @@ export interface InputOptions {
     alias?: Record<string, string[] | string>
     aliasFields?: string[][]
     conditionNames?: string[]
+    /**
+     * Packages that always resolve to the copy found from `cwd`, no matter which module imports them.
+     * It prevents bundling several copies of a package that is installed in nested `node_modules`.
+     */
+    dedupe?: string[]
     /**
      * Map of extensions to alternative extensions.
      *
@@ -160,12 +201,32 @@ export interface InputOptions {
   dropLabels?: string[]
   keepNames?: boolean
   checks?: ChecksOptions
+  /**
+   * Ordered rules to ignore diagnostics, report them as warnings, or make them fail the build. The first rule matching both `code` and `id` wins.
+   *
+   * `id` is a glob pattern matched against the module id relative to `cwd`, or a regex matched against the module id.
+   * A rule with `id` never matches diagnostics that are not about a module.
+   */
+  logRules?: {
+    code?: string
+    id?: StringOrRegExp
+    action: 'ignore' | 'warn' | 'error'
+  }[]
+  /**
+   * Assets smaller than this byte size are inlined as data URLs instead of being emitted as separate files.
+   * A function decides per asset, returning `undefined` leaves it emitted.
+   * @default 0
+   */
+  assetsInlineLimit?:
+    | number
+    | ((id: string, content: Uint8Array) => boolean | undefined)
 }
 
 interface OverwriteInputOptionsForCli {
   external?: string[]
   inject?: Record<string, string>
   treeshake?: boolean
+  checks?: Omit<ChecksOptions, 'circularDependencyIgnore' | 'boundaries'>
 }
 
 export type InputCliOptions = Omit<
@@ -179,5 +240,7 @@ export type InputCliOptions = Omit<
   | 'experimental'
   | 'profilerNames'
   | 'watch'
+  | 'logRules'
+  | 'assetsInlineLimit'
 > &
   OverwriteInputOptionsForCli
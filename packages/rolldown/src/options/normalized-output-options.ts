@@ -38,7 +38,7 @@ export interface NormalizedOutputOptions {
   sourcemapIgnoreList: SourcemapIgnoreListOption | undefined
   sourcemapPathTransform: SourcemapPathTransformOption | undefined
   minify: boolean
-  comments: 'none' | 'preserve-legal'
+  comments: 'none' | 'preserve-legal' | 'external'
   polyfillRequire: boolean
 }
 
@@ -169,7 +169,7 @@ export class NormalizedOutputOptionsImpl implements NormalizedOutputOptions {
     return this.inner.minify
   }
 
-  get comments(): 'none' | 'preserve-legal' {
+  get comments(): 'none' | 'preserve-legal' | 'external' {
     return this.inner.comments
   }
 
@@ -37,6 +37,36 @@ export type ESTarget =
   | 'es2024'
   | 'esnext'
 
+export interface MinifyOptions {
+  compress?: {
+    /** @default false */
+    dropConsole?: boolean
+    /** @default true */
+    dropDebugger?: boolean
+    /**
+     * Calls to these functions are dropped when their result is unused, e.g. `['console.info']`.
+     */
+    pureFuncs?: string[]
+    /** @default 1 */
+    passes?: number
+  }
+  mangle?: {
+    /** Also mangle top-level bindings. Exported names are kept. */
+    topLevel?: boolean
+    keepFnames?: boolean
+    keepClassnames?: boolean
+    /** Names that are neither mangled nor used as mangled names. */
+    reserved?: string[]
+    /**
+     * Path of a JSON file, relative to `cwd`, that keeps the mangled names of top-level bindings
+     * across builds. It is updated when the bundle is written. Requires `topLevel`.
+     */
+    nameCache?: string
+  }
+  /** Only remove whitespace. `compress` and `mangle` are ignored. */
+  whitespaceOnly?: boolean
+}
+
 export interface OutputOptions {
   dir?: string
   file?: string
@@ -55,6 +85,40 @@ export interface OutputOptions {
   sourcemap?: boolean | 'inline' | 'hidden'
   sourcemapIgnoreList?: boolean | SourcemapIgnoreListOption
   sourcemapPathTransform?: SourcemapPathTransformOption
+  /**
+   * Add a [debug id](https://github.com/tc39/source-map/blob/main/proposals/debug-id.md) to each chunk and its source map.
+   * @default false
+   */
+  sourcemapDebugIds?: boolean
+  /**
+   * Name of a `globalThis` property, e.g. `'_sentryDebugIds'`, in which each chunk registers its debug id
+   * keyed by the stack trace of an error created in the chunk, so error reporters can find the debug id of a stack frame.
+   * Errors thrown while the chunk is evaluated are not covered. Requires `sourcemapDebugIds`.
+   */
+  sourcemapDebugIdsGlobal?: string
+  /**
+   * File name of a JSON asset listing every chunk with its source map file and debug id. Requires `sourcemapDebugIds`.
+   */
+  sourcemapDebugIdsManifest?: string
+  /**
+   * Emit source map v3 index maps with one section per rendered module instead of one flattened map.
+   * Chunks changed by `renderChunk` hooks or by `minify` fall back to a flattened map,
+   * and `OutputChunk.map` is only set for flattened maps.
+   * @default false
+   */
+  sourcemapIndexMap?: boolean
+  /**
+   * Source map quality of the modules whose id matches `test`. The first matching rule wins, and
+   * modules without a match get `full` mappings.
+   *
+   * - `full`: mappings with column precision
+   * - `cheap`: one mapping per generated line
+   * - `excluded`: the module is left out of the source map
+   */
+  sourcemapQuality?: {
+    test: StringOrRegExp
+    quality: 'full' | 'cheap' | 'excluded'
+  }[]
   banner?: string | AddonFunction
   footer?: string | AddonFunction
   intro?: string | AddonFunction
@@ -66,7 +130,20 @@ export interface OutputOptions {
   chunkFileNames?: string | ChunkFileNamesFunction
   cssEntryFileNames?: string | ChunkFileNamesFunction
   cssChunkFileNames?: string | ChunkFileNamesFunction
-  minify?: boolean
+  minify?: boolean | MinifyOptions
+  /**
+   * Renames the properties whose names match `regex` consistently across all chunks.
+   */
+  mangleProps?: {
+    regex: StringOrRegExp
+    /** Property names that are never renamed, even if they match `regex`. */
+    reserved?: string[]
+    /**
+     * Path of a JSON file, relative to `cwd`, that keeps the mangled property names across builds.
+     * It is updated when the bundle is written.
+     */
+    nameCache?: string
+  }
   name?: string
   globals?: Record<string, string> | GlobalsFunction
   externalLiveBindings?: boolean
@@ -87,14 +164,35 @@ export interface OutputOptions {
    *
    * - `none`: no comments
    * - `preserve-legal`: preserve comments that contain `@license`, `@preserve` or starts with `//!` `/*!`
+   * - `external`: move legal comments to a `<chunk>.LEGAL.txt` asset named by `assetFileNames`, which is referenced by a comment at the end of the chunk
+   */
+  comments?: 'none' | 'preserve-legal' | 'external'
+  /**
+   * With `comments: 'external'`, the legal comments of all chunks are written to this file instead
+   * of a `<chunk>.LEGAL.txt` asset per chunk.
    */
-  comments?: 'none' | 'preserve-legal'
+  legalCommentsFile?: string
+  /**
+   * File name of a JSON asset that lists the bundled third-party packages with their `license`,
+   * grouped by package.
+   */
+  licenseReport?: string
   plugins?: RolldownOutputPluginOption
   polyfillRequire?: boolean
   target?: ESTarget
+  /**
+   * Where the helpers of the runtime module, such as `__toESM` and `__export`, come from. Only `es` and `cjs` formats support the other modes.
+   *
+   * - `inline`: inline the used helpers into the output
+   * - `{ external: specifier }`: import the used helpers from `specifier`, which needs to be provided at runtime
+   * - `{ shared: fileName }`: emit all helpers as a separate asset named `fileName` and import the used helpers from it
+   * @default 'inline'
+   */
+  runtimeHelpers?: 'inline' | { external: string } | { shared: string }
 }
 
 interface OverwriteOutputOptionsForCli {
+  minify?: boolean
   banner?: string
   footer?: string
   intro?: string
@@ -112,5 +210,8 @@ export type OutputCliOptions = Omit<
   | keyof OverwriteOutputOptionsForCli
   | 'sourcemapIgnoreList'
   | 'sourcemapPathTransform'
+  | 'sourcemapQuality'
+  | 'mangleProps'
+  | 'runtimeHelpers'
 > &
   OverwriteOutputOptionsForCli
@@ -354,3 +354,82 @@ export function bindingifyOutro(
     meta: bindingifyPluginHookMeta(meta),
   }
 }
+
+export function bindingifyResolveImportMeta(
+  args: BindingifyPluginArgs,
+): PluginHookWithBindingExt<BindingPluginOptions['resolveImportMeta']> {
+  const hook = args.plugin.resolveImportMeta
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: (ctx, property, options) => {
+      return handler.call(
+        new PluginContext(
+          ctx,
+          args.plugin,
+          args.pluginContextData,
+          args.onLog,
+          args.logLevel,
+        ),
+        property ?? null,
+        options,
+      )
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
+
+export function bindingifyResolveFileUrl(
+  args: BindingifyPluginArgs,
+): PluginHookWithBindingExt<BindingPluginOptions['resolveFileUrl']> {
+  const hook = args.plugin.resolveFileUrl
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: (ctx, options) => {
+      return handler.call(
+        new PluginContext(
+          ctx,
+          args.plugin,
+          args.pluginContextData,
+          args.onLog,
+          args.logLevel,
+        ),
+        { ...options, referenceId: options.referenceId ?? null },
+      )
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
+
+export function bindingifyRenderDynamicImport(
+  args: BindingifyPluginArgs,
+): PluginHookWithBindingExt<BindingPluginOptions['renderDynamicImport']> {
+  const hook = args.plugin.renderDynamicImport
+  if (!hook) {
+    return {}
+  }
+  const { handler, meta } = normalizeHook(hook)
+
+  return {
+    plugin: (ctx, options) => {
+      return handler.call(
+        new PluginContext(
+          ctx,
+          args.plugin,
+          args.pluginContextData,
+          args.onLog,
+          args.logLevel,
+        ),
+        options,
+      )
+    },
+    meta: bindingifyPluginHookMeta(meta),
+  }
+}
@@ -21,6 +21,9 @@ import {
   bindingifyIntro,
   bindingifyOutro,
   bindingifyCloseBundle,
+  bindingifyResolveImportMeta,
+  bindingifyResolveFileUrl,
+  bindingifyRenderDynamicImport,
 } from './bindingify-output-hooks'
 
 import type { Plugin } from './index'
@@ -119,6 +122,15 @@ export function bindingifyPlugin(
 
   const { plugin: outro, meta: outroMeta } = bindingifyOutro(args)
 
+  const { plugin: resolveImportMeta, meta: resolveImportMetaMeta } =
+    bindingifyResolveImportMeta(args)
+
+  const { plugin: resolveFileUrl, meta: resolveFileUrlMeta } =
+    bindingifyResolveFileUrl(args)
+
+  const { plugin: renderDynamicImport, meta: renderDynamicImportMeta } =
+    bindingifyRenderDynamicImport(args)
+
   const { plugin: watchChange, meta: watchChangeMeta } =
     bindingifyWatchChange(args)
 
@@ -168,6 +180,12 @@ export function bindingifyPlugin(
     introMeta,
     outro,
     outroMeta,
+    resolveImportMeta,
+    resolveImportMetaMeta,
+    resolveFileUrl,
+    resolveFileUrlMeta,
+    renderDynamicImport,
+    renderDynamicImportMeta,
     watchChange,
     watchChangeMeta,
     closeWatcher,
@@ -196,6 +214,9 @@ function wrapHandlers(plugin: BindingPluginOptions): BindingPluginOptions {
     'footer',
     'intro',
     'outro',
+    'resolveImportMeta',
+    'resolveFileUrl',
+    'renderDynamicImport',
     'watchChange',
     'closeWatcher',
   ] as const) {
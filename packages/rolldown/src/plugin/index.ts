@@ -14,7 +14,10 @@ import type { ModuleInfo } from '../types/module-info'
 import type { OutputBundle } from '../types/output-bundle'
 import type { PluginContext } from './plugin-context'
 import type { TransformPluginContext } from './transform-plugin-context'
-import type { NormalizedOutputOptions } from '../options/normalized-output-options'
+import type {
+  InternalModuleFormat,
+  NormalizedOutputOptions,
+} from '../options/normalized-output-options'
 import type { LogLevel } from '../log/logging'
 import type { RollupLog } from '../types/misc'
 import type { MinimalPluginContext } from './minimal-plugin-context'
@@ -155,6 +158,39 @@ export interface FunctionPluginHooks {
     inputOptions: NormalizedInputOptions,
   ) => void
 
+  [DEFINED_HOOK_NAMES.resolveImportMeta]: (
+    this: PluginContext,
+    property: string | null,
+    options: {
+      chunkId: string
+      format: InternalModuleFormat
+      moduleId: string
+    },
+  ) => string | NullValue
+
+  [DEFINED_HOOK_NAMES.resolveFileUrl]: (
+    this: PluginContext,
+    options: {
+      chunkId: string
+      fileName: string
+      format: InternalModuleFormat
+      moduleId: string
+      referenceId: string | null
+      relativePath: string
+    },
+  ) => string | NullValue
+
+  [DEFINED_HOOK_NAMES.renderDynamicImport]: (
+    this: PluginContext,
+    options: {
+      chunkId: string
+      format: InternalModuleFormat
+      moduleId: string
+      specifier: string
+      targetModuleId: string
+    },
+  ) => { left: string; right: string } | NullValue
+
   [DEFINED_HOOK_NAMES.renderChunk]: (
     this: PluginContext,
     code: string,
@@ -210,10 +246,10 @@ export type ObjectHook<T, O = {}> = T | ({ handler: T } & ObjectHookMeta & O)
 export type SyncPluginHooks = DefinedHookNames[
   | 'augmentChunkHash'
   | 'onLog'
-  | 'outputOptions']
-// | 'renderDynamicImport'
-// | 'resolveFileUrl'
-// | 'resolveImportMeta'
+  | 'outputOptions'
+  | 'renderDynamicImport'
+  | 'resolveFileUrl'
+  | 'resolveImportMeta']
 
 export type AsyncPluginHooks = Exclude<
   keyof FunctionPluginHooks,
@@ -222,11 +258,11 @@ export type AsyncPluginHooks = Exclude<
 
 export type FirstPluginHooks = DefinedHookNames[
   | 'load'
-  // | 'renderDynamicImport'
+  | 'renderDynamicImport'
   | 'resolveDynamicImport'
-  // | 'resolveFileUrl'
-  | 'resolveId']
-// | 'resolveImportMeta'
+  | 'resolveFileUrl'
+  | 'resolveId'
+  | 'resolveImportMeta']
 // | 'shouldTransformCachedModule'
 
 export type SequentialPluginHooks = DefinedHookNames[
@@ -249,11 +285,11 @@ export type OutputPluginHooks = DefinedHookNames[
   | 'generateBundle'
   | 'outputOptions'
   | 'renderChunk'
-  // | 'renderDynamicImport'
+  | 'renderDynamicImport'
   | 'renderError'
   | 'renderStart'
-  // | 'resolveFileUrl'
-  // | 'resolveImportMeta'
+  | 'resolveFileUrl'
+  | 'resolveImportMeta'
   | 'writeBundle']
 
 export type ParallelPluginHooks = Exclude<
@@ -56,4 +56,8 @@ export class TransformPluginContext extends PluginContext {
   public getCombinedSourcemap(): SourceMap {
     return JSON.parse(this.inner.getCombinedSourcemap())
   }
+
+  public addWatchFile(id: string): void {
+    this.inner.addWatchFile(id)
+  }
 }
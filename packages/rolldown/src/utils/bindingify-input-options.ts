@@ -74,6 +74,8 @@ export function bindingifyInputOptions(
     dropLabels: inputOptions.dropLabels,
     keepNames: inputOptions.keepNames,
     checks: inputOptions.checks,
+    logRules: inputOptions.logRules,
+    assetsInlineLimit: inputOptions.assetsInlineLimit,
   }
 }
 
@@ -14,6 +14,11 @@ export function bindingifyOutputOptions(
     sourcemap,
     sourcemapIgnoreList,
     sourcemapPathTransform,
+    sourcemapDebugIds,
+    sourcemapDebugIdsGlobal,
+    sourcemapDebugIdsManifest,
+    sourcemapIndexMap,
+    sourcemapQuality,
     name,
     assetFileNames,
     entryFileNames,
@@ -39,6 +44,11 @@ export function bindingifyOutputOptions(
     sourcemap: bindingifySourcemap(sourcemap),
     sourcemapIgnoreList: bindingifySourcemapIgnoreList(sourcemapIgnoreList),
     sourcemapPathTransform,
+    sourcemapDebugIds,
+    sourcemapDebugIdsGlobal,
+    sourcemapDebugIdsManifest,
+    sourcemapIndexMap,
+    sourcemapQuality,
     banner: bindingifyAddon(banner),
     footer: bindingifyAddon(footer),
     intro: bindingifyAddon(intro),
@@ -55,11 +65,16 @@ export function bindingifyOutputOptions(
     // TODO(sapphi-red): support parallel plugins
     plugins: [],
     minify: outputOptions.minify,
+    mangleProps: outputOptions.mangleProps,
     externalLiveBindings: outputOptions.externalLiveBindings,
     inlineDynamicImports: outputOptions.inlineDynamicImports,
     advancedChunks: outputOptions.advancedChunks,
+    comments: outputOptions.comments,
+    legalCommentsFile: outputOptions.legalCommentsFile,
+    licenseReport: outputOptions.licenseReport,
     polyfillRequire: outputOptions.polyfillRequire,
     target: outputOptions.target,
+    runtimeHelpers: outputOptions.runtimeHelpers,
   }
 }
 
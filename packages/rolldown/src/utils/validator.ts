@@ -118,13 +118,40 @@ const WatchOptionsSchema = v.strictObject({
   ),
 })
 
-const ChecksOptionsSchema = v.strictObject({
+const ChecksCliOptionsSchema = v.strictObject({
   circularDependency: v.pipe(
     v.optional(v.boolean()),
     v.description(
       'Wether to emit warnings when detecting circular dependencies',
     ),
   ),
+  duplicatePackage: v.pipe(
+    v.optional(v.boolean()),
+    v.description(
+      'Whether to emit warnings when a package is bundled from several locations',
+    ),
+  ),
+})
+
+const ChecksOptionsSchema = v.strictObject({
+  ...ChecksCliOptionsSchema.entries,
+  circularDependencyIgnore: v.optional(v.array(StringOrRegExpSchema)),
+  boundaries: v.optional(
+    v.array(
+      v.strictObject({
+        from: v.optional(StringOrRegExpSchema),
+        disallow: StringOrRegExpSchema,
+        platform: v.optional(
+          v.union([
+            v.literal('node'),
+            v.literal('browser'),
+            v.literal('neutral'),
+          ]),
+        ),
+        message: v.optional(v.string()),
+      }),
+    ),
+  ),
 })
 
 const ResolveOptionsSchema = v.strictObject({
@@ -133,6 +160,7 @@ const ResolveOptionsSchema = v.strictObject({
   ),
   aliasFields: v.optional(v.array(v.array(v.string()))),
   conditionNames: v.optional(v.array(v.string())),
+  dedupe: v.optional(v.array(v.string())),
   extensionAlias: v.optional(v.record(v.string(), v.array(v.string()))),
   exportsFields: v.optional(v.array(v.array(v.string()))),
   extensions: v.optional(v.array(v.string())),
@@ -242,6 +270,29 @@ const InputOptionsSchema = v.strictObject({
     v.description('Remove labeled statements with these label names'),
   ),
   checks: v.optional(ChecksOptionsSchema),
+  logRules: v.optional(
+    v.array(
+      v.strictObject({
+        code: v.optional(v.string()),
+        id: v.optional(StringOrRegExpSchema),
+        action: v.union([
+          v.literal('ignore'),
+          v.literal('warn'),
+          v.literal('error'),
+        ]),
+      }),
+    ),
+  ),
+  assetsInlineLimit: v.optional(
+    v.union([
+      v.number(),
+      v.pipe(
+        v.function(),
+        v.args(v.tuple([v.string(), v.instance(Uint8Array)])),
+        v.returns(v.optional(v.boolean())),
+      ),
+    ]),
+  ),
 })
 
 const InputCliOverrideSchema = v.strictObject({
@@ -259,6 +310,7 @@ const InputCliOverrideSchema = v.strictObject({
     v.optional(v.boolean(), true),
     v.description('enable treeshaking'),
   ),
+  checks: v.optional(ChecksCliOptionsSchema),
 })
 
 const InputCliOptionsSchema = v.omit(
@@ -275,6 +327,8 @@ const InputCliOptionsSchema = v.omit(
     'experimental',
     'profilerNames',
     'watch',
+    'logRules',
+    'assetsInlineLimit',
   ],
 )
 
@@ -347,6 +401,27 @@ const AdvancedChunksSchema = v.strictObject({
   ),
 })
 
+const MinifyOptionsSchema = v.strictObject({
+  compress: v.optional(
+    v.strictObject({
+      dropConsole: v.optional(v.boolean()),
+      dropDebugger: v.optional(v.boolean()),
+      pureFuncs: v.optional(v.array(v.string())),
+      passes: v.optional(v.number()),
+    }),
+  ),
+  mangle: v.optional(
+    v.strictObject({
+      topLevel: v.optional(v.boolean()),
+      keepFnames: v.optional(v.boolean()),
+      keepClassnames: v.optional(v.boolean()),
+      reserved: v.optional(v.array(v.string())),
+      nameCache: v.optional(v.string()),
+    }),
+  ),
+  whitespaceOnly: v.optional(v.boolean()),
+})
+
 const OutputOptionsSchema = v.strictObject({
   dir: v.pipe(
     v.optional(v.string()),
@@ -393,6 +468,36 @@ const OutputOptionsSchema = v.strictObject({
   sourcemapPathTransform: v.optional(
     v.custom<SourcemapPathTransformOption>(() => true),
   ),
+  sourcemapDebugIds: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Add a debug id to each chunk and its source map'),
+  ),
+  sourcemapDebugIdsGlobal: v.pipe(
+    v.optional(v.string()),
+    v.description(
+      'Register the debug id of each chunk in this `globalThis` property',
+    ),
+  ),
+  sourcemapDebugIdsManifest: v.pipe(
+    v.optional(v.string()),
+    v.description('Emit a JSON asset that lists the debug id of each chunk'),
+  ),
+  sourcemapIndexMap: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Emit source map index maps with one section per module'),
+  ),
+  sourcemapQuality: v.optional(
+    v.array(
+      v.strictObject({
+        test: StringOrRegExpSchema,
+        quality: v.union([
+          v.literal('full'),
+          v.literal('cheap'),
+          v.literal('excluded'),
+        ]),
+      }),
+    ),
+  ),
   banner: v.optional(v.union([v.string(), AddonFunctionSchema])),
   footer: v.optional(v.union([v.string(), AddonFunctionSchema])),
   intro: v.optional(v.union([v.string(), AddonFunctionSchema])),
@@ -413,9 +518,16 @@ const OutputOptionsSchema = v.strictObject({
   cssEntryFileNames: v.optional(ChunkFileNamesSchema),
   cssChunkFileNames: v.optional(ChunkFileNamesSchema),
   minify: v.pipe(
-    v.optional(v.boolean()),
+    v.optional(v.union([v.boolean(), MinifyOptionsSchema])),
     v.description('Minify the bundled file'),
   ),
+  mangleProps: v.optional(
+    v.strictObject({
+      regex: StringOrRegExpSchema,
+      reserved: v.optional(v.array(v.string())),
+      nameCache: v.optional(v.string()),
+    }),
+  ),
   name: v.pipe(
     v.optional(v.string()),
     v.description('Name for UMD / IIFE format outputs'),
@@ -438,13 +550,36 @@ const OutputOptionsSchema = v.strictObject({
   ),
   advancedChunks: v.optional(AdvancedChunksSchema),
   comments: v.pipe(
-    v.optional(v.union([v.literal('none'), v.literal('preserve-legal')])),
+    v.optional(
+      v.union([
+        v.literal('none'),
+        v.literal('preserve-legal'),
+        v.literal('external'),
+      ]),
+    ),
     v.description('Control comments in the output'),
   ),
+  legalCommentsFile: v.pipe(
+    v.optional(v.string()),
+    v.description('Write the legal comments of all chunks to this file'),
+  ),
+  licenseReport: v.pipe(
+    v.optional(v.string()),
+    v.description(
+      'Emit a JSON asset that lists the licenses of bundled packages',
+    ),
+  ),
   target: v.pipe(
     v.optional(v.enum(ESTarget)),
     v.description('The JavaScript target environment'),
   ),
+  runtimeHelpers: v.optional(
+    v.union([
+      v.literal('inline'),
+      v.strictObject({ external: v.string() }),
+      v.strictObject({ shared: v.string() }),
+    ]),
+  ),
 })
 
 const getAddonDescription = (
@@ -455,6 +590,11 @@ const getAddonDescription = (
 }
 
 const OutputCliOverrideSchema = v.strictObject({
+  // Only `true` and `false` are supported in CLI
+  minify: v.pipe(
+    v.optional(v.boolean()),
+    v.description('Minify the bundled file'),
+  ),
   // Reject all functions in CLI
   entryFileNames: v.pipe(
     v.optional(v.string()),
@@ -525,7 +665,13 @@ const OutputCliOptionsSchema = v.omit(
     ...OutputOptionsSchema.entries,
     ...OutputCliOverrideSchema.entries,
   }),
-  ['sourcemapIgnoreList', 'sourcemapPathTransform'],
+  [
+    'sourcemapIgnoreList',
+    'sourcemapPathTransform',
+    'sourcemapQuality',
+    'mangleProps',
+    'runtimeHelpers',
+  ],
 )
 
 /// --- CliSchema ---
@@ -0,0 +1,24 @@
+import { expect, vi } from 'vitest'
+import path from 'node:path'
+import { defineTest } from 'rolldown-tests'
+
+const fn = vi.fn()
+
+export default defineTest({
+  config: {
+    experimental: {
+      resolveNewUrlToAsset: true,
+    },
+    assetsInlineLimit: (id, content) => {
+      fn()
+      expect(id).toBe(path.join(__dirname, 'icon.svg'))
+      expect(content).toBeInstanceOf(Uint8Array)
+      return true
+    },
+  },
+  afterTest: (output) => {
+    expect(fn).toHaveBeenCalledTimes(1)
+    expect(output.output).toHaveLength(1)
+    expect(output.output[0].code).toContain('data:image/svg+xml')
+  },
+})
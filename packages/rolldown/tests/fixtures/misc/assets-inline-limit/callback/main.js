@@ -0,0 +1 @@
+export const url = new URL('./icon.svg', import.meta.url).href
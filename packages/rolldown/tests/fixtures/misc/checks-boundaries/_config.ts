@@ -0,0 +1,27 @@
+import { defineTest } from 'rolldown-tests'
+import { stripVTControlCharacters } from 'util'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    checks: {
+      boundaries: [
+        {
+          from: /main\.js$/,
+          disallow: /secret\.js$/,
+          message: 'Secrets must not be bundled.',
+        },
+      ],
+    },
+  },
+  catchError(e: any) {
+    expect(e.errors).toEqual([
+      expect.objectContaining({
+        kind: 'BOUNDARY_VIOLATION',
+      }),
+    ])
+    expect(stripVTControlCharacters(e.errors[0].message)).toContain(
+      'Secrets must not be bundled.',
+    )
+  },
+})
@@ -0,0 +1,3 @@
+import { secret } from './secret.js'
+
+console.log(secret)
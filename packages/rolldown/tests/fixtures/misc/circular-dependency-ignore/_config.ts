@@ -0,0 +1,21 @@
+import { defineTest } from 'rolldown-tests'
+import { expect, vi } from 'vitest'
+
+const fn = vi.fn()
+
+export default defineTest({
+  config: {
+    onwarn(warning) {
+      fn()
+      expect(warning.code).toBe('CIRCULAR_DEPENDENCY')
+      expect(warning.message).not.toContain('vendor')
+    },
+    checks: {
+      circularDependency: true,
+      circularDependencyIgnore: [/vendor/],
+    },
+  },
+  afterTest: () => {
+    expect(fn).toHaveBeenCalledTimes(1)
+  },
+})
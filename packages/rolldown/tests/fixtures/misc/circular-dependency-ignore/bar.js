@@ -0,0 +1,5 @@
+import { foo } from './foo.js'
+
+export function bar() {
+  return foo()
+}
@@ -0,0 +1,4 @@
+import { lib } from './vendor/lib.js'
+import { foo } from './foo.js'
+
+export { lib, foo }
@@ -0,0 +1,22 @@
+import { defineTest } from 'rolldown-tests'
+import { expect, vi } from 'vitest'
+
+const fn = vi.fn()
+
+export default defineTest({
+  config: {
+    onwarn() {
+      fn()
+    },
+    resolve: {
+      dedupe: ['shared'],
+    },
+    checks: {
+      duplicatePackage: true,
+    },
+  },
+  afterTest: (output) => {
+    expect(fn).toHaveBeenCalledTimes(0)
+    expect(output.output[0].code).not.toContain('2.0.0')
+  },
+})
@@ -0,0 +1,19 @@
+import { defineTest } from 'rolldown-tests'
+import { expect, vi } from 'vitest'
+
+const fn = vi.fn()
+
+export default defineTest({
+  config: {
+    onwarn(warning) {
+      fn()
+      expect(warning.code).toBe('DUPLICATE_PACKAGE')
+    },
+    checks: {
+      duplicatePackage: true,
+    },
+  },
+  afterTest: () => {
+    expect(fn).toHaveBeenCalledTimes(1)
+  },
+})
@@ -0,0 +1,4 @@
+import { version } from 'shared'
+import { wrapperVersion } from 'wrapper'
+
+export { version, wrapperVersion }
@@ -0,0 +1,21 @@
+import { defineTest } from 'rolldown-tests'
+import { expect, vi } from 'vitest'
+
+const fn = vi.fn()
+
+export default defineTest({
+  config: {
+    onwarn() {
+      fn()
+    },
+    checks: {
+      circularDependency: true,
+    },
+    logRules: [
+      { code: 'CIRCULAR_DEPENDENCY', id: /main\.js$/, action: 'ignore' },
+    ],
+  },
+  afterTest: () => {
+    expect(fn).toHaveBeenCalledTimes(0)
+  },
+})
@@ -0,0 +1,3 @@
+import { value } from './main'
+
+export const value = 1
@@ -0,0 +1,18 @@
+import { defineTest } from 'rolldown-tests'
+import { getOutputAsset, getOutputChunk } from 'rolldown-tests/utils'
+import { expect } from 'vitest'
+
+export default defineTest({
+  config: {
+    output: {
+      runtimeHelpers: { shared: 'runtime.js' },
+    },
+  },
+  afterTest: (output) => {
+    const runtime = getOutputAsset(output).find(
+      (asset) => asset.fileName === 'runtime.js',
+    )
+    expect(runtime?.source).toContain('__toESM')
+    expect(getOutputChunk(output)[0].code).toContain('./runtime.js')
+  },
+})
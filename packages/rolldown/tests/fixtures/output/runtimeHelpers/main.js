@@ -0,0 +1,3 @@
+import cjs from './cjs.js'
+
+export const value = cjs.value
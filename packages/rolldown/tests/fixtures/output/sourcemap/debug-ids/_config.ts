@@ -0,0 +1,36 @@
+import { expect } from 'vitest'
+import { getOutputChunk, getOutputFileNames } from 'rolldown-tests/utils'
+import { defineTest } from 'rolldown-tests'
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+    output: {
+      sourcemap: true,
+      sourcemapDebugIds: true,
+      sourcemapDebugIdsGlobal: '_debugIds',
+      sourcemapDebugIdsManifest: 'debug-ids.json',
+    },
+  },
+  afterTest: function (output) {
+    expect(getOutputFileNames(output)).toStrictEqual([
+      'debug-ids.json',
+      'main.js',
+      'main.js.map',
+    ])
+    const manifestAsset = output.output.find(
+      (item) => item.fileName === 'debug-ids.json',
+    )!
+    expect(manifestAsset.type).toBe('asset')
+    const manifest = JSON.parse(
+      (manifestAsset as { source: string | Uint8Array }).source.toString(),
+    )
+    expect(manifest.chunks).toEqual([
+      { file: 'main.js', map: 'main.js.map', debugId: expect.any(String) },
+    ])
+
+    const code = getOutputChunk(output)[0].code
+    expect(code).toContain(`//# debugId=${manifest.chunks[0].debugId}`)
+    expect(code).toContain('g["_debugIds"]')
+  },
+})
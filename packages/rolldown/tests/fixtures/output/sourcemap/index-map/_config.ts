@@ -0,0 +1,22 @@
+import { expect } from 'vitest'
+import { getOutputAsset, getOutputFileNames } from 'rolldown-tests/utils'
+import { defineTest } from 'rolldown-tests'
+
+export default defineTest({
+  config: {
+    input: ['main.js'],
+    output: {
+      sourcemap: true,
+      sourcemapIndexMap: true,
+    },
+  },
+  afterTest: function (output) {
+    expect(getOutputFileNames(output)).toStrictEqual(['main.js', 'main.js.map'])
+    const map = JSON.parse(getOutputAsset(output)[0].source.toString())
+    expect(map.file).toBe('main.js')
+    expect(map.sections.map((section: any) => section.map.sources)).toEqual([
+      [expect.stringMatching(/foo\.js$/)],
+      [expect.stringMatching(/main\.js$/)],
+    ])
+  },
+})
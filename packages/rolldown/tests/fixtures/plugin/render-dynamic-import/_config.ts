@@ -0,0 +1,29 @@
+import { expect, vi } from 'vitest'
+import path from 'node:path'
+import { defineTest } from 'rolldown-tests'
+
+const entry = path.join(__dirname, './main.js')
+
+const fn = vi.fn()
+
+export default defineTest({
+  config: {
+    input: entry,
+    plugins: [
+      {
+        name: 'test-plugin',
+        renderDynamicImport: ({ moduleId, targetModuleId, specifier }) => {
+          fn()
+          expect(moduleId).toBe(entry)
+          expect(targetModuleId).toBe(path.join(__dirname, './foo.js'))
+          expect(specifier).toBe('./foo.js')
+          return { left: 'customImport(', right: ')' }
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(fn).toHaveBeenCalledTimes(1)
+    expect(output.output[0].code).toContain("customImport('./foo.js')")
+  },
+})
@@ -0,0 +1,43 @@
+import { expect, vi } from 'vitest'
+import path from 'node:path'
+import { defineTest } from 'rolldown-tests'
+
+const entry = path.join(__dirname, './main.js')
+
+const fn = vi.fn()
+let referenceId: string
+
+export default defineTest({
+  config: {
+    input: entry,
+    plugins: [
+      {
+        name: 'test-plugin',
+        buildStart() {
+          referenceId = this.emitFile({
+            type: 'asset',
+            fileName: 'foo.txt',
+            source: 'foo',
+          })
+        },
+        transform(code, id) {
+          if (id === entry) {
+            return code.replace('REFERENCE_ID', referenceId)
+          }
+        },
+        resolveFileUrl: (options) => {
+          fn()
+          expect(options.referenceId).toBe(referenceId)
+          expect(options.fileName).toBe('foo.txt')
+          expect(options.relativePath).toBe('foo.txt')
+          expect(options.moduleId).toBe(entry)
+          return `getAssetUrl(${JSON.stringify(options.relativePath)})`
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(fn).toHaveBeenCalledTimes(1)
+    expect(output.output[0].code).toContain('getAssetUrl("foo.txt")')
+  },
+})
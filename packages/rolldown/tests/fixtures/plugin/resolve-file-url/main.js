@@ -0,0 +1 @@
+export const url = import.meta.ROLLUP_FILE_URL_REFERENCE_ID
@@ -0,0 +1,30 @@
+import { expect, vi } from 'vitest'
+import path from 'node:path'
+import { defineTest } from 'rolldown-tests'
+
+const entry = path.join(__dirname, './main.js')
+
+const fn = vi.fn()
+
+export default defineTest({
+  config: {
+    input: entry,
+    plugins: [
+      {
+        name: 'test-plugin',
+        resolveImportMeta: (property, { chunkId, format, moduleId }) => {
+          fn()
+          expect(property).toBe('foo')
+          expect(chunkId).toBe('main.js')
+          expect(format).toBe('es')
+          expect(moduleId).toBe(entry)
+          return '"resolved foo"'
+        },
+      },
+    ],
+  },
+  afterTest: (output) => {
+    expect(fn).toHaveBeenCalledTimes(1)
+    expect(output.output[0].code).toContain('"resolved foo"')
+  },
+})